@@ -0,0 +1,318 @@
+//! approximate equality for floats, vectors and matrices
+//!
+//! `ApproxEq` offers three tests, each of which also passes when the absolute
+//! difference is within `epsilon`, so values near zero compare sensibly:
+//!
+//! - `abs_diff_eq`: |a - b| ≤ epsilon
+//! - `relative_eq`: |a - b| ≤ max_relative · max(|a|, |b|)
+//! - `ulps_eq`: a and b are at most max_ulps representable floats apart
+//!
+//! composite types compare element by element and are equal when every
+//! element is. values of different shape are never equal. NaN equals nothing.
+//!
+//! the `assert_approx_eq!` and `approx_eq!` macros wrap these for tests. with
+//! the `approx` feature the same types also implement the `approx` crate's
+//! `AbsDiffEq`, `RelativeEq` and `UlpsEq`.
+use num::Complex;
+
+use crate::matrix::matrixnxm::Matrix;
+use crate::matrix::sparse::{CscMatrix, CsrMatrix};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use crate::vector::soa::Vector3x8;
+use crate::vector::vector::Vector;
+use crate::vector::vectorn::VectorN;
+use crate::vector::{Vector2, Vector3, Vector4};
+
+/// default ulp distance of `ulps_eq`
+pub const DEFAULT_MAX_ULPS: u32 = 4;
+
+/// approximate equality with absolute, relative and ulp tolerances
+pub trait ApproxEq {
+    /// tolerance type, the scalar type for composite values
+    type Epsilon: Copy;
+
+    /// machine epsilon of the scalar, the default for every tolerance
+    fn default_epsilon() -> Self::Epsilon;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// `relative_eq` with the default tolerances
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_epsilon())
+    }
+}
+
+macro_rules! float_approx {
+    ($t:ty, $bits:ty) => (
+        impl ApproxEq for $t {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                <$t>::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                // equal infinities have no finite difference
+                self == other || (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                if self == other {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                let diff = (self - other).abs();
+                diff <= epsilon || diff <= self.abs().max(other.abs()) * max_relative
+            }
+
+            fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                // floats of one sign are ordered like their bit patterns,
+                // opposite signs are only equal at zero, handled above
+                if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+                let (a, b) = (self.to_bits(), other.to_bits());
+                let distance: $bits = if a > b { a - b } else { b - a };
+                distance <= max_ulps as $bits
+            }
+        }
+    );
+}
+
+float_approx!(f32, u32);
+float_approx!(f64, u64);
+
+/// element-wise comparison of two equally long sequences
+fn all_pairs<'a, T: ApproxEq + 'a>(
+    a: impl IntoIterator<Item = &'a T>,
+    b: impl IntoIterator<Item = &'a T>,
+    eq: impl Fn(&T, &T) -> bool,
+) -> bool {
+    a.into_iter().zip(b).all(|(x, y)| eq(x, y))
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.len() == other.len() && all_pairs(self, other, |x, y| x.abs_diff_eq(y, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.len() == other.len() && all_pairs(self, other, |x, y| x.relative_eq(y, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.len() == other.len() && all_pairs(self, other, |x, y| x.ulps_eq(y, epsilon, max_ulps))
+    }
+}
+
+/// implement `ApproxEq` by comparing a slice of elements taken from each value
+macro_rules! approx_via {
+    ([$($gen:tt)*] $t:ty, $elem:ty, |$s:ident| $view:expr) => (
+        impl<$($gen)*> ApproxEq for $t {
+            type Epsilon = <$elem as ApproxEq>::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$elem>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                let (a, b) = ({ let $s = self; $view }, { let $s = other; $view });
+                a.abs_diff_eq(b, epsilon)
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                let (a, b) = ({ let $s = self; $view }, { let $s = other; $view });
+                a.relative_eq(b, epsilon, max_relative)
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                let (a, b) = ({ let $s = self; $view }, { let $s = other; $view });
+                a.ulps_eq(b, epsilon, max_ulps)
+            }
+        }
+    );
+}
+
+approx_via!([T: ApproxEq, const N: usize] [T; N], T, |a| &a[..]);
+approx_via!([T: ApproxEq, const N: usize] Vector<T, N>, T, |v| &v.data[..]);
+approx_via!([T: ApproxEq] VectorN<T>, T, |v| &v.data[..]);
+approx_via!([] Matrix2x2, [f32; 2], |m| &m.data[..]);
+approx_via!([] Matrix3x3, [f32; 3], |m| &m.data[..]);
+approx_via!([] Matrix4x4, [f32; 4], |m| &m.data[..]);
+
+/// implement `ApproxEq` for an f32 struct by comparing the listed fields
+macro_rules! approx_fields {
+    ($t:ty, $($f:ident),*) => (
+        impl ApproxEq for $t {
+            type Epsilon = f32;
+
+            fn default_epsilon() -> f32 {
+                f32::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                $(self.$f.abs_diff_eq(&other.$f, epsilon))&&*
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                $(self.$f.relative_eq(&other.$f, epsilon, max_relative))&&*
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                $(self.$f.ulps_eq(&other.$f, epsilon, max_ulps))&&*
+            }
+        }
+    );
+}
+
+approx_fields!(Vector2, x, y);
+approx_fields!(Vector3, x, y, z);
+approx_fields!(Vector4, x, y, z, w);
+approx_fields!(Vector3x8, x, y, z);
+
+impl<T: ApproxEq> ApproxEq for Complex<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon) && self.im.abs_diff_eq(&other.im, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.re.relative_eq(&other.re, epsilon, max_relative) && self.im.relative_eq(&other.im, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.re.ulps_eq(&other.re, epsilon, max_ulps) && self.im.ulps_eq(&other.im, epsilon, max_ulps)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Matrix<T> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.n == other.n && self.m == other.m && self.data.abs_diff_eq(&other.data, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.n == other.n && self.m == other.m && self.data.relative_eq(&other.data, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.n == other.n && self.m == other.m && self.data.ulps_eq(&other.data, epsilon, max_ulps)
+    }
+}
+
+/// sparse matrices are approximately equal when they have the same shape and
+/// sparsity pattern and their stored values are; an explicitly stored zero
+/// is not the same as a missing entry
+macro_rules! approx_sparse {
+    ($t:ident, $ptr:ident, $idx:ident) => (
+        impl<T: ApproxEq> $t<T> {
+            fn same_pattern(&self, other: &Self) -> bool {
+                self.n == other.n && self.m == other.m && self.$ptr == other.$ptr && self.$idx == other.$idx
+            }
+        }
+
+        impl<T: ApproxEq> ApproxEq for $t<T> {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                self.same_pattern(other) && self.values.abs_diff_eq(&other.values, epsilon)
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                self.same_pattern(other) && self.values.relative_eq(&other.values, epsilon, max_relative)
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                self.same_pattern(other) && self.values.ulps_eq(&other.values, epsilon, max_ulps)
+            }
+        }
+    );
+}
+
+approx_sparse!(CsrMatrix, row_ptr, col_idx);
+approx_sparse!(CscMatrix, col_ptr, row_idx);
+
+/// evaluate an approximate comparison to a bool
+///
+/// ```
+/// use rmu::approx_eq;
+/// assert!(approx_eq!(0.1f64 + 0.2, 0.3));
+/// assert!(approx_eq!(1.0f64, 1.05, abs <= 0.1));
+/// assert!(approx_eq!(100.0f64, 101.0, rel <= 0.01));
+/// assert!(approx_eq!(1.0f32, 1.0 + f32::EPSILON, ulps <= 1));
+/// ```
+#[macro_export]
+macro_rules! approx_eq {
+    ($a:expr, $b:expr) => (
+        $crate::approx_eq::ApproxEq::approx_eq(&$a, &$b)
+    );
+    ($a:expr, $b:expr, abs <= $eps:expr) => (
+        $crate::approx_eq::ApproxEq::abs_diff_eq(&$a, &$b, $eps)
+    );
+    ($a:expr, $b:expr, rel <= $rel:expr) => ({
+        let (a, b) = (&$a, &$b);
+        $crate::approx_eq::ApproxEq::relative_eq(a, b, $crate::approx_eq::epsilon_of(a), $rel)
+    });
+    ($a:expr, $b:expr, ulps <= $ulps:expr) => ({
+        let (a, b) = (&$a, &$b);
+        $crate::approx_eq::ApproxEq::ulps_eq(a, b, $crate::approx_eq::epsilon_of(a), $ulps)
+    });
+}
+
+/// assert two values are approximately equal, see `approx_eq!` for the forms
+///
+/// ```
+/// use rmu::assert_approx_eq;
+/// use rmu::vector::Vector3;
+/// assert_approx_eq!(Vector3::new(0.1, 0.2, 0.3) * 3.0, Vector3::new(0.3, 0.6, 0.9));
+/// assert_approx_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.01), abs <= 0.1);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr $(, $kind:ident <= $tol:expr)?) => ({
+        let (a, b) = (&$a, &$b);
+        if !$crate::approx_eq!(*a, *b $(, $kind <= $tol)?) {
+            panic!(
+                "assertion failed: `{} ≈ {}`{}\n  left: {:?}\n right: {:?}",
+                stringify!($a), stringify!($b),
+                concat!($(" (", stringify!($kind), " <= ", stringify!($tol), ")")?),
+                a, b,
+            );
+        }
+    });
+}
+
+/// the default epsilon of a value's type, lets macros name it without the type
+#[doc(hidden)]
+pub fn epsilon_of<T: ApproxEq + ?Sized>(_: &T) -> T::Epsilon {
+    T::default_epsilon()
+}
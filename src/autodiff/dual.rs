@@ -0,0 +1,40 @@
+//! dual numbers a + bε with ε² = 0
+use num::Float;
+use super::dual_impls;
+
+/// a value and its derivative by one variable
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual<T> {
+    /// value
+    pub re: T,
+    /// derivative
+    pub eps: T,
+}
+
+impl<T: Float> Dual<T> {
+    pub fn new(re: T, eps: T) -> Self {
+        Dual { re, eps }
+    }
+
+    /// a constant, its derivative is zero
+    pub fn constant(re: T) -> Self {
+        Dual { re, eps: T::zero() }
+    }
+
+    /// the variable being differentiated by, its derivative is one
+    pub fn variable(re: T) -> Self {
+        Dual { re, eps: T::one() }
+    }
+
+    #[inline]
+    fn chain(self, re: T, slope: T) -> Self {
+        Dual { re, eps: self.eps * slope }
+    }
+
+    #[inline]
+    fn combine(self, other: Self, re: T, da: T, db: T) -> Self {
+        Dual { re, eps: self.eps * da + other.eps * db }
+    }
+}
+
+dual_impls!([T: Float], Dual<T>);
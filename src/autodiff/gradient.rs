@@ -0,0 +1,65 @@
+//! multivariate dual numbers
+use num::Float;
+use crate::vector::vector::Vector;
+use super::dual_impls;
+
+/// a value and its partial derivatives by N variables
+///
+/// costs N times a `Dual` per operation but yields the whole gradient in
+/// one evaluation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gradient<T, const N: usize> {
+    /// value
+    pub re: T,
+    /// ∂/∂xᵢ
+    pub grad: [T; N],
+}
+
+impl<T: Float, const N: usize> Gradient<T, N> {
+    pub fn new(re: T, grad: [T; N]) -> Self {
+        Gradient { re, grad }
+    }
+
+    /// a constant, all partial derivatives are zero
+    pub fn constant(re: T) -> Self {
+        Gradient { re, grad: [T::zero(); N] }
+    }
+
+    /// variable i of N
+    pub fn variable(re: T, i: usize) -> Self {
+        let mut grad = [T::zero(); N];
+        grad[i] = T::one();
+        Gradient { re, grad }
+    }
+
+    /// every component of x as its own variable
+    pub fn variables(x: &Vector<T, N>) -> Vector<Self, N> {
+        let mut data = [Self::constant(T::zero()); N];
+        for (i, d) in data.iter_mut().enumerate() {
+            *d = Self::variable(x.data[i], i);
+        }
+        Vector { data }
+    }
+
+    #[inline]
+    fn chain(self, re: T, slope: T) -> Self {
+        Gradient { re, grad: self.grad.map(|g| g * slope) }
+    }
+
+    #[inline]
+    fn combine(self, other: Self, re: T, da: T, db: T) -> Self {
+        let mut grad = self.grad;
+        for (g, o) in grad.iter_mut().zip(other.grad.iter()) {
+            *g = *g * da + *o * db;
+        }
+        Gradient { re, grad }
+    }
+}
+
+impl<T: Float, const N: usize> Default for Gradient<T, N> {
+    fn default() -> Self {
+        Self::constant(T::zero())
+    }
+}
+
+dual_impls!([T: Float, const N: usize], Gradient<T, N>);
@@ -0,0 +1,458 @@
+//! forward mode automatic differentiation
+//!
+//! `Dual<T>` carries a value and one derivative, `Gradient<T, N>` a value and
+//! the partial derivatives by N variables. both implement `num::Float` and
+//! `Sqrt`, so they can stand in for f32/f64 in `Vector<T, N>`, `VectorN<T>`,
+//! `Matrix<T>` and the generic routines built on them, and every result
+//! comes out with its exact derivative.
+use num::Float;
+use crate::matrix::matrixnxm::Matrix;
+use crate::vector::vector::Vector;
+use crate::vector::vectorn::VectorN;
+
+pub mod dual;
+pub mod gradient;
+
+pub use dual::Dual;
+pub use gradient::Gradient;
+
+/// f(x) and f'(x)
+pub fn derivative<T: Float, F: Fn(Dual<T>) -> Dual<T>>(f: F, x: T) -> (T, T) {
+    let y = f(Dual::variable(x));
+    (y.re, y.eps)
+}
+
+/// f(x) and ∇f(x)
+pub fn gradient<T: Float, const N: usize, F>(f: F, x: &Vector<T, N>) -> (T, Vector<T, N>)
+where
+    F: Fn(&Vector<Gradient<T, N>, N>) -> Gradient<T, N>,
+{
+    let y = f(&Gradient::variables(x));
+    (y.re, Vector { data: y.grad })
+}
+
+/// r(x) and the jacobian ∂rᵢ/∂xⱼ, one row per residual
+///
+/// fits `numeric::WithJacobian` for least squares problems.
+pub fn jacobian<T: Float, const N: usize, F>(f: F, x: &Vector<T, N>) -> (VectorN<T>, Matrix<T>)
+where
+    F: Fn(&Vector<Gradient<T, N>, N>) -> VectorN<Gradient<T, N>>,
+{
+    let y = f(&Gradient::variables(x));
+    let r = y.iter().map(|g| g.re).collect();
+    let rows: Vec<[T; N]> = y.iter().map(|g| g.grad).collect();
+    let j = if rows.is_empty() { Matrix::zeros(0, N) } else { Matrix::from_rows(&rows) };
+    (r, j)
+}
+
+/// operator, num and `Sqrt` impls shared by the dual number types
+///
+/// the type supplies `constant(re)`, `chain(self, re, slope)` for a unary
+/// function with value re and derivative slope, and `combine(self, other,
+/// re, da, db)` for a binary one with partials da and db.
+macro_rules! dual_impls {
+    ([$($gen:tt)*], $ty:ty) => {
+        impl<$($gen)*> std::ops::Add for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                self.combine(rhs, self.re + rhs.re, T::one(), T::one())
+            }
+        }
+
+        impl<$($gen)*> std::ops::Sub for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self.combine(rhs, self.re - rhs.re, T::one(), -T::one())
+            }
+        }
+
+        impl<$($gen)*> std::ops::Mul for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.combine(rhs, self.re * rhs.re, rhs.re, self.re)
+            }
+        }
+
+        impl<$($gen)*> std::ops::Div for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                let r = self.re / rhs.re;
+                self.combine(rhs, r, T::one() / rhs.re, -r / rhs.re)
+            }
+        }
+
+        impl<$($gen)*> std::ops::Rem for $ty {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                self.combine(rhs, self.re % rhs.re, T::one(), -(self.re / rhs.re).trunc())
+            }
+        }
+
+        impl<$($gen)*> std::ops::Neg for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self.chain(-self.re, -T::one())
+            }
+        }
+
+        impl<$($gen)*> std::ops::Add<T> for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: T) -> Self {
+                self.chain(self.re + rhs, T::one())
+            }
+        }
+
+        impl<$($gen)*> std::ops::Sub<T> for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: T) -> Self {
+                self.chain(self.re - rhs, T::one())
+            }
+        }
+
+        impl<$($gen)*> std::ops::Mul<T> for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                self.chain(self.re * rhs, rhs)
+            }
+        }
+
+        impl<$($gen)*> std::ops::Div<T> for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                self.chain(self.re / rhs, T::one() / rhs)
+            }
+        }
+
+        impl<$($gen)*> std::ops::AddAssign for $ty {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<$($gen)*> std::ops::SubAssign for $ty {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<$($gen)*> std::ops::MulAssign for $ty {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<$($gen)*> std::ops::DivAssign for $ty {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<$($gen)*> std::iter::Sum for $ty {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::constant(T::zero()), |a, b| a + b)
+            }
+        }
+
+        impl<$($gen)*> std::iter::Product for $ty {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::constant(T::one()), |a, b| a * b)
+            }
+        }
+
+        /// ordered by value. equal values with different derivatives are
+        /// unordered, since `==` compares the derivatives as well
+        impl<$($gen)*> PartialOrd for $ty {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                match self.re.partial_cmp(&other.re)? {
+                    std::cmp::Ordering::Equal if self != other => None,
+                    ordering => Some(ordering),
+                }
+            }
+        }
+
+        impl<$($gen)*> num::Zero for $ty {
+            fn zero() -> Self {
+                Self::constant(T::zero())
+            }
+
+            /// the additive identity, so the derivatives have to vanish too.
+            /// test `re` for a zero value
+            fn is_zero(&self) -> bool {
+                *self == Self::zero()
+            }
+        }
+
+        impl<$($gen)*> num::One for $ty {
+            fn one() -> Self {
+                Self::constant(T::one())
+            }
+        }
+
+        impl<$($gen)*> num::Num for $ty {
+            type FromStrRadixErr = T::FromStrRadixErr;
+
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                T::from_str_radix(s, radix).map(Self::constant)
+            }
+        }
+
+        impl<$($gen)*> num::ToPrimitive for $ty {
+            fn to_i64(&self) -> Option<i64> {
+                self.re.to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.re.to_u64()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                self.re.to_f64()
+            }
+        }
+
+        impl<$($gen)*> num::NumCast for $ty {
+            fn from<P: num::ToPrimitive>(n: P) -> Option<Self> {
+                <T as num::NumCast>::from(n).map(Self::constant)
+            }
+        }
+
+        impl<$($gen)*> crate::vector::vector::Sqrt for $ty {
+            #[inline]
+            fn sqrt(self) -> Self {
+                num::Float::sqrt(self)
+            }
+        }
+
+        impl<$($gen)*> num::Float for $ty {
+            fn nan() -> Self { Self::constant(T::nan()) }
+            fn infinity() -> Self { Self::constant(T::infinity()) }
+            fn neg_infinity() -> Self { Self::constant(T::neg_infinity()) }
+            fn neg_zero() -> Self { Self::constant(T::neg_zero()) }
+            fn min_value() -> Self { Self::constant(T::min_value()) }
+            fn min_positive_value() -> Self { Self::constant(T::min_positive_value()) }
+            fn max_value() -> Self { Self::constant(T::max_value()) }
+            fn epsilon() -> Self { Self::constant(T::epsilon()) }
+            fn is_nan(self) -> bool { self.re.is_nan() }
+            fn is_infinite(self) -> bool { self.re.is_infinite() }
+            fn is_finite(self) -> bool { self.re.is_finite() }
+            fn is_normal(self) -> bool { self.re.is_normal() }
+            fn classify(self) -> std::num::FpCategory { self.re.classify() }
+            fn is_sign_positive(self) -> bool { self.re.is_sign_positive() }
+            fn is_sign_negative(self) -> bool { self.re.is_sign_negative() }
+            fn integer_decode(self) -> (u64, i16, i8) { self.re.integer_decode() }
+
+            // piecewise constant, zero derivative
+            fn floor(self) -> Self { self.chain(self.re.floor(), T::zero()) }
+            fn ceil(self) -> Self { self.chain(self.re.ceil(), T::zero()) }
+            fn round(self) -> Self { self.chain(self.re.round(), T::zero()) }
+            fn trunc(self) -> Self { self.chain(self.re.trunc(), T::zero()) }
+            fn signum(self) -> Self { self.chain(self.re.signum(), T::zero()) }
+            fn fract(self) -> Self { self.chain(self.re.fract(), T::one()) }
+            fn abs(self) -> Self { self.chain(self.re.abs(), self.re.signum()) }
+
+            fn max(self, other: Self) -> Self {
+                if other.re > self.re || self.re.is_nan() { other } else { self }
+            }
+
+            fn min(self, other: Self) -> Self {
+                if other.re < self.re || self.re.is_nan() { other } else { self }
+            }
+
+            fn abs_sub(self, other: Self) -> Self {
+                if self.re > other.re { self - other } else { Self::constant(T::zero()) }
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+
+            fn recip(self) -> Self {
+                let r = self.re.recip();
+                self.chain(r, -r * r)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                // n - 1 overflows for i32::MIN
+                let below = n.checked_sub(1).map_or_else(|| self.re.powi(n) / self.re, |m| self.re.powi(m));
+                let slope = if n == 0 { T::zero() } else { T::from(n).unwrap() * below };
+                self.chain(self.re.powi(n), slope)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                let v = self.re.powf(n.re);
+                // ∂/∂n = xⁿ ln x only exists for positive x
+                let dn = if self.re > T::zero() { v * self.re.ln() } else { T::zero() };
+                let dx = if n.re == T::zero() { T::zero() } else { n.re * self.re.powf(n.re - T::one()) };
+                self.combine(n, v, dx, dn)
+            }
+
+            fn sqrt(self) -> Self {
+                let s = self.re.sqrt();
+                self.chain(s, T::from(0.5).unwrap() / s)
+            }
+
+            fn cbrt(self) -> Self {
+                let c = self.re.cbrt();
+                self.chain(c, T::one() / (T::from(3.0).unwrap() * c * c))
+            }
+
+            fn exp(self) -> Self {
+                let e = self.re.exp();
+                self.chain(e, e)
+            }
+
+            fn exp2(self) -> Self {
+                let e = self.re.exp2();
+                self.chain(e, e * T::from(std::f64::consts::LN_2).unwrap())
+            }
+
+            fn exp_m1(self) -> Self {
+                self.chain(self.re.exp_m1(), self.re.exp())
+            }
+
+            fn ln(self) -> Self {
+                self.chain(self.re.ln(), self.re.recip())
+            }
+
+            fn ln_1p(self) -> Self {
+                self.chain(self.re.ln_1p(), (T::one() + self.re).recip())
+            }
+
+            fn log(self, base: Self) -> Self {
+                self.ln() / base.ln()
+            }
+
+            fn log2(self) -> Self {
+                self.chain(self.re.log2(), (self.re * T::from(std::f64::consts::LN_2).unwrap()).recip())
+            }
+
+            fn log10(self) -> Self {
+                self.chain(self.re.log10(), (self.re * T::from(std::f64::consts::LN_10).unwrap()).recip())
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                let h = self.re.hypot(other.re);
+                self.combine(other, h, self.re / h, other.re / h)
+            }
+
+            fn sin(self) -> Self { self.chain(self.re.sin(), self.re.cos()) }
+            fn cos(self) -> Self { self.chain(self.re.cos(), -self.re.sin()) }
+
+            fn tan(self) -> Self {
+                let t = self.re.tan();
+                self.chain(t, T::one() + t * t)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (s, c) = self.re.sin_cos();
+                (self.chain(s, c), self.chain(c, -s))
+            }
+
+            fn asin(self) -> Self {
+                self.chain(self.re.asin(), (T::one() - self.re * self.re).sqrt().recip())
+            }
+
+            fn acos(self) -> Self {
+                self.chain(self.re.acos(), -(T::one() - self.re * self.re).sqrt().recip())
+            }
+
+            fn atan(self) -> Self {
+                self.chain(self.re.atan(), (T::one() + self.re * self.re).recip())
+            }
+
+            /// self is y, other is x
+            fn atan2(self, other: Self) -> Self {
+                let d = self.re * self.re + other.re * other.re;
+                self.combine(other, self.re.atan2(other.re), other.re / d, -self.re / d)
+            }
+
+            fn sinh(self) -> Self { self.chain(self.re.sinh(), self.re.cosh()) }
+            fn cosh(self) -> Self { self.chain(self.re.cosh(), self.re.sinh()) }
+
+            fn tanh(self) -> Self {
+                let t = self.re.tanh();
+                self.chain(t, T::one() - t * t)
+            }
+
+            fn asinh(self) -> Self {
+                self.chain(self.re.asinh(), (self.re * self.re + T::one()).sqrt().recip())
+            }
+
+            fn acosh(self) -> Self {
+                self.chain(self.re.acosh(), (self.re * self.re - T::one()).sqrt().recip())
+            }
+
+            fn atanh(self) -> Self {
+                self.chain(self.re.atanh(), (T::one() - self.re * self.re).recip())
+            }
+        }
+    };
+}
+
+pub(crate) use dual_impls;
+
+/// scalar on the left: 2.0 * x
+macro_rules! scalar_lhs {
+    ($($t:ty),*) => ($(
+        impl std::ops::Add<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+            fn add(self, rhs: Dual<$t>) -> Dual<$t> { rhs + self }
+        }
+
+        impl std::ops::Sub<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+            fn sub(self, rhs: Dual<$t>) -> Dual<$t> { -rhs + self }
+        }
+
+        impl std::ops::Mul<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+            fn mul(self, rhs: Dual<$t>) -> Dual<$t> { rhs * self }
+        }
+
+        impl std::ops::Div<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+            fn div(self, rhs: Dual<$t>) -> Dual<$t> { Dual::constant(self) / rhs }
+        }
+
+        impl<const N: usize> std::ops::Add<Gradient<$t, N>> for $t {
+            type Output = Gradient<$t, N>;
+            fn add(self, rhs: Gradient<$t, N>) -> Gradient<$t, N> { rhs + self }
+        }
+
+        impl<const N: usize> std::ops::Sub<Gradient<$t, N>> for $t {
+            type Output = Gradient<$t, N>;
+            fn sub(self, rhs: Gradient<$t, N>) -> Gradient<$t, N> { -rhs + self }
+        }
+
+        impl<const N: usize> std::ops::Mul<Gradient<$t, N>> for $t {
+            type Output = Gradient<$t, N>;
+            fn mul(self, rhs: Gradient<$t, N>) -> Gradient<$t, N> { rhs * self }
+        }
+
+        impl<const N: usize> std::ops::Div<Gradient<$t, N>> for $t {
+            type Output = Gradient<$t, N>;
+            fn div(self, rhs: Gradient<$t, N>) -> Gradient<$t, N> { Gradient::constant(self) / rhs }
+        }
+    )*);
+}
+
+scalar_lhs!(f32, f64);
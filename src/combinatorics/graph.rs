@@ -1,6 +1,8 @@
 use std::collections::HashMap;
+use std::fmt;
 
 /// edge list undirected graph 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Graph<T> {
     pub nodes: HashMap<String,T>,
     /// Hashmap<edge,(node,node)>
@@ -28,8 +30,15 @@ impl<T> Graph<T> where {
     }
 }
 
+impl<T> Default for Graph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 use std::collections::HashSet;
 /// adjacency list durected graph
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DGraph<T> {
     pub nodes: HashMap<String,T>,
     pub edges: HashMap<String,HashSet<String>>,
@@ -97,8 +106,111 @@ impl<T> DGraph<T> {
     }
 }
 
+impl<T> Default for DGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// one line per node in label order, `a -- b (i), c (j)` lists the
+/// neighbours of a with the label of the edge to each
+impl<T> fmt::Display for Graph<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut adjacent: HashMap<&String, Vec<(&String, &String)>> = HashMap::new();
+        for (edge, (a, b)) in self.edges.iter() {
+            adjacent.entry(a).or_default().push((b, edge));
+            if a != b {
+                adjacent.entry(b).or_default().push((a, edge));
+            }
+        }
+        write_adjacency(f, &self.nodes, " --", |label| {
+            let mut next: Vec<_> = adjacent.get(label).into_iter().flatten().collect();
+            next.sort();
+            next.into_iter().map(|(b, edge)| format!("{} ({})", b, edge)).collect()
+        })
+    }
+}
+
+/// one line per node in label order, `a -> b, c` lists the successors of a
+impl<T> fmt::Display for DGraph<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_adjacency(f, &self.nodes, " ->", |label| {
+            let mut next: Vec<_> = self.edges.get(label).into_iter().flatten().cloned().collect();
+            next.sort();
+            next
+        })
+    }
+}
+
+fn write_adjacency<T>(
+    f: &mut fmt::Formatter,
+    nodes: &HashMap<String, T>,
+    arrow: &str,
+    next: impl Fn(&String) -> Vec<String>,
+) -> fmt::Result {
+    let mut labels: Vec<&String> = nodes.keys().collect();
+    labels.sort();
+    for (k, label) in labels.into_iter().enumerate() {
+        if k > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "{}", label)?;
+        let next = next(label);
+        if !next.is_empty() {
+            write!(f, "{} {}", arrow, next.join(", "))?;
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug,PartialEq)]
 pub enum GraphConnectError {
     NodeNotExit,
     CyclicConnect,
+}
+
+/// rejects edges whose end nodes are missing
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Graph<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Graph")]
+        struct Raw<T> {
+            nodes: HashMap<String,T>,
+            edges: HashMap<String,(String,String)>,
+        }
+
+        let Raw { nodes, edges } = Raw::deserialize(deserializer)?;
+        for (label, (a, b)) in edges.iter() {
+            if !nodes.contains_key(a) || !nodes.contains_key(b) {
+                return Err(D::Error::custom(format!("edge {} connects a node that does not exist", label)));
+            }
+        }
+        Ok(Self { nodes, edges })
+    }
+}
+
+/// rejects edges whose end nodes are missing
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for DGraph<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "DGraph")]
+        struct Raw<T> {
+            nodes: HashMap<String,T>,
+            edges: HashMap<String,HashSet<String>>,
+        }
+
+        let Raw { nodes, edges } = Raw::deserialize(deserializer)?;
+        for (a, next_nodes) in edges.iter() {
+            if let Some(b) = std::iter::once(a).chain(next_nodes.iter()).find(|n| !nodes.contains_key(*n)) {
+                return Err(D::Error::custom(format!("edge from {} connects node {} that does not exist", a, b)));
+            }
+        }
+        Ok(Self { nodes, edges })
+    }
 }
\ No newline at end of file
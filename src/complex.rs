@@ -0,0 +1,42 @@
+//! complex scalars
+//!
+//! `Complex<T>` is num's complex number, re-exported here and made a `Sqrt`
+//! so it fits `Vector<T, N>` as well as `VectorN<T>` and `Matrix<T>`.
+//! note that `length()` on a complex vector is √(Σ zᵢ²), use `norm()` on
+//! `VectorN` for the hermitian length √(Σ |zᵢ|²).
+use num::Float;
+use crate::vector::vector::Sqrt;
+use crate::vector::vectorn::VectorN;
+
+pub use num::complex::{Complex, Complex32, Complex64};
+
+impl<T: Float> Sqrt for Complex<T> {
+    /// principal square root
+    #[inline]
+    fn sqrt(self) -> Self {
+        Complex::sqrt(self)
+    }
+}
+
+impl<T: Float> VectorN<Complex<T>> {
+    /// complex conjugate of every component
+    pub fn conj(&self) -> Self {
+        self.iter().map(|z| z.conj()).collect()
+    }
+
+    /// hermitian inner product Σ conj(aᵢ)·bᵢ, panics on different lengths
+    pub fn hermitian_dot(a: &Self, b: &Self) -> Complex<T> {
+        Self::check_len(a, b).unwrap_or_else(|e| panic!("{}", e));
+        a.iter().zip(b.iter()).fold(Complex::new(T::zero(), T::zero()), |acc, (x, y)| acc + x.conj() * y)
+    }
+
+    /// hermitian length √(Σ |zᵢ|²)
+    pub fn norm(&self) -> T {
+        self.iter().fold(T::zero(), |acc, z| acc + z.norm_sqr()).sqrt()
+    }
+
+    /// |zᵢ| of every component
+    pub fn magnitudes(&self) -> VectorN<T> {
+        self.iter().map(|z| z.norm()).collect()
+    }
+}
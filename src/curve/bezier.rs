@@ -0,0 +1,131 @@
+//! quadratic and cubic bézier curves on t ∈ [0, 1]
+use crate::polynomial::solve_quadratic;
+use super::{clamp, Curve, CurvePoint};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+}
+
+#[inline]
+fn lerp<P: CurvePoint>(a: P, b: P, t: f32) -> P {
+    a + (b - a) * t
+}
+
+/// grow the box (min, max) to hold p
+fn extend<P: CurvePoint>((mut min, mut max): (P, P), p: P) -> (P, P) {
+    for i in 0..P::DIM {
+        min[i] = min[i].min(p[i]);
+        max[i] = max[i].max(p[i]);
+    }
+    (min, max)
+}
+
+impl<P: CurvePoint> QuadraticBezier<P> {
+    pub fn new(p0: P, p1: P, p2: P) -> Self {
+        Self { p0, p1, p2 }
+    }
+
+    /// the two halves at t by de casteljau's construction
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = lerp(self.p0, self.p1, t);
+        let b = lerp(self.p1, self.p2, t);
+        let m = lerp(a, b, t);
+        (Self::new(self.p0, a, m), Self::new(m, b, self.p2))
+    }
+
+    /// tight axis aligned box (min, max) around the curve
+    pub fn bounding_box(&self) -> (P, P) {
+        let mut bb = extend((self.p0, self.p0), self.p2);
+        for i in 0..P::DIM {
+            // B'(t) is linear per component
+            let den = self.p0[i] - 2.0 * self.p1[i] + self.p2[i];
+            if den != 0.0 {
+                let t = (self.p0[i] - self.p1[i]) / den;
+                if t > 0.0 && t < 1.0 {
+                    bb = extend(bb, self.eval(t));
+                }
+            }
+        }
+        bb
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for QuadraticBezier<P> {
+    fn domain(&self) -> (f32, f32) {
+        (0.0, 1.0)
+    }
+
+    fn eval(&self, t: f32) -> P {
+        let t = clamp(t, self.domain());
+        let s = 1.0 - t;
+        self.p0 * (s * s) + self.p1 * (2.0 * s * t) + self.p2 * (t * t)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let t = clamp(t, self.domain());
+        (self.p1 - self.p0) * (2.0 * (1.0 - t)) + (self.p2 - self.p1) * (2.0 * t)
+    }
+}
+
+impl<P: CurvePoint> CubicBezier<P> {
+    pub fn new(p0: P, p1: P, p2: P, p3: P) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    /// the two halves at t by de casteljau's construction
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = lerp(self.p0, self.p1, t);
+        let b = lerp(self.p1, self.p2, t);
+        let c = lerp(self.p2, self.p3, t);
+        let ab = lerp(a, b, t);
+        let bc = lerp(b, c, t);
+        let m = lerp(ab, bc, t);
+        (Self::new(self.p0, a, ab, m), Self::new(m, bc, c, self.p3))
+    }
+
+    /// tight axis aligned box (min, max) around the curve
+    pub fn bounding_box(&self) -> (P, P) {
+        let mut bb = extend((self.p0, self.p0), self.p3);
+        for i in 0..P::DIM {
+            // B'(t)/3 = (a - 2b + c) t² + 2(b - a) t + a per component
+            let a = self.p1[i] - self.p0[i];
+            let b = self.p2[i] - self.p1[i];
+            let c = self.p3[i] - self.p2[i];
+            for t in solve_quadratic(a - 2.0 * b + c, 2.0 * (b - a), a) {
+                if t > 0.0 && t < 1.0 {
+                    bb = extend(bb, self.eval(t));
+                }
+            }
+        }
+        bb
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for CubicBezier<P> {
+    fn domain(&self) -> (f32, f32) {
+        (0.0, 1.0)
+    }
+
+    fn eval(&self, t: f32) -> P {
+        let t = clamp(t, self.domain());
+        let s = 1.0 - t;
+        self.p0 * (s * s * s) + self.p1 * (3.0 * s * s * t) + self.p2 * (3.0 * s * t * t) + self.p3 * (t * t * t)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let t = clamp(t, self.domain());
+        let s = 1.0 - t;
+        (self.p1 - self.p0) * (3.0 * s * s) + (self.p2 - self.p1) * (6.0 * s * t) + (self.p3 - self.p2) * (3.0 * t * t)
+    }
+}
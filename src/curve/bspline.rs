@@ -0,0 +1,224 @@
+//! b-splines and NURBS evaluated by de boor's algorithm
+//!
+//! a spline of degree p with n control points has n + p + 1 knots and is
+//! defined on [knots[p], knots[n]].
+use std::ops::{Add,Sub,Mul};
+use super::{clamp, Curve, CurveError, CurvePoint};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSpline<P> {
+    degree: usize,
+    control: Vec<P>,
+    knots: Vec<f32>,
+}
+
+/// rational b-spline, every control point carries a weight
+#[derive(Debug, Clone, PartialEq)]
+pub struct Nurbs<P> {
+    degree: usize,
+    /// control points premultiplied by their weights
+    control: Vec<Weighted<P>>,
+    knots: Vec<f32>,
+}
+
+/// homogeneous control point (w·P, w)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Weighted<P> {
+    p: P,
+    w: f32,
+}
+
+impl<P: CurvePoint> Add for Weighted<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Weighted { p: self.p + rhs.p, w: self.w + rhs.w }
+    }
+}
+
+impl<P: CurvePoint> Sub for Weighted<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Weighted { p: self.p - rhs.p, w: self.w - rhs.w }
+    }
+}
+
+impl<P: CurvePoint> Mul<f32> for Weighted<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Weighted { p: self.p * rhs, w: self.w * rhs }
+    }
+}
+
+/// check the knot vector against degree and control point count
+fn check(degree: usize, points: usize, knots: &[f32]) -> Result<(), CurveError> {
+    if points < degree + 1 {
+        return Err(CurveError::NotEnoughPoints { needed: degree + 1, found: points });
+    }
+    if knots.len() != points + degree + 1 {
+        return Err(CurveError::CountMismatch { expected: points + degree + 1, found: knots.len() });
+    }
+    if let Some((index, &knot)) = knots.iter().enumerate().find(|(_, k)| !k.is_finite()) {
+        return Err(CurveError::InvalidKnot { index, knot });
+    }
+    if knots.windows(2).any(|w| w[0] > w[1]) || knots[degree] >= knots[points] {
+        return Err(CurveError::DecreasingKnots);
+    }
+    Ok(())
+}
+
+/// clamped uniform knots on [0, 1]: degree + 1 copies of each end and even steps between
+fn clamped_uniform(degree: usize, points: usize) -> Vec<f32> {
+    let spans = points.saturating_sub(degree).max(1);
+    let mut knots = vec![0.0; degree + 1];
+    knots.extend((1..spans).map(|i| i as f32 / spans as f32));
+    knots.resize(knots.len() + degree + 1, 1.0);
+    knots
+}
+
+/// knot span k with knots[k] <= t < knots[k + 1], the last nonempty span at the end
+fn span(degree: usize, knots: &[f32], points: usize, t: f32) -> usize {
+    let mut k = points - 1;
+    while k > degree && (knots[k] > t || knots[k] == knots[k + 1]) {
+        k -= 1;
+    }
+    k
+}
+
+fn de_boor<T>(degree: usize, knots: &[f32], control: &[T], t: f32) -> T
+where
+    T: Copy + Add<Output=T> + Mul<f32, Output=T>,
+{
+    let k = span(degree, knots, control.len(), t);
+    let mut d: Vec<T> = control[k - degree..=k].to_vec();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let lo = knots[j + k - degree];
+            let den = knots[j + 1 + k - r] - lo;
+            let alpha = if den > 0.0 { (t - lo) / den } else { 0.0 };
+            d[j] = d[j - 1] * (1.0 - alpha) + d[j] * alpha;
+        }
+    }
+    d[degree]
+}
+
+/// control points of the derivative spline, of one degree less on knots[1..len-1]
+fn derivative_control<T>(degree: usize, knots: &[f32], control: &[T]) -> Vec<T>
+where
+    T: Copy + Sub<Output=T> + Mul<f32, Output=T>,
+{
+    control.windows(2).enumerate().map(|(i, w)| {
+        let den = knots[i + degree + 1] - knots[i + 1];
+        let s = if den > 0.0 { degree as f32 / den } else { 0.0 };
+        (w[1] - w[0]) * s
+    }).collect()
+}
+
+fn derivative_at<T>(degree: usize, knots: &[f32], control: &[T], zero: T, t: f32) -> T
+where
+    T: Copy + Add<Output=T> + Sub<Output=T> + Mul<f32, Output=T>,
+{
+    if degree == 0 {
+        return zero;
+    }
+    let q = derivative_control(degree, knots, control);
+    de_boor(degree - 1, &knots[1..knots.len() - 1], &q, t)
+}
+
+impl<P: CurvePoint> BSpline<P> {
+    /// spline on an explicit, possibly non-uniform, knot vector
+    pub fn new(degree: usize, control: Vec<P>, knots: Vec<f32>) -> Result<Self, CurveError> {
+        check(degree, control.len(), &knots)?;
+        Ok(BSpline { degree, control, knots })
+    }
+
+    /// clamped uniform spline on [0, 1], it starts and ends at the end control points
+    pub fn uniform(degree: usize, control: Vec<P>) -> Result<Self, CurveError> {
+        let knots = clamped_uniform(degree, control.len());
+        Self::new(degree, control, knots)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn control_points(&self) -> &[P] {
+        &self.control
+    }
+
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for BSpline<P> {
+    fn domain(&self) -> (f32, f32) {
+        (self.knots[self.degree], self.knots[self.control.len()])
+    }
+
+    fn eval(&self, t: f32) -> P {
+        de_boor(self.degree, &self.knots, &self.control, clamp(t, self.domain()))
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        derivative_at(self.degree, &self.knots, &self.control, P::zero(), clamp(t, self.domain()))
+    }
+}
+
+impl<P: CurvePoint> Nurbs<P> {
+    /// NURBS on an explicit knot vector, fails unless every weight is positive and finite
+    pub fn new(degree: usize, control: Vec<P>, weights: Vec<f32>, knots: Vec<f32>) -> Result<Self, CurveError> {
+        check(degree, control.len(), &knots)?;
+        if weights.len() != control.len() {
+            return Err(CurveError::CountMismatch { expected: control.len(), found: weights.len() });
+        }
+        if let Some((index, &weight)) = weights.iter().enumerate().find(|(_, w)| !(**w > 0.0 && w.is_finite())) {
+            return Err(CurveError::InvalidWeight { index, weight });
+        }
+        let control = control.into_iter().zip(weights).map(|(p, w)| Weighted { p: p * w, w }).collect();
+        Ok(Nurbs { degree, control, knots })
+    }
+
+    /// clamped uniform NURBS on [0, 1]
+    pub fn uniform(degree: usize, control: Vec<P>, weights: Vec<f32>) -> Result<Self, CurveError> {
+        let knots = clamped_uniform(degree, control.len());
+        Self::new(degree, control, weights, knots)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn knots(&self) -> &[f32] {
+        &self.knots
+    }
+
+    /// control point i and its weight
+    pub fn control_point(&self, i: usize) -> (P, f32) {
+        let c = self.control[i];
+        (c.p * (1.0 / c.w), c.w)
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for Nurbs<P> {
+    fn domain(&self) -> (f32, f32) {
+        (self.knots[self.degree], self.knots[self.control.len()])
+    }
+
+    fn eval(&self, t: f32) -> P {
+        let h = de_boor(self.degree, &self.knots, &self.control, clamp(t, self.domain()));
+        h.p * (1.0 / h.w)
+    }
+
+    /// C' = (A' - w' C) / w for the homogeneous curve (A, w)
+    fn derivative(&self, t: f32) -> P {
+        let t = clamp(t, self.domain());
+        let h = de_boor(self.degree, &self.knots, &self.control, t);
+        let zero = Weighted { p: P::zero(), w: 0.0 };
+        let dh = derivative_at(self.degree, &self.knots, &self.control, zero, t);
+        let c = h.p * (1.0 / h.w);
+        (dh.p - c * dh.w) * (1.0 / h.w)
+    }
+}
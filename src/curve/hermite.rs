@@ -0,0 +1,142 @@
+//! cubic hermite and catmull-rom splines
+//!
+//! both are defined on [0, n - 1] for n points, segment i covers [i, i + 1]
+//! and the curve passes through point i at t = i.
+use super::{clamp, Curve, CurveError, CurvePoint};
+
+/// cubic hermite spline through points with given tangents
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hermite<P> {
+    points: Vec<P>,
+    tangents: Vec<P>,
+}
+
+/// catmull-rom spline through points
+///
+/// alpha picks the knot spacing: 0 uniform, 0.5 centripetal (no cusps or
+/// self intersections inside a segment), 1 chordal. the ends are extended
+/// by mirroring so the curve passes through every point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRom<P> {
+    points: Vec<P>,
+    /// outgoing and incoming tangent of every segment
+    tangents: Vec<(P, P)>,
+}
+
+/// segment index and local parameter for t on [0, n - 1]
+fn segment(t: f32, n: usize) -> (usize, f32) {
+    let t = clamp(t, (0.0, (n - 1) as f32));
+    let i = (t.floor() as usize).min(n - 2);
+    (i, t - i as f32)
+}
+
+fn hermite<P: CurvePoint>(p0: P, m0: P, p1: P, m1: P, u: f32) -> P {
+    let u2 = u * u;
+    let u3 = u2 * u;
+    p0 * (2.0 * u3 - 3.0 * u2 + 1.0) + m0 * (u3 - 2.0 * u2 + u) + p1 * (3.0 * u2 - 2.0 * u3) + m1 * (u3 - u2)
+}
+
+fn hermite_derivative<P: CurvePoint>(p0: P, m0: P, p1: P, m1: P, u: f32) -> P {
+    let u2 = u * u;
+    p0 * (6.0 * u2 - 6.0 * u) + m0 * (3.0 * u2 - 4.0 * u + 1.0) + p1 * (6.0 * u - 6.0 * u2) + m1 * (3.0 * u2 - 2.0 * u)
+}
+
+impl<P: CurvePoint> Hermite<P> {
+    pub fn new(points: Vec<P>, tangents: Vec<P>) -> Result<Self, CurveError> {
+        if points.len() < 2 {
+            return Err(CurveError::NotEnoughPoints { needed: 2, found: points.len() });
+        }
+        if tangents.len() != points.len() {
+            return Err(CurveError::CountMismatch { expected: points.len(), found: tangents.len() });
+        }
+        Ok(Hermite { points, tangents })
+    }
+
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    pub fn tangents(&self) -> &[P] {
+        &self.tangents
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for Hermite<P> {
+    fn domain(&self) -> (f32, f32) {
+        (0.0, (self.points.len() - 1) as f32)
+    }
+
+    fn eval(&self, t: f32) -> P {
+        let (i, u) = segment(t, self.points.len());
+        hermite(self.points[i], self.tangents[i], self.points[i + 1], self.tangents[i + 1], u)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let (i, u) = segment(t, self.points.len());
+        hermite_derivative(self.points[i], self.tangents[i], self.points[i + 1], self.tangents[i + 1], u)
+    }
+}
+
+impl<P: CurvePoint> CatmullRom<P> {
+    /// uniform catmull-rom
+    pub fn new(points: Vec<P>) -> Result<Self, CurveError> {
+        Self::with_alpha(points, 0.0)
+    }
+
+    /// centripetal catmull-rom, alpha = 0.5
+    pub fn centripetal(points: Vec<P>) -> Result<Self, CurveError> {
+        Self::with_alpha(points, 0.5)
+    }
+
+    pub fn with_alpha(points: Vec<P>, alpha: f32) -> Result<Self, CurveError> {
+        let n = points.len();
+        if n < 2 {
+            return Err(CurveError::NotEnoughPoints { needed: 2, found: n });
+        }
+        let at = |i: isize| -> P {
+            if i < 0 {
+                points[0] * 2.0 - points[1]
+            } else if i as usize >= n {
+                points[n - 1] * 2.0 - points[n - 2]
+            } else {
+                points[i as usize]
+            }
+        };
+        // knot interval between two points, |Δ|^alpha, never zero
+        let dt = |a: P, b: P| {
+            let d = (b - a).length().powf(alpha);
+            if d > f32::EPSILON { d } else { 1.0 }
+        };
+        let tangents = (0..n as isize - 1).map(|i| {
+            let (p0, p1, p2, p3) = (at(i - 1), at(i), at(i + 1), at(i + 2));
+            let (d0, d1, d2) = (dt(p0, p1), dt(p1, p2), dt(p2, p3));
+            // tangents of the non-uniform catmull-rom segment, scaled to a unit parameter step
+            let m1 = ((p1 - p0) * (1.0 / d0) - (p2 - p0) * (1.0 / (d0 + d1)) + (p2 - p1) * (1.0 / d1)) * d1;
+            let m2 = ((p2 - p1) * (1.0 / d1) - (p3 - p1) * (1.0 / (d1 + d2)) + (p3 - p2) * (1.0 / d2)) * d1;
+            (m1, m2)
+        }).collect();
+        Ok(CatmullRom { points, tangents })
+    }
+
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for CatmullRom<P> {
+    fn domain(&self) -> (f32, f32) {
+        (0.0, (self.points.len() - 1) as f32)
+    }
+
+    fn eval(&self, t: f32) -> P {
+        let (i, u) = segment(t, self.points.len());
+        let (m0, m1) = self.tangents[i];
+        hermite(self.points[i], m0, self.points[i + 1], m1, u)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let (i, u) = segment(t, self.points.len());
+        let (m0, m1) = self.tangents[i];
+        hermite_derivative(self.points[i], m0, self.points[i + 1], m1, u)
+    }
+}
@@ -0,0 +1,229 @@
+//! parametric curves over `Vector2` and `Vector3`
+//!
+//! every curve implements `Curve`, which gives arc length and closest point
+//! queries on top of evaluation and the first derivative.
+use std::fmt;
+use std::ops::{Add,Sub,Mul,Index,IndexMut};
+use crate::vector::{Vector2, Vector3};
+
+pub mod bezier;
+pub mod bspline;
+pub mod hermite;
+
+pub use bezier::{QuadraticBezier, CubicBezier};
+pub use bspline::{BSpline, Nurbs};
+pub use hermite::{Hermite, CatmullRom};
+
+/// a point type curves can be built from
+pub trait CurvePoint:
+    Copy + Add<Output=Self> + Sub<Output=Self> + Mul<f32, Output=Self>
+    + Index<usize, Output=f32> + IndexMut<usize>
+{
+    /// number of components
+    const DIM: usize;
+
+    fn zero() -> Self;
+
+    fn dot(a: Self, b: Self) -> f32;
+
+    fn length(&self) -> f32 {
+        Self::dot(*self, *self).sqrt()
+    }
+}
+
+impl CurvePoint for Vector2 {
+    const DIM: usize = 2;
+
+    fn zero() -> Self {
+        Vector2::default()
+    }
+
+    fn dot(a: Self, b: Self) -> f32 {
+        Vector2::dot(a, b)
+    }
+}
+
+impl CurvePoint for Vector3 {
+    const DIM: usize = 3;
+
+    fn zero() -> Self {
+        Vector3::default()
+    }
+
+    fn dot(a: Self, b: Self) -> f32 {
+        Vector3::dot(a, b)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CurveError {
+    /// fewer control points than the curve needs
+    NotEnoughPoints { needed: usize, found: usize },
+    /// knots, weights or tangents don't match the number of control points
+    CountMismatch { expected: usize, found: usize },
+    /// the knot vector decreases somewhere
+    DecreasingKnots,
+    /// a knot is infinite or nan
+    InvalidKnot { index: usize, knot: f32 },
+    /// a NURBS weight is zero, negative or not finite
+    InvalidWeight { index: usize, weight: f32 },
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CurveError::NotEnoughPoints { needed, found } => write!(f, "curve needs at least {} points, found {}", needed, found),
+            CurveError::CountMismatch { expected, found } => write!(f, "expected {} values, found {}", expected, found),
+            CurveError::DecreasingKnots => write!(f, "knot vector is decreasing"),
+            CurveError::InvalidKnot { index, knot } => write!(f, "knot {} is {}, expected a finite number", index, knot),
+            CurveError::InvalidWeight { index, weight } => write!(f, "weight {} is {}, expected a positive number", index, weight),
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
+
+/// intervals the domain is split into for arc length and closest point queries
+const SEGMENTS: usize = 32;
+const CLOSEST_SAMPLES: usize = 128;
+const GOLDEN_STEPS: usize = 40;
+
+const GAUSS_NODES: [f32; 5] = [0.0, -0.538_469_3, 0.538_469_3, -0.906_179_85, 0.906_179_85];
+const GAUSS_WEIGHTS: [f32; 5] = [0.568_888_9, 0.478_628_67, 0.478_628_67, 0.236_926_88, 0.236_926_88];
+
+/// a curve C(t) over the parameter interval `domain()`
+pub trait Curve<P: CurvePoint> {
+    /// parameter interval, the curve is evaluated with t clamped into it
+    fn domain(&self) -> (f32, f32);
+
+    fn eval(&self, t: f32) -> P;
+
+    /// dC/dt
+    fn derivative(&self, t: f32) -> P;
+
+    /// length of the curve between parameters a and b, by 5 point gauss-legendre on 32 pieces
+    fn length_between(&self, a: f32, b: f32) -> f32 {
+        let h = (b - a) / SEGMENTS as f32;
+        (0..SEGMENTS).map(|i| {
+            let lo = a + h * i as f32;
+            gauss_length(self, lo, lo + h)
+        }).sum()
+    }
+
+    /// length of the whole curve
+    fn length(&self) -> f32 {
+        let (a, b) = self.domain();
+        self.length_between(a, b)
+    }
+
+    /// parameter and point of the curve closest to p
+    ///
+    /// samples the curve and refines around the nearest sample, so a curve
+    /// with many tight loops may need `closest_point_with` and more samples.
+    fn closest_point(&self, p: P) -> (f32, P) {
+        self.closest_point_with(p, CLOSEST_SAMPLES)
+    }
+
+    fn closest_point_with(&self, p: P, samples: usize) -> (f32, P) {
+        let (a, b) = self.domain();
+        let samples = samples.max(2);
+        let h = (b - a) / (samples - 1) as f32;
+        let dist = |t: f32| {
+            let d = self.eval(t) - p;
+            P::dot(d, d)
+        };
+        let best = (0..samples)
+            .map(|i| (i, dist(a + h * i as f32)))
+            .fold((0, f32::INFINITY), |acc, x| if x.1 < acc.1 { x } else { acc })
+            .0;
+        // golden section search over the neighbouring intervals
+        let ratio = (5f32.sqrt() - 1.0) / 2.0;
+        let mut lo = (a + h * best as f32 - h).max(a);
+        let mut hi = (a + h * best as f32 + h).min(b);
+        let mut x1 = hi - ratio * (hi - lo);
+        let mut x2 = lo + ratio * (hi - lo);
+        let (mut f1, mut f2) = (dist(x1), dist(x2));
+        for _ in 0..GOLDEN_STEPS {
+            if f1 < f2 {
+                hi = x2;
+                x2 = x1;
+                f2 = f1;
+                x1 = hi - ratio * (hi - lo);
+                f1 = dist(x1);
+            } else {
+                lo = x1;
+                x1 = x2;
+                f1 = f2;
+                x2 = lo + ratio * (hi - lo);
+                f2 = dist(x2);
+            }
+        }
+        let t = (lo + hi) / 2.0;
+        (t, self.eval(t))
+    }
+}
+
+fn gauss_length<P: CurvePoint, C: Curve<P> + ?Sized>(curve: &C, a: f32, b: f32) -> f32 {
+    let half = (b - a) / 2.0;
+    let mid = (a + b) / 2.0;
+    GAUSS_NODES.iter().zip(GAUSS_WEIGHTS.iter())
+        .map(|(x, w)| w * curve.derivative(mid + half * x).length())
+        .sum::<f32>() * half
+}
+
+/// table from arc length to curve parameter
+///
+/// moving along the curve at constant speed means evaluating it at
+/// `param_at(s)` for evenly spaced s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable {
+    params: Vec<f32>,
+    lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    /// sample the curve at `samples` evenly spaced parameters
+    pub fn new<P: CurvePoint, C: Curve<P> + ?Sized>(curve: &C, samples: usize) -> Self {
+        let samples = samples.max(2);
+        let (a, b) = curve.domain();
+        let h = (b - a) / (samples - 1) as f32;
+        let params: Vec<f32> = (0..samples).map(|i| a + h * i as f32).collect();
+        let mut lengths = Vec::with_capacity(samples);
+        let mut s = 0.0;
+        lengths.push(s);
+        for w in params.windows(2) {
+            s += gauss_length(curve, w[0], w[1]);
+            lengths.push(s);
+        }
+        ArcLengthTable { params, lengths }
+    }
+
+    /// total arc length
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// parameter at arc length s from the start, s is clamped to the curve
+    pub fn param_at(&self, s: f32) -> f32 {
+        let s = s.max(0.0).min(self.length());
+        let i = self.lengths.partition_point(|&l| l <= s).clamp(1, self.lengths.len() - 1);
+        let (l0, l1) = (self.lengths[i - 1], self.lengths[i]);
+        let (t0, t1) = (self.params[i - 1], self.params[i]);
+        if l1 > l0 {
+            t0 + (t1 - t0) * (s - l0) / (l1 - l0)
+        } else {
+            t0
+        }
+    }
+
+    /// parameter at a fraction u in [0, 1] of the total length
+    pub fn param_at_fraction(&self, u: f32) -> f32 {
+        self.param_at(u * self.length())
+    }
+}
+
+/// clamp t into [a, b]
+#[inline]
+fn clamp(t: f32, (a, b): (f32, f32)) -> f32 {
+    t.max(a).min(b)
+}
@@ -0,0 +1,126 @@
+//! shared `Display` layout for vectors and matrices
+//!
+//! every element is printed with the precision and `+` flag of the format
+//! spec, then padded to the spec's width (right aligned unless `<` or `^`
+//! is given). matrix columns are additionally padded to their widest
+//! element so rows line up. the alternate flag `{:#}` prints a matrix on a
+//! single line.
+//!
+//! - vector: `(1.00, -2.00, 3.00)`
+//! - matrix: one `[a, b, c]` line per row, `{:#}` gives `[[a, b], [c, d]]`
+//! - sparse matrix: a `n x m <format>, k nonzeros` header and one
+//!   `(i, j) value` line per stored entry, `{:#}` gives `{(i, j): value, ..}`
+//!
+//! the functions are public for the `matrix_n!` macro, not as api.
+#![doc(hidden)]
+use std::fmt::{self, Alignment, Display, Formatter, Write};
+
+/// an element formatted with the precision and sign flag of f
+fn cell<T: Display + ?Sized>(f: &Formatter, x: &T) -> String {
+    match (f.precision(), f.sign_plus()) {
+        (Some(p), true) => format!("{:+.*}", p, x),
+        (Some(p), false) => format!("{:.*}", p, x),
+        (None, true) => format!("{:+}", x),
+        (None, false) => format!("{}", x),
+    }
+}
+
+/// write s padded to width with the alignment of f
+fn pad(f: &mut Formatter, s: &str, width: usize) -> fmt::Result {
+    let fill = width.saturating_sub(s.chars().count());
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, fill),
+        Some(Alignment::Center) => (fill / 2, fill - fill / 2),
+        _ => (fill, 0),
+    };
+    let c = f.fill();
+    for _ in 0..before {
+        f.write_char(c)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+/// write items as `(a, b, c)`
+pub fn write_vector<'a, T: Display + 'a>(f: &mut Formatter, items: impl IntoIterator<Item = &'a T>) -> fmt::Result {
+    let width = f.width().unwrap_or(0);
+    f.write_char('(')?;
+    for (k, x) in items.into_iter().enumerate() {
+        if k > 0 {
+            f.write_str(", ")?;
+        }
+        let s = cell(f, x);
+        pad(f, &s, width)?;
+    }
+    f.write_char(')')
+}
+
+/// write an n x m matrix given element (i, j), one row per line
+pub fn write_matrix<'a, T: Display + ?Sized + 'a>(f: &mut Formatter, n: usize, m: usize, at: impl Fn(usize, usize) -> &'a T) -> fmt::Result {
+    if n == 0 || m == 0 {
+        return f.write_str("[]");
+    }
+    let cells: Vec<String> = (0..n * m).map(|k| cell(f, at(k / m, k % m))).collect();
+    let min = f.width().unwrap_or(0);
+    let compact = f.alternate();
+    let widths: Vec<usize> = if compact {
+        vec![min; m]
+    } else {
+        (0..m).map(|j| (0..n).map(|i| cells[i * m + j].chars().count()).fold(min, usize::max)).collect()
+    };
+
+    if compact {
+        f.write_char('[')?;
+    }
+    for i in 0..n {
+        if i > 0 {
+            f.write_str(if compact { ", " } else { "\n" })?;
+        }
+        f.write_char('[')?;
+        for j in 0..m {
+            if j > 0 {
+                f.write_str(", ")?;
+            }
+            pad(f, &cells[i * m + j], widths[j])?;
+        }
+        f.write_char(']')?;
+    }
+    if compact {
+        f.write_char(']')?;
+    }
+    Ok(())
+}
+
+/// write the stored entries of a sparse matrix in the given order
+pub fn write_sparse<T: Display>(
+    f: &mut Formatter,
+    kind: &str,
+    shape: (usize, usize),
+    entries: impl Iterator<Item = (usize, usize, T)>,
+) -> fmt::Result {
+    let entries: Vec<(String, String)> = entries.map(|(i, j, v)| (format!("({}, {})", i, j), cell(f, &v))).collect();
+    if f.alternate() {
+        let width = f.width().unwrap_or(0);
+        f.write_char('{')?;
+        for (k, (at, v)) in entries.iter().enumerate() {
+            if k > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}: ", at)?;
+            pad(f, v, width)?;
+        }
+        return f.write_char('}');
+    }
+
+    write!(f, "{} x {} {}, {} nonzeros", shape.0, shape.1, kind, entries.len())?;
+    let at_width = entries.iter().map(|(at, _)| at.len()).max().unwrap_or(0);
+    let width = entries.iter().map(|(_, v)| v.chars().count()).fold(f.width().unwrap_or(0), usize::max);
+    for (at, v) in entries.iter() {
+        write!(f, "\n{:<w$} ", at, w = at_width)?;
+        pad(f, v, width)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,215 @@
+//! fast fourier transforms
+//!
+//! the forward transform is X[k] = Σ x[j]·e^(-2πi·jk/n), the inverse divides
+//! by n so `inverse(forward(x)) == x`. powers of two use an iterative
+//! radix-2 transform, other sizes a mixed radix one, and sizes with a large
+//! prime factor bluestein's algorithm on top of radix-2.
+use num::Float;
+use crate::complex::Complex;
+use crate::vector::vectorn::VectorN;
+
+pub mod real;
+
+pub use real::{convolve, convolve_complex, irfft, rfft};
+
+/// largest prime factor handled by the mixed radix butterflies, O(n·p) each
+const MAX_RADIX: usize = 61;
+
+/// a transform of one size with its twiddle factors precomputed
+#[derive(Debug, Clone)]
+pub struct Fft<T> {
+    n: usize,
+    algorithm: Algorithm<T>,
+}
+
+#[derive(Debug, Clone)]
+enum Algorithm<T> {
+    /// n ≤ 1, nothing to do
+    Identity,
+    Radix2 { twiddles: Vec<Complex<T>> },
+    MixedRadix { factors: Vec<usize>, twiddles: Vec<Complex<T>> },
+    Bluestein { chirp: Vec<Complex<T>>, kernel: Vec<Complex<T>>, inner: Box<Fft<T>> },
+}
+
+/// e^(-2πi·k/n)
+fn root<T: Float>(k: usize, n: usize) -> Complex<T> {
+    let a = -2.0 * std::f64::consts::PI * k as f64 / n as f64;
+    Complex::new(T::from(a.cos()).unwrap(), T::from(a.sin()).unwrap())
+}
+
+/// prime factors, smallest first, fours before twos
+// is_multiple_of needs rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn factorize(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    while n % 4 == 0 {
+        factors.push(4);
+        n /= 4;
+    }
+    let mut p = 2;
+    while p * p <= n {
+        while n % p == 0 {
+            factors.push(p);
+            n /= p;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+impl<T: Float> Fft<T> {
+    pub fn new(n: usize) -> Self {
+        let algorithm = if n <= 1 {
+            Algorithm::Identity
+        } else if n.is_power_of_two() {
+            Algorithm::Radix2 { twiddles: (0..n / 2).map(|k| root(k, n)).collect() }
+        } else {
+            let factors = factorize(n);
+            if factors.iter().all(|&p| p <= MAX_RADIX) {
+                Algorithm::MixedRadix { factors, twiddles: (0..n).map(|k| root(k, n)).collect() }
+            } else {
+                Self::bluestein(n)
+            }
+        };
+        Fft { n, algorithm }
+    }
+
+    fn bluestein(n: usize) -> Algorithm<T> {
+        let m = (2 * n - 1).next_power_of_two();
+        // e^(-πi·k²/n), k² taken mod 2n to keep the angle small
+        let chirp: Vec<Complex<T>> = (0..n).map(|k| root((k * k) % (2 * n), 2 * n)).collect();
+        let inner = Fft::new(m);
+        let mut kernel = vec![Complex::new(T::zero(), T::zero()); m];
+        kernel[0] = chirp[0].conj();
+        for k in 1..n {
+            kernel[k] = chirp[k].conj();
+            kernel[m - k] = chirp[k].conj();
+        }
+        inner.forward(&mut kernel);
+        Algorithm::Bluestein { chirp, kernel, inner: Box::new(inner) }
+    }
+
+    /// the transform size
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// forward transform in place, panics if data isn't `len()` long
+    pub fn forward(&self, data: &mut [Complex<T>]) {
+        assert_eq!(data.len(), self.n, "fft size mismatch");
+        match &self.algorithm {
+            Algorithm::Identity => {}
+            Algorithm::Radix2 { twiddles } => radix2(data, twiddles),
+            Algorithm::MixedRadix { factors, twiddles } => {
+                let input = data.to_vec();
+                mixed_radix(data, &input, 1, factors, twiddles, 1);
+            }
+            Algorithm::Bluestein { chirp, kernel, inner } => {
+                let m = kernel.len();
+                let mut a = vec![Complex::new(T::zero(), T::zero()); m];
+                for k in 0..self.n {
+                    a[k] = data[k] * chirp[k];
+                }
+                inner.forward(&mut a);
+                for (x, k) in a.iter_mut().zip(kernel.iter()) {
+                    *x = *x * k;
+                }
+                inner.inverse(&mut a);
+                for k in 0..self.n {
+                    data[k] = a[k] * chirp[k];
+                }
+            }
+        }
+    }
+
+    /// inverse transform in place, scaled by 1/n
+    pub fn inverse(&self, data: &mut [Complex<T>]) {
+        // ifft(x) = conj(fft(conj(x))) / n
+        for x in data.iter_mut() {
+            *x = x.conj();
+        }
+        self.forward(data);
+        let scale = T::one() / T::from(self.n.max(1)).unwrap();
+        for x in data.iter_mut() {
+            *x = x.conj() * scale;
+        }
+    }
+}
+
+/// iterative cooley-tukey on a power of two
+fn radix2<T: Float>(data: &mut [Complex<T>], twiddles: &[Complex<T>]) {
+    let n = data.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let stride = n / len;
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                let t = data[start + k + half] * twiddles[k * stride];
+                let u = data[start + k];
+                data[start + k] = u + t;
+                data[start + k + half] = u - t;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// recursive decimation in time: out gets the transform of input[0], input[stride], ...
+///
+/// twiddles holds the n-th roots of unity of the full transform, tw_stride
+/// maps them to the size of this level.
+fn mixed_radix<T: Float>(out: &mut [Complex<T>], input: &[Complex<T>], stride: usize, factors: &[usize], twiddles: &[Complex<T>], tw_stride: usize) {
+    let p = factors[0];
+    let m = out.len() / p;
+    if m == 1 {
+        for (q, o) in out.iter_mut().enumerate() {
+            *o = input[q * stride];
+        }
+    } else {
+        for r in 0..p {
+            mixed_radix(&mut out[r * m..(r + 1) * m], &input[r * stride..], stride * p, &factors[1..], twiddles, tw_stride * p);
+        }
+    }
+    // combine p transforms of length m
+    let n = twiddles.len();
+    let mut tmp = vec![Complex::new(T::zero(), T::zero()); p];
+    for k in 0..m {
+        for (r, t) in tmp.iter_mut().enumerate() {
+            *t = out[r * m + k] * twiddles[(r * k * tw_stride) % n];
+        }
+        for q in 0..p {
+            let w = m * tw_stride * q;
+            out[k + q * m] = tmp.iter().enumerate()
+                .fold(Complex::new(T::zero(), T::zero()), |acc, (r, t)| acc + t * twiddles[(r * w) % n]);
+        }
+    }
+}
+
+/// forward transform of x
+pub fn fft<T: Float>(x: &[Complex<T>]) -> VectorN<Complex<T>> {
+    let mut data = x.to_vec();
+    Fft::new(data.len()).forward(&mut data);
+    VectorN::new(data)
+}
+
+/// inverse transform of x, scaled by 1/n
+pub fn ifft<T: Float>(x: &[Complex<T>]) -> VectorN<Complex<T>> {
+    let mut data = x.to_vec();
+    Fft::new(data.len()).inverse(&mut data);
+    VectorN::new(data)
+}
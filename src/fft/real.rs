@@ -0,0 +1,100 @@
+//! transforms of real signals and convolution
+use num::Float;
+use crate::complex::Complex;
+use crate::vector::vectorn::VectorN;
+use super::{root, Fft};
+
+/// spectrum of a real signal, the n/2 + 1 bins from 0 up to nyquist
+///
+/// the other half is the complex conjugate mirror image. even lengths run
+/// one complex transform of half the size.
+pub fn rfft<T: Float>(x: &[T]) -> VectorN<Complex<T>> {
+    let n = x.len();
+    let zero = Complex::new(T::zero(), T::zero());
+    if n % 2 == 1 || n == 0 {
+        let mut data: Vec<Complex<T>> = x.iter().map(|&v| Complex::new(v, T::zero())).collect();
+        Fft::new(n).forward(&mut data);
+        // an empty signal still has the one zero bin irfft expects
+        data.resize(n / 2 + 1, zero);
+        return VectorN::new(data);
+    }
+    // pack even and odd samples into one complex signal of half the length
+    let h = n / 2;
+    let mut z: Vec<Complex<T>> = (0..h).map(|k| Complex::new(x[2 * k], x[2 * k + 1])).collect();
+    Fft::new(h).forward(&mut z);
+    let half = T::from(0.5).unwrap();
+    let mut out = vec![zero; h + 1];
+    for (k, o) in out.iter_mut().enumerate() {
+        let a = z[k % h];
+        let b = z[(h - k) % h].conj();
+        let even = (a + b) * half;
+        // (a - b) / 2i
+        let odd = Complex::new(a.im - b.im, b.re - a.re) * half;
+        *o = even + root::<T>(k, n) * odd;
+    }
+    VectorN::new(out)
+}
+
+/// real signal of length n from its n/2 + 1 bin spectrum, inverse of `rfft`
+///
+/// panics if the spectrum doesn't have n/2 + 1 bins.
+pub fn irfft<T: Float>(spectrum: &[Complex<T>], n: usize) -> VectorN<T> {
+    assert_eq!(spectrum.len(), n / 2 + 1, "spectrum of a length {} signal needs {} bins", n, n / 2 + 1);
+    if n % 2 == 1 {
+        // rebuild the mirrored half
+        let mut data: Vec<Complex<T>> = (0..n).map(|k| if k <= n / 2 { spectrum[k] } else { spectrum[n - k].conj() }).collect();
+        Fft::new(n).inverse(&mut data);
+        return data.iter().map(|z| z.re).collect();
+    }
+    let h = n / 2;
+    let half = T::from(0.5).unwrap();
+    let mut z: Vec<Complex<T>> = (0..h).map(|k| {
+        let a = spectrum[k];
+        let b = spectrum[h - k].conj();
+        let even = (a + b) * half;
+        let odd = (a - b) * half / root::<T>(k, n);
+        // even + i·odd
+        Complex::new(even.re - odd.im, even.im + odd.re)
+    }).collect();
+    Fft::new(h).inverse(&mut z);
+    z.iter().flat_map(|c| [c.re, c.im]).collect()
+}
+
+/// full linear convolution of two real signals, a.len() + b.len() - 1 long
+pub fn convolve<T: Float>(a: &[T], b: &[T]) -> VectorN<T> {
+    if a.is_empty() || b.is_empty() {
+        return VectorN::default();
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two().max(2);
+    let pad = |x: &[T]| {
+        let mut v = x.to_vec();
+        v.resize(n, T::zero());
+        rfft(&v)
+    };
+    let (fa, fb) = (pad(a), pad(b));
+    let product: Vec<Complex<T>> = fa.iter().zip(fb.iter()).map(|(x, y)| x * y).collect();
+    let mut out = irfft(&product, n);
+    out.data.truncate(len);
+    out
+}
+
+/// full linear convolution of two complex signals
+pub fn convolve_complex<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> VectorN<Complex<T>> {
+    if a.is_empty() || b.is_empty() {
+        return VectorN::default();
+    }
+    let len = a.len() + b.len() - 1;
+    let plan = Fft::new(len.next_power_of_two());
+    let pad = |x: &[Complex<T>]| {
+        let mut v = x.to_vec();
+        v.resize(plan.len(), Complex::new(T::zero(), T::zero()));
+        plan.forward(&mut v);
+        v
+    };
+    let (fa, fb) = (pad(a), pad(b));
+    let mut product: Vec<Complex<T>> = fa.iter().zip(fb.iter()).map(|(x, y)| x * y).collect();
+    plan.inverse(&mut product);
+    product.truncate(len);
+    VectorN::new(product)
+}
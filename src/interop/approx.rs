@@ -0,0 +1,58 @@
+use crate::approx_eq::{ApproxEq, DEFAULT_MAX_ULPS};
+use crate::matrix::matrixnxm::Matrix;
+use crate::matrix::sparse::{CscMatrix, CsrMatrix};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use crate::vector::soa::Vector3x8;
+use crate::vector::vector::Vector;
+use crate::vector::vectorn::VectorN;
+use crate::vector::{Vector2, Vector3, Vector4};
+
+// forward the approx crate's traits to `ApproxEq`
+macro_rules! approx_bridge {
+    ([$($gen:tt)*] $t:ty) => (
+        impl<$($gen)*> ::approx::AbsDiffEq for $t {
+            type Epsilon = <$t as ApproxEq>::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$t as ApproxEq>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                ApproxEq::abs_diff_eq(self, other, epsilon)
+            }
+        }
+
+        impl<$($gen)*> ::approx::RelativeEq for $t {
+            fn default_max_relative() -> Self::Epsilon {
+                <$t as ApproxEq>::default_epsilon()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                ApproxEq::relative_eq(self, other, epsilon, max_relative)
+            }
+        }
+
+        impl<$($gen)*> ::approx::UlpsEq for $t {
+            fn default_max_ulps() -> u32 {
+                DEFAULT_MAX_ULPS
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                ApproxEq::ulps_eq(self, other, epsilon, max_ulps)
+            }
+        }
+    );
+}
+
+approx_bridge!([] Vector2);
+approx_bridge!([] Vector3);
+approx_bridge!([] Vector4);
+approx_bridge!([] Vector3x8);
+approx_bridge!([] Matrix2x2);
+approx_bridge!([] Matrix3x3);
+approx_bridge!([] Matrix4x4);
+approx_bridge!([T: ApproxEq + PartialEq, const N: usize] Vector<T, N>);
+approx_bridge!([T: ApproxEq + PartialEq] VectorN<T>);
+approx_bridge!([T: ApproxEq + PartialEq] Matrix<T>);
+approx_bridge!([T: ApproxEq + PartialEq] CsrMatrix<T>);
+approx_bridge!([T: ApproxEq + PartialEq] CscMatrix<T>);
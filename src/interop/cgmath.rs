@@ -0,0 +1,45 @@
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use super::transpose;
+
+macro_rules! cgmath_vector {
+    ($rmu:ident, $cg:ident, $($f:ident),*) => (
+        impl From<::cgmath::$cg<f32>> for $rmu {
+            fn from(v: ::cgmath::$cg<f32>) -> Self {
+                $rmu { $($f: v.$f),* }
+            }
+        }
+
+        impl From<$rmu> for ::cgmath::$cg<f32> {
+            fn from(v: $rmu) -> Self {
+                ::cgmath::$cg::new($(v.$f),*)
+            }
+        }
+    );
+}
+
+cgmath_vector!(Vector2, Vector2, x, y);
+cgmath_vector!(Vector3, Vector3, x, y, z);
+cgmath_vector!(Vector4, Vector4, x, y, z, w);
+
+// cgmath stores columns, its array conversions are column by column
+macro_rules! cgmath_matrix {
+    ($rmu:ident, $cg:ident, $n:expr) => (
+        impl From<::cgmath::$cg<f32>> for $rmu {
+            fn from(m: ::cgmath::$cg<f32>) -> Self {
+                let cols: [[f32;$n];$n] = m.into();
+                $rmu { data: transpose(cols) }
+            }
+        }
+
+        impl From<$rmu> for ::cgmath::$cg<f32> {
+            fn from(m: $rmu) -> Self {
+                transpose(m.data).into()
+            }
+        }
+    );
+}
+
+cgmath_matrix!(Matrix2x2, Matrix2, 2);
+cgmath_matrix!(Matrix3x3, Matrix3, 3);
+cgmath_matrix!(Matrix4x4, Matrix4, 4);
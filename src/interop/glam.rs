@@ -0,0 +1,44 @@
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use super::transpose;
+
+macro_rules! glam_vector {
+    ($rmu:ident, $glam:ident, $($f:ident),*) => (
+        impl From<::glam::$glam> for $rmu {
+            fn from(v: ::glam::$glam) -> Self {
+                $rmu { $($f: v.$f),* }
+            }
+        }
+
+        impl From<$rmu> for ::glam::$glam {
+            fn from(v: $rmu) -> Self {
+                ::glam::$glam::new($(v.$f),*)
+            }
+        }
+    );
+}
+
+glam_vector!(Vector2, Vec2, x, y);
+glam_vector!(Vector3, Vec3, x, y, z);
+glam_vector!(Vector4, Vec4, x, y, z, w);
+
+// glam stores columns
+macro_rules! glam_matrix {
+    ($rmu:ident, $glam:ident) => (
+        impl From<::glam::$glam> for $rmu {
+            fn from(m: ::glam::$glam) -> Self {
+                $rmu { data: transpose(m.to_cols_array_2d()) }
+            }
+        }
+
+        impl From<$rmu> for ::glam::$glam {
+            fn from(m: $rmu) -> Self {
+                ::glam::$glam::from_cols_array_2d(&transpose(m.data))
+            }
+        }
+    );
+}
+
+glam_matrix!(Matrix2x2, Mat2);
+glam_matrix!(Matrix3x3, Mat3);
+glam_matrix!(Matrix4x4, Mat4);
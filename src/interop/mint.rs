@@ -0,0 +1,65 @@
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use super::transpose;
+
+macro_rules! mint_vector {
+    ($rmu:ident, $mint:ident, $($f:ident),*) => (
+        impl From<::mint::$mint<f32>> for $rmu {
+            fn from(v: ::mint::$mint<f32>) -> Self {
+                $rmu { $($f: v.$f),* }
+            }
+        }
+
+        impl From<$rmu> for ::mint::$mint<f32> {
+            fn from(v: $rmu) -> Self {
+                ::mint::$mint { $($f: v.$f),* }
+            }
+        }
+
+        impl ::mint::IntoMint for $rmu {
+            type MintType = ::mint::$mint<f32>;
+        }
+    );
+}
+
+mint_vector!(Vector2, Vector2, x, y);
+mint_vector!(Vector3, Vector3, x, y, z);
+mint_vector!(Vector4, Vector4, x, y, z, w);
+
+macro_rules! mint_matrix {
+    ($rmu:ident, $row:ident, $col:ident, $n:expr) => (
+        impl From<::mint::$row<f32>> for $rmu {
+            fn from(m: ::mint::$row<f32>) -> Self {
+                let rows: [[f32;$n];$n] = m.into();
+                $rmu { data: rows }
+            }
+        }
+
+        impl From<$rmu> for ::mint::$row<f32> {
+            fn from(m: $rmu) -> Self {
+                m.data.into()
+            }
+        }
+
+        impl From<::mint::$col<f32>> for $rmu {
+            fn from(m: ::mint::$col<f32>) -> Self {
+                let cols: [[f32;$n];$n] = m.into();
+                $rmu { data: transpose(cols) }
+            }
+        }
+
+        impl From<$rmu> for ::mint::$col<f32> {
+            fn from(m: $rmu) -> Self {
+                transpose(m.data).into()
+            }
+        }
+
+        impl ::mint::IntoMint for $rmu {
+            type MintType = ::mint::$row<f32>;
+        }
+    );
+}
+
+mint_matrix!(Matrix2x2, RowMatrix2, ColumnMatrix2, 2);
+mint_matrix!(Matrix3x3, RowMatrix3, ColumnMatrix3, 3);
+mint_matrix!(Matrix4x4, RowMatrix4, ColumnMatrix4, 4);
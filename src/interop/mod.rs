@@ -0,0 +1,28 @@
+//! conversions to and from other math crates, and their comparison traits
+//!
+//! rmu matrices store rows and multiply column vectors, `m * v`. crates that
+//! store columns (glam, cgmath, mint's `ColumnMatrix*`) get the transpose of
+//! the storage, so the converted matrix maps vectors the same way.
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "cgmath")]
+mod cgmath;
+#[cfg(feature = "approx")]
+mod approx;
+
+/// transpose an n x n array, turning rows into columns and back
+#[cfg(any(feature = "mint", feature = "glam", feature = "cgmath"))]
+#[inline]
+fn transpose<const N: usize>(m: [[f32;N];N]) -> [[f32;N];N] {
+    let mut ret = [[0.0;N];N];
+    for (i, row) in m.iter().enumerate() {
+        for (j, e) in row.iter().enumerate() {
+            ret[j][i] = *e;
+        }
+    }
+    ret
+}
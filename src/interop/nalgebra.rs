@@ -0,0 +1,49 @@
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+
+macro_rules! nalgebra_vector {
+    ($rmu:ident, $na:ident, $($i:expr => $f:ident),*) => (
+        impl From<::nalgebra::$na<f32>> for $rmu {
+            fn from(v: ::nalgebra::$na<f32>) -> Self {
+                $rmu { $($f: v[$i]),* }
+            }
+        }
+
+        impl From<$rmu> for ::nalgebra::$na<f32> {
+            fn from(v: $rmu) -> Self {
+                ::nalgebra::$na::new($(v.$f),*)
+            }
+        }
+    );
+}
+
+nalgebra_vector!(Vector2, Vector2, 0 => x, 1 => y);
+nalgebra_vector!(Vector3, Vector3, 0 => x, 1 => y, 2 => z);
+nalgebra_vector!(Vector4, Vector4, 0 => x, 1 => y, 2 => z, 3 => w);
+
+// nalgebra indexes by (row, column) whatever its storage, so no transpose is needed
+macro_rules! nalgebra_matrix {
+    ($rmu:ident, $na:ident, $n:expr) => (
+        impl From<::nalgebra::$na<f32>> for $rmu {
+            fn from(m: ::nalgebra::$na<f32>) -> Self {
+                let mut data = [[0.0;$n];$n];
+                for (i, row) in data.iter_mut().enumerate() {
+                    for (j, e) in row.iter_mut().enumerate() {
+                        *e = m[(i, j)];
+                    }
+                }
+                $rmu { data }
+            }
+        }
+
+        impl From<$rmu> for ::nalgebra::$na<f32> {
+            fn from(m: $rmu) -> Self {
+                ::nalgebra::$na::from_fn(|i, j| m.data[i][j])
+            }
+        }
+    );
+}
+
+nalgebra_matrix!(Matrix2x2, Matrix2, 2);
+nalgebra_matrix!(Matrix3x3, Matrix3, 3);
+nalgebra_matrix!(Matrix4x4, Matrix4, 4);
@@ -0,0 +1,311 @@
+//! gpu buffer layout types
+//!
+//! `Vector2/3/4`, `Vector<T, SIZE>` and the matrix types are `#[repr(C)]` and
+//! tightly packed, so they can be uploaded as-is where the buffer layout has
+//! no padding (vertex buffers, std430 arrays of vec4/mat4).
+//!
+//! uniform and storage blocks follow the std140/std430 rules instead, where a
+//! vec3 is aligned to 16 bytes and matrix columns are padded. the `Std140*`
+//! and `Std430*` types here are the padded variants. the padded matrices are
+//! stored column by column, which is what glsl and wgsl expect by default,
+//! so converting from rmu's row storage transposes.
+//!
+//! with the `bytemuck` feature every type here and every packed rmu type is
+//! `Pod` and `Zeroable`.
+use std::mem::size_of;
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+
+/// view a padding-free value as its bytes
+#[inline]
+fn bytes_of<T: Copy>(v: &T) -> &[u8] {
+    // SAFETY: only called on `#[repr(C)]` types made of f32 with every padding byte
+    // spelled out as a field, so all size_of::<T>() bytes are initialized
+    unsafe { std::slice::from_raw_parts(v as *const T as *const u8, size_of::<T>()) }
+}
+
+/// vec2 in std140 and std430, 8 byte aligned
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C, align(8))]
+pub struct Std140Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// vec3 in std140 and std430, 16 byte aligned and padded to 16 bytes
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    _pad: f32,
+}
+
+/// vec4 in std140 and std430, 16 byte aligned
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Vector4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+/// std430 vectors are laid out like std140
+pub type Std430Vector2 = Std140Vector2;
+pub type Std430Vector3 = Std140Vector3;
+pub type Std430Vector4 = Std140Vector4;
+
+/// mat2 in std140, each column padded to 16 bytes
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Matrix2x2 {
+    cols: [[f32;4];2],
+}
+
+/// mat2 in std430, two tightly packed vec2 columns
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C, align(8))]
+pub struct Std430Matrix2x2 {
+    cols: [[f32;2];2],
+}
+
+/// mat3 in std140 and std430, each column padded to 16 bytes
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Matrix3x3 {
+    cols: [[f32;4];3],
+}
+
+/// mat4 in std140 and std430
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Matrix4x4 {
+    cols: [[f32;4];4],
+}
+
+/// std430 mat3 and mat4 are laid out like std140
+pub type Std430Matrix3x3 = Std140Matrix3x3;
+pub type Std430Matrix4x4 = Std140Matrix4x4;
+
+impl Std140Vector3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {x, y, z, _pad: 0.0}
+    }
+}
+
+impl From<Vector2> for Std140Vector2 {
+    fn from(v: Vector2) -> Self {
+        Self {x: v.x, y: v.y}
+    }
+}
+
+impl From<Std140Vector2> for Vector2 {
+    fn from(v: Std140Vector2) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector3> for Std140Vector3 {
+    fn from(v: Vector3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Std140Vector3> for Vector3 {
+    fn from(v: Std140Vector3) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector4> for Std140Vector4 {
+    fn from(v: Vector4) -> Self {
+        Self {x: v.x, y: v.y, z: v.z, w: v.w}
+    }
+}
+
+impl From<Std140Vector4> for Vector4 {
+    fn from(v: Std140Vector4) -> Self {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Matrix2x2> for Std140Matrix2x2 {
+    fn from(m: Matrix2x2) -> Self {
+        let mut cols = [[0.0;4];2];
+        for (j, col) in cols.iter_mut().enumerate() {
+            for i in 0..2 {
+                col[i] = m[i][j];
+            }
+        }
+        Self {cols}
+    }
+}
+
+impl From<Std140Matrix2x2> for Matrix2x2 {
+    fn from(m: Std140Matrix2x2) -> Self {
+        let mut ret = Matrix2x2::new(0.0, 0.0);
+        for (j, col) in m.cols.iter().enumerate() {
+            for i in 0..2 {
+                ret[i][j] = col[i];
+            }
+        }
+        ret
+    }
+}
+
+impl From<Matrix2x2> for Std430Matrix2x2 {
+    fn from(m: Matrix2x2) -> Self {
+        Self {cols: [[m[0][0], m[1][0]], [m[0][1], m[1][1]]]}
+    }
+}
+
+impl From<Std430Matrix2x2> for Matrix2x2 {
+    fn from(m: Std430Matrix2x2) -> Self {
+        Matrix2x2::from([[m.cols[0][0], m.cols[1][0]], [m.cols[0][1], m.cols[1][1]]])
+    }
+}
+
+impl From<Matrix3x3> for Std140Matrix3x3 {
+    fn from(m: Matrix3x3) -> Self {
+        let mut cols = [[0.0;4];3];
+        for (j, col) in cols.iter_mut().enumerate() {
+            for i in 0..3 {
+                col[i] = m[i][j];
+            }
+        }
+        Self {cols}
+    }
+}
+
+impl From<Std140Matrix3x3> for Matrix3x3 {
+    fn from(m: Std140Matrix3x3) -> Self {
+        let mut ret = Matrix3x3::new(0.0, 0.0, 0.0);
+        for (j, col) in m.cols.iter().enumerate() {
+            for i in 0..3 {
+                ret[i][j] = col[i];
+            }
+        }
+        ret
+    }
+}
+
+impl From<Matrix4x4> for Std140Matrix4x4 {
+    fn from(m: Matrix4x4) -> Self {
+        let mut cols = [[0.0;4];4];
+        for (j, col) in cols.iter_mut().enumerate() {
+            for (i, e) in col.iter_mut().enumerate() {
+                *e = m[i][j];
+            }
+        }
+        Self {cols}
+    }
+}
+
+impl From<Std140Matrix4x4> for Matrix4x4 {
+    fn from(m: Std140Matrix4x4) -> Self {
+        let mut ret = Matrix4x4::new(0.0, 0.0, 0.0, 0.0);
+        for (j, col) in m.cols.iter().enumerate() {
+            for (i, e) in col.iter().enumerate() {
+                ret[i][j] = *e;
+            }
+        }
+        ret
+    }
+}
+
+macro_rules! gpu_layout {
+    ($($name:ty),*) => ($(
+        impl $name {
+            /// view as the bytes uploaded to the gpu
+            pub fn as_bytes(&self) -> &[u8] {
+                bytes_of(self)
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Zeroable for $name {}
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Pod for $name {}
+    )*);
+}
+
+gpu_layout!(Vector2, Vector3, Vector4, Matrix2x2, Matrix3x3, Matrix4x4,
+            Std140Vector2, Std140Vector3, Std140Vector4,
+            Std140Matrix2x2, Std430Matrix2x2, Std140Matrix3x3, Std140Matrix4x4);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const SIZE: usize> bytemuck::Zeroable for crate::vector::vector::Vector<T, SIZE> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod, const SIZE: usize> bytemuck::Pod for crate::vector::vector::Vector<T, SIZE> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for crate::vector::Vector3x8 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for crate::vector::Vector3x8 {}
+
+#[test]
+fn packed_layout() {
+    use std::mem::align_of;
+
+    assert_eq!((size_of::<Vector2>(), align_of::<Vector2>()), (8, 4));
+    assert_eq!((size_of::<Vector3>(), align_of::<Vector3>()), (12, 4));
+    assert_eq!((size_of::<Vector4>(), align_of::<Vector4>()), (16, 4));
+    assert_eq!((size_of::<Matrix2x2>(), align_of::<Matrix2x2>()), (16, 4));
+    assert_eq!((size_of::<Matrix3x3>(), align_of::<Matrix3x3>()), (36, 4));
+    assert_eq!((size_of::<Matrix4x4>(), align_of::<Matrix4x4>()), (64, 4));
+    assert_eq!(size_of::<crate::vector::vector::Vector<f64, 3>>(), 24);
+}
+
+#[test]
+fn std140_layout() {
+    use std::mem::align_of;
+
+    assert_eq!((size_of::<Std140Vector2>(), align_of::<Std140Vector2>()), (8, 8));
+    assert_eq!((size_of::<Std140Vector3>(), align_of::<Std140Vector3>()), (16, 16));
+    assert_eq!((size_of::<Std140Vector4>(), align_of::<Std140Vector4>()), (16, 16));
+    assert_eq!((size_of::<Std140Matrix2x2>(), align_of::<Std140Matrix2x2>()), (32, 16));
+    assert_eq!((size_of::<Std430Matrix2x2>(), align_of::<Std430Matrix2x2>()), (16, 8));
+    assert_eq!((size_of::<Std140Matrix3x3>(), align_of::<Std140Matrix3x3>()), (48, 16));
+    assert_eq!((size_of::<Std140Matrix4x4>(), align_of::<Std140Matrix4x4>()), (64, 16));
+}
+
+#[test]
+fn std140_bytes() {
+    let m = Matrix4x4::translate3(1.0, 2.0, 3.0);
+    let std140 = Std140Matrix4x4::from(m);
+    let bytes = std140.as_bytes();
+    assert_eq!(bytes.len(), 64);
+    // translation is the last column, the last 16 bytes
+    assert_eq!(&bytes[48..52], &1f32.to_ne_bytes());
+    assert_eq!(&bytes[52..56], &2f32.to_ne_bytes());
+    let back: Matrix4x4 = std140.into();
+    assert_eq!(back, m);
+
+    let m3 = Matrix3x3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let std140 = Std140Matrix3x3::from(m3);
+    assert_eq!(&std140.as_bytes()[16..20], &2f32.to_ne_bytes());
+    assert_eq!(Matrix3x3::from(std140), m3);
+
+    let m2 = Matrix2x2::from([[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(Matrix2x2::from(Std140Matrix2x2::from(m2)), m2);
+    assert_eq!(Matrix2x2::from(Std430Matrix2x2::from(m2)), m2);
+
+    let v = Std140Vector3::from(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(&v.as_bytes()[8..12], &3f32.to_ne_bytes());
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).as_bytes().len(), 12);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_cast() {
+    let points = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+    let floats: &[f32] = bytemuck::cast_slice(&points);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let m: Matrix4x4 = bytemuck::Zeroable::zeroed();
+    assert_eq!(bytemuck::bytes_of(&m), Matrix4x4::new(0.0, 0.0, 0.0, 0.0).as_bytes());
+}
@@ -1,7 +1,23 @@
 pub mod raw;
+pub mod layout;
+mod interop;
 pub mod vector;
 pub mod matrix;
 
 pub mod combinatorics;
 
-pub mod common;
\ No newline at end of file
+pub mod common;
+pub mod stats;
+pub mod polynomial;
+pub mod curve;
+pub mod numeric;
+pub mod autodiff;
+pub mod complex;
+pub mod fft;
+pub mod number_theory;
+pub mod approx_eq;
+pub mod display;
+pub mod parse;
+
+#[cfg(feature = "rand")]
+pub mod random;
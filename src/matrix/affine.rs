@@ -0,0 +1,287 @@
+//! translation, rotation and scale transforms
+//!
+//! a transform applies scale first, then rotation, then translation:
+//! `x ↦ t + r(s ⊙ x)`. `a * b` applies `b` first, the same as matrices, so a
+//! scene graph node's world transform is `parent_world * local`.
+//!
+//! composition and inverse stay in translation/rotation/scale form, which is
+//! only exact when the scale is uniform (or nothing rotates). a non-uniform
+//! scale followed by a rotation is a shear that this form cannot hold; go
+//! through `to_matrix4`/`to_matrix3` when that matters.
+use std::f32::consts::PI;
+use std::ops::{Mul, MulAssign};
+
+use crate::vector::{Vector2, Vector3, Vector4};
+use super::{Matrix3x3, Matrix4x4};
+use super::rotation::{
+    quat_conjugate, quat_from_matrix3, quat_mul, quat_rotate, quat_slerp, quat_to_matrix3,
+    QUAT_IDENTITY,
+};
+
+/// affine transform in R³, rotation stored as a unit quaternion (x, y, z, w)
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform3 {
+    pub translation: Vector3,
+    pub rotation: Vector4,
+    pub scale: Vector3,
+}
+
+impl Transform3 {
+    /// the transform that changes nothing
+    pub const IDENTITY: Self = Self {
+        translation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+        rotation: QUAT_IDENTITY,
+        scale: Vector3 { x: 1.0, y: 1.0, z: 1.0 },
+    };
+
+    pub fn new(translation: Vector3, rotation: Vector4, scale: Vector3) -> Self {
+        Self { translation, rotation, scale }
+    }
+
+    pub fn from_translation(translation: Vector3) -> Self {
+        Self { translation, ..Self::IDENTITY }
+    }
+
+    pub fn from_rotation(rotation: Vector4) -> Self {
+        Self { rotation, ..Self::IDENTITY }
+    }
+
+    pub fn from_scale(scale: Vector3) -> Self {
+        Self { scale, ..Self::IDENTITY }
+    }
+
+    /// apply to a point, translation included
+    pub fn transform_point(&self, p: Vector3) -> Vector3 {
+        self.translation + quat_rotate(self.rotation, self.scale * p)
+    }
+
+    /// apply to a direction, translation ignored
+    pub fn transform_vector(&self, v: Vector3) -> Vector3 {
+        quat_rotate(self.rotation, self.scale * v)
+    }
+
+    /// inverse transform, exact for uniform scale
+    pub fn inverse(&self) -> Self {
+        let scale = Vector3::broadcast(1.0) / self.scale;
+        let rotation = quat_conjugate(self.rotation);
+        let translation = -(scale * quat_rotate(rotation, self.translation));
+        Self { translation, rotation, scale }
+    }
+
+    /// homogeneous matrix, `m * (p, 1)` equals `transform_point(p)`
+    pub fn to_matrix4(&self) -> Matrix4x4 {
+        let r = quat_to_matrix3(self.rotation);
+        let s = self.scale;
+        let t = self.translation;
+        Matrix4x4 {
+            data: [
+                [r[0][0] * s.x, r[0][1] * s.y, r[0][2] * s.z, t.x],
+                [r[1][0] * s.x, r[1][1] * s.y, r[1][2] * s.z, t.y],
+                [r[2][0] * s.x, r[2][1] * s.y, r[2][2] * s.z, t.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// decompose an affine matrix, the last row is assumed to be (0, 0, 0, 1)
+    ///
+    /// shear is dropped. a reflection is folded into a negative x scale.
+    pub fn from_matrix4(m: &Matrix4x4) -> Self {
+        let d = &m.data;
+        let col = |j: usize| Vector3::new(d[0][j], d[1][j], d[2][j]);
+        let (c0, c1, c2) = (col(0), col(1), col(2));
+        let mut scale = Vector3::new(c0.length(), c1.length(), c2.length());
+        if Vector3::dot(Vector3::cross(c0, c1), c2) < 0.0 {
+            scale.x = -scale.x;
+        }
+        let r = Matrix3x3::from_cols(c0 / scale.x, c1 / scale.y, c2 / scale.z);
+        Self {
+            translation: col(3),
+            rotation: quat_from_matrix3(&r),
+            scale,
+        }
+    }
+
+    /// interpolate translation and scale linearly and rotation along the shorter arc
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            translation: self.translation + (other.translation - self.translation) * t,
+            rotation: quat_slerp(self.rotation, other.rotation, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+}
+
+impl Default for Transform3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Transform3 {
+    type Output = Self;
+
+    /// `self * rhs` applies `rhs` first
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            translation: self.transform_point(rhs.translation),
+            rotation: quat_mul(self.rotation, rhs.rotation).normalized(),
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+
+impl MulAssign for Transform3 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl From<Transform3> for Matrix4x4 {
+    fn from(t: Transform3) -> Self {
+        t.to_matrix4()
+    }
+}
+
+impl From<Matrix4x4> for Transform3 {
+    fn from(m: Matrix4x4) -> Self {
+        Self::from_matrix4(&m)
+    }
+}
+
+/// affine transform in R², rotation stored as a counter clockwise angle in radians
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2 {
+    pub translation: Vector2,
+    pub rotation: f32,
+    pub scale: Vector2,
+}
+
+fn rotate(angle: f32, v: Vector2) -> Vector2 {
+    let (s, c) = angle.sin_cos();
+    Vector2::new(c * v.x - s * v.y, s * v.x + c * v.y)
+}
+
+impl Transform2 {
+    /// the transform that changes nothing
+    pub const IDENTITY: Self = Self {
+        translation: Vector2 { x: 0.0, y: 0.0 },
+        rotation: 0.0,
+        scale: Vector2 { x: 1.0, y: 1.0 },
+    };
+
+    pub fn new(translation: Vector2, rotation: f32, scale: Vector2) -> Self {
+        Self { translation, rotation, scale }
+    }
+
+    pub fn from_translation(translation: Vector2) -> Self {
+        Self { translation, ..Self::IDENTITY }
+    }
+
+    pub fn from_rotation(rotation: f32) -> Self {
+        Self { rotation, ..Self::IDENTITY }
+    }
+
+    pub fn from_scale(scale: Vector2) -> Self {
+        Self { scale, ..Self::IDENTITY }
+    }
+
+    /// apply to a point, translation included
+    pub fn transform_point(&self, p: Vector2) -> Vector2 {
+        self.translation + rotate(self.rotation, self.scale * p)
+    }
+
+    /// apply to a direction, translation ignored
+    pub fn transform_vector(&self, v: Vector2) -> Vector2 {
+        rotate(self.rotation, self.scale * v)
+    }
+
+    /// inverse transform, exact for uniform scale
+    pub fn inverse(&self) -> Self {
+        let scale = Vector2::broadcast(1.0) / self.scale;
+        let rotation = -self.rotation;
+        let translation = -(scale * rotate(rotation, self.translation));
+        Self { translation, rotation, scale }
+    }
+
+    /// homogeneous matrix, `m * (p, 1)` equals `transform_point(p)`
+    pub fn to_matrix3(&self) -> Matrix3x3 {
+        let (sin, cos) = self.rotation.sin_cos();
+        let s = self.scale;
+        let t = self.translation;
+        Matrix3x3 {
+            data: [
+                [cos * s.x, -sin * s.y, t.x],
+                [sin * s.x, cos * s.y, t.y],
+                [0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// decompose an affine matrix, the last row is assumed to be (0, 0, 1)
+    ///
+    /// shear is dropped. a reflection is folded into a negative x scale.
+    pub fn from_matrix3(m: &Matrix3x3) -> Self {
+        let d = &m.data;
+        let c0 = Vector2::new(d[0][0], d[1][0]);
+        let c1 = Vector2::new(d[0][1], d[1][1]);
+        let mut scale = Vector2::new(c0.length(), c1.length());
+        if c0.x * c1.y - c0.y * c1.x < 0.0 {
+            scale.x = -scale.x;
+        }
+        Self {
+            translation: Vector2::new(d[0][2], d[1][2]),
+            rotation: (c0.y / scale.x).atan2(c0.x / scale.x),
+            scale,
+        }
+    }
+
+    /// interpolate translation and scale linearly and rotation along the shorter arc
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let turn = (other.rotation - self.rotation + PI).rem_euclid(2.0 * PI) - PI;
+        Self {
+            translation: self.translation + (other.translation - self.translation) * t,
+            rotation: self.rotation + turn * t,
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+}
+
+impl Default for Transform2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Transform2 {
+    type Output = Self;
+
+    /// `self * rhs` applies `rhs` first
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            translation: self.transform_point(rhs.translation),
+            rotation: self.rotation + rhs.rotation,
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+
+impl MulAssign for Transform2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl From<Transform2> for Matrix3x3 {
+    fn from(t: Transform2) -> Self {
+        t.to_matrix3()
+    }
+}
+
+impl From<Matrix3x3> for Transform2 {
+    fn from(m: Matrix3x3) -> Self {
+        Self::from_matrix3(&m)
+    }
+}
@@ -0,0 +1,234 @@
+//! apply one matrix to whole buffers of vectors
+//!
+//! vectors are treated as columns, `m * v`, the same as `Matrix4x4 * Vector4`.
+//! points are extended with w = 1 and directions with w = 0, the projective
+//! row of `m` is ignored for both. the loops are written over plain slices so
+//! they auto-vectorize, and the `par_` variants split the work with rayon when
+//! the `rayon` feature is on.
+use crate::raw::{Vec3f, Vec4f};
+use crate::vector::{Vector3x8, Vector3SoA};
+use super::Matrix4x4;
+
+#[inline(always)]
+fn point3(m: &[[f32;4];4], p: Vec3f) -> Vec3f {
+    [m[0][0] * p[0] + m[0][1] * p[1] + m[0][2] * p[2] + m[0][3]
+    ,m[1][0] * p[0] + m[1][1] * p[1] + m[1][2] * p[2] + m[1][3]
+    ,m[2][0] * p[0] + m[2][1] * p[1] + m[2][2] * p[2] + m[2][3]]
+}
+
+#[inline(always)]
+fn vector3(m: &[[f32;4];4], v: Vec3f) -> Vec3f {
+    [m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2]
+    ,m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2]
+    ,m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2]]
+}
+
+#[inline(always)]
+fn vector4(m: &[[f32;4];4], v: Vec4f) -> Vec4f {
+    [m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2] + m[0][3] * v[3]
+    ,m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2] + m[1][3] * v[3]
+    ,m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2] + m[2][3] * v[3]
+    ,m[3][0] * v[0] + m[3][1] * v[1] + m[3][2] * v[2] + m[3][3] * v[3]]
+}
+
+/// transform points (w = 1) from input into output, panics if the lengths differ
+pub fn transform_points3(m: &Matrix4x4, input: &[Vec3f], output: &mut [Vec3f]) {
+    assert_eq!(input.len(), output.len(), "batch transform length mismatch");
+    let m = &m.data;
+    for (o, p) in output.iter_mut().zip(input) {
+        *o = point3(m, *p);
+    }
+}
+
+/// transform points (w = 1) in place
+pub fn transform_points3_in_place(m: &Matrix4x4, points: &mut [Vec3f]) {
+    let m = &m.data;
+    for p in points.iter_mut() {
+        *p = point3(m, *p);
+    }
+}
+
+/// transform directions (w = 0) from input into output, panics if the lengths differ
+pub fn transform_vectors3(m: &Matrix4x4, input: &[Vec3f], output: &mut [Vec3f]) {
+    assert_eq!(input.len(), output.len(), "batch transform length mismatch");
+    let m = &m.data;
+    for (o, v) in output.iter_mut().zip(input) {
+        *o = vector3(m, *v);
+    }
+}
+
+/// transform directions (w = 0) in place
+pub fn transform_vectors3_in_place(m: &Matrix4x4, vectors: &mut [Vec3f]) {
+    let m = &m.data;
+    for v in vectors.iter_mut() {
+        *v = vector3(m, *v);
+    }
+}
+
+/// transform homogeneous vectors from input into output, panics if the lengths differ
+pub fn transform4(m: &Matrix4x4, input: &[Vec4f], output: &mut [Vec4f]) {
+    assert_eq!(input.len(), output.len(), "batch transform length mismatch");
+    let m = &m.data;
+    for (o, v) in output.iter_mut().zip(input) {
+        *o = vector4(m, *v);
+    }
+}
+
+/// transform homogeneous vectors in place
+pub fn transform4_in_place(m: &Matrix4x4, vectors: &mut [Vec4f]) {
+    let m = &m.data;
+    for v in vectors.iter_mut() {
+        *v = vector4(m, *v);
+    }
+}
+
+/// transform the eight points (w = 1) of a wide vector
+pub fn transform_points3x8(m: &Matrix4x4, p: &Vector3x8) -> Vector3x8 {
+    let m = &m.data;
+    let mut ret = Vector3x8::default();
+    for i in 0..crate::vector::soa::LANES {
+        let [x, y, z] = point3(m, [p.x[i], p.y[i], p.z[i]]);
+        ret.x[i] = x;
+        ret.y[i] = y;
+        ret.z[i] = z;
+    }
+    ret
+}
+
+/// transform the eight directions (w = 0) of a wide vector
+pub fn transform_vectors3x8(m: &Matrix4x4, v: &Vector3x8) -> Vector3x8 {
+    let m = &m.data;
+    let mut ret = Vector3x8::default();
+    for i in 0..crate::vector::soa::LANES {
+        let [x, y, z] = vector3(m, [v.x[i], v.y[i], v.z[i]]);
+        ret.x[i] = x;
+        ret.y[i] = y;
+        ret.z[i] = z;
+    }
+    ret
+}
+
+/// transform every point (w = 1) of a structure-of-arrays buffer in place
+pub fn transform_points3_soa(m: &Matrix4x4, points: &mut Vector3SoA) {
+    let m = &m.data;
+    let (xs, ys, zs) = points.components_mut();
+    for i in 0..xs.len() {
+        let (x, y, z) = (xs[i], ys[i], zs[i]);
+        xs[i] = m[0][0] * x + m[0][1] * y + m[0][2] * z + m[0][3];
+        ys[i] = m[1][0] * x + m[1][1] * y + m[1][2] * z + m[1][3];
+        zs[i] = m[2][0] * x + m[2][1] * y + m[2][2] * z + m[2][3];
+    }
+}
+
+/// transform every direction (w = 0) of a structure-of-arrays buffer in place
+pub fn transform_vectors3_soa(m: &Matrix4x4, vectors: &mut Vector3SoA) {
+    let m = &m.data;
+    let (xs, ys, zs) = vectors.components_mut();
+    for i in 0..xs.len() {
+        let (x, y, z) = (xs[i], ys[i], zs[i]);
+        xs[i] = m[0][0] * x + m[0][1] * y + m[0][2] * z;
+        ys[i] = m[1][0] * x + m[1][1] * y + m[1][2] * z;
+        zs[i] = m[2][0] * x + m[2][1] * y + m[2][2] * z;
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub use self::parallel::*;
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use rayon::prelude::*;
+    use crate::raw::{Vec3f, Vec4f};
+    use super::{Matrix4x4, point3, vector3, vector4};
+
+    /// smallest slice a rayon task is given, keeps tiny buffers on one thread
+    const MIN_LEN: usize = 4096;
+
+    /// parallel `transform_points3`
+    pub fn par_transform_points3(m: &Matrix4x4, input: &[Vec3f], output: &mut [Vec3f]) {
+        assert_eq!(input.len(), output.len(), "batch transform length mismatch");
+        let m = &m.data;
+        output.par_iter_mut().zip(input.par_iter()).with_min_len(MIN_LEN)
+            .for_each(|(o, p)| *o = point3(m, *p));
+    }
+
+    /// parallel `transform_points3_in_place`
+    pub fn par_transform_points3_in_place(m: &Matrix4x4, points: &mut [Vec3f]) {
+        let m = &m.data;
+        points.par_iter_mut().with_min_len(MIN_LEN)
+            .for_each(|p| *p = point3(m, *p));
+    }
+
+    /// parallel `transform_vectors3`
+    pub fn par_transform_vectors3(m: &Matrix4x4, input: &[Vec3f], output: &mut [Vec3f]) {
+        assert_eq!(input.len(), output.len(), "batch transform length mismatch");
+        let m = &m.data;
+        output.par_iter_mut().zip(input.par_iter()).with_min_len(MIN_LEN)
+            .for_each(|(o, v)| *o = vector3(m, *v));
+    }
+
+    /// parallel `transform_vectors3_in_place`
+    pub fn par_transform_vectors3_in_place(m: &Matrix4x4, vectors: &mut [Vec3f]) {
+        let m = &m.data;
+        vectors.par_iter_mut().with_min_len(MIN_LEN)
+            .for_each(|v| *v = vector3(m, *v));
+    }
+
+    /// parallel `transform4`
+    pub fn par_transform4(m: &Matrix4x4, input: &[Vec4f], output: &mut [Vec4f]) {
+        assert_eq!(input.len(), output.len(), "batch transform length mismatch");
+        let m = &m.data;
+        output.par_iter_mut().zip(input.par_iter()).with_min_len(MIN_LEN)
+            .for_each(|(o, v)| *o = vector4(m, *v));
+    }
+
+    /// parallel `transform4_in_place`
+    pub fn par_transform4_in_place(m: &Matrix4x4, vectors: &mut [Vec4f]) {
+        let m = &m.data;
+        vectors.par_iter_mut().with_min_len(MIN_LEN)
+            .for_each(|v| *v = vector4(m, *v));
+    }
+}
+
+#[test]
+fn batch_points_match_single() {
+    use crate::vector::Vector4;
+
+    let m = Matrix4x4::translate3(1.0, 2.0, 3.0) * Matrix4x4::new(2.0, 3.0, 4.0, 1.0);
+    let input: Vec<Vec3f> = (0..37).map(|i| [i as f32, -(i as f32), 0.5 * i as f32]).collect();
+    let mut output = vec![[0.0;3]; input.len()];
+    transform_points3(&m, &input, &mut output);
+
+    for (p, o) in input.iter().zip(output.iter()) {
+        let expect = m * Vector4::new(p[0], p[1], p[2], 1.0);
+        assert_eq!(*o, [expect.x, expect.y, expect.z]);
+    }
+
+    let mut in_place = input.clone();
+    transform_points3_in_place(&m, &mut in_place);
+    assert_eq!(in_place, output);
+
+    let mut soa = Vector3SoA::from_aos(&input);
+    transform_points3_soa(&m, &mut soa);
+    assert_eq!(soa.to_aos(), output);
+
+    let wide = transform_points3x8(&m, &Vector3SoA::from_aos(&input).load8(0));
+    let wide: [Vec3f;8] = wide.into();
+    assert_eq!(&wide[..], &output[..8]);
+
+    let mut dirs = input.clone();
+    transform_vectors3_in_place(&Matrix4x4::translate3(5.0, 5.0, 5.0), &mut dirs);
+    assert_eq!(dirs, input);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_matches_serial() {
+    let m = Matrix4x4::translate3(1.0, -2.0, 0.5) * Matrix4x4::new(0.5, 2.0, 1.0, 1.0);
+    let input: Vec<Vec3f> = (0..10_000).map(|i| [i as f32, 1.0, -(i as f32)]).collect();
+    let mut serial = vec![[0.0;3]; input.len()];
+    let mut parallel = vec![[0.0;3]; input.len()];
+    transform_points3(&m, &input, &mut serial);
+    par_transform_points3(&m, &input, &mut parallel);
+    assert_eq!(serial, parallel);
+}
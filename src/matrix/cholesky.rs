@@ -0,0 +1,245 @@
+//! cholesky (L Lᵀ) and L D Lᵀ factorizations of symmetric positive definite matrices
+//!
+//! both work on any `SquareMatrix`, so the fixed size matrices factor without
+//! allocating. only the lower triangle of the input is read. a matrix that is
+//! not positive definite gives `FactorError::NotPositiveDefinite` instead of
+//! a factor full of NaN, a non-square `Matrix` gives `FactorError::NotSquare`.
+use std::fmt;
+use num::{Float, One};
+use super::square::SquareMatrix;
+use super::{Matrix2x2, Matrix3x3, Matrix4x4};
+use super::matrixnxm::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FactorError {
+    /// a pivot was zero, negative or not finite
+    NotPositiveDefinite,
+    /// only square matrices factor
+    NotSquare { rows: usize, cols: usize },
+}
+
+impl fmt::Display for FactorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FactorError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            FactorError::NotSquare { rows, cols } => write!(f, "matrix is {} x {}, not square", rows, cols),
+        }
+    }
+}
+
+impl std::error::Error for FactorError {}
+
+/// dimension of a, or the error for a non-square one
+fn square<M: SquareMatrix>(a: &M) -> Result<usize, FactorError> {
+    match a.shape() {
+        (rows, cols) if rows != cols => Err(FactorError::NotSquare { rows, cols }),
+        (n, _) => Ok(n),
+    }
+}
+
+/// A = L Lᵀ with L lower triangular
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky<M> {
+    l: M,
+}
+
+/// A = L D Lᵀ with L unit lower triangular and D diagonal, no square roots taken
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ldlt<M: SquareMatrix> {
+    l: M,
+    d: M::Vector,
+}
+
+#[inline]
+fn positive<T: Float>(d: T) -> Result<T, FactorError> {
+    if d > T::zero() && d.is_finite() {
+        Ok(d)
+    } else {
+        Err(FactorError::NotPositiveDefinite)
+    }
+}
+
+impl<M: SquareMatrix> Cholesky<M> {
+    pub fn new(a: &M) -> Result<Self, FactorError> {
+        let n = square(a)?;
+        let mut l = a.zeros_like();
+        for j in 0..n {
+            let mut d = a.get(j, j);
+            for k in 0..j {
+                d = d - l.get(j, k) * l.get(j, k);
+            }
+            let d = positive(d)?.sqrt();
+            l.set(j, j, d);
+            for i in j + 1..n {
+                let mut s = a.get(i, j);
+                for k in 0..j {
+                    s = s - l.get(i, k) * l.get(j, k);
+                }
+                l.set(i, j, s / d);
+            }
+        }
+        Ok(Self { l })
+    }
+
+    /// the lower triangular factor
+    pub fn l(&self) -> &M {
+        &self.l
+    }
+
+    /// solve A x = b
+    pub fn solve(&self, b: &M::Vector) -> M::Vector {
+        let n = self.l.dim();
+        assert_eq!(M::vector_dim(b), n, "matrix vector dimension mismatch");
+        let mut x = b.clone();
+        // L y = b
+        for i in 0..n {
+            let mut s = M::vector_get(&x, i);
+            for k in 0..i {
+                s = s - self.l.get(i, k) * M::vector_get(&x, k);
+            }
+            M::vector_set(&mut x, i, s / self.l.get(i, i));
+        }
+        // Lᵀ x = y
+        for i in (0..n).rev() {
+            let mut s = M::vector_get(&x, i);
+            for k in i + 1..n {
+                s = s - self.l.get(k, i) * M::vector_get(&x, k);
+            }
+            M::vector_set(&mut x, i, s / self.l.get(i, i));
+        }
+        x
+    }
+
+    /// det A = ∏ lᵢᵢ²
+    pub fn determinant(&self) -> M::Scalar {
+        let mut det = M::Scalar::one();
+        for i in 0..self.l.dim() {
+            det = det * self.l.get(i, i);
+        }
+        det * det
+    }
+
+    /// A⁻¹, solved one column at a time
+    pub fn inverse(&self) -> M {
+        inverse(&self.l, |b| self.solve(b))
+    }
+}
+
+impl<M: SquareMatrix> Ldlt<M> {
+    pub fn new(a: &M) -> Result<Self, FactorError> {
+        let n = square(a)?;
+        let mut l = a.zeros_like();
+        let mut d = a.zero_vector();
+        for j in 0..n {
+            let mut dj = a.get(j, j);
+            for k in 0..j {
+                dj = dj - l.get(j, k) * l.get(j, k) * M::vector_get(&d, k);
+            }
+            let dj = positive(dj)?;
+            M::vector_set(&mut d, j, dj);
+            l.set(j, j, M::Scalar::one());
+            for i in j + 1..n {
+                let mut s = a.get(i, j);
+                for k in 0..j {
+                    s = s - l.get(i, k) * l.get(j, k) * M::vector_get(&d, k);
+                }
+                l.set(i, j, s / dj);
+            }
+        }
+        Ok(Self { l, d })
+    }
+
+    /// the unit lower triangular factor
+    pub fn l(&self) -> &M {
+        &self.l
+    }
+
+    /// the diagonal of D
+    pub fn d(&self) -> &M::Vector {
+        &self.d
+    }
+
+    /// solve A x = b
+    pub fn solve(&self, b: &M::Vector) -> M::Vector {
+        let n = self.l.dim();
+        assert_eq!(M::vector_dim(b), n, "matrix vector dimension mismatch");
+        let mut x = b.clone();
+        for i in 0..n {
+            let mut s = M::vector_get(&x, i);
+            for k in 0..i {
+                s = s - self.l.get(i, k) * M::vector_get(&x, k);
+            }
+            M::vector_set(&mut x, i, s);
+        }
+        for i in 0..n {
+            let v = M::vector_get(&x, i) / M::vector_get(&self.d, i);
+            M::vector_set(&mut x, i, v);
+        }
+        for i in (0..n).rev() {
+            let mut s = M::vector_get(&x, i);
+            for k in i + 1..n {
+                s = s - self.l.get(k, i) * M::vector_get(&x, k);
+            }
+            M::vector_set(&mut x, i, s);
+        }
+        x
+    }
+
+    /// det A = ∏ dᵢ
+    pub fn determinant(&self) -> M::Scalar {
+        let mut det = M::Scalar::one();
+        for i in 0..self.l.dim() {
+            det = det * M::vector_get(&self.d, i);
+        }
+        det
+    }
+
+    /// A⁻¹, solved one column at a time
+    pub fn inverse(&self) -> M {
+        inverse(&self.l, |b| self.solve(b))
+    }
+}
+
+fn inverse<M: SquareMatrix>(like: &M, solve: impl Fn(&M::Vector) -> M::Vector) -> M {
+    let n = like.dim();
+    let mut inv = like.zeros_like();
+    for j in 0..n {
+        let mut e = like.zero_vector();
+        M::vector_set(&mut e, j, M::Scalar::one());
+        let col = solve(&e);
+        for i in 0..n {
+            inv.set(i, j, M::vector_get(&col, i));
+        }
+    }
+    inv
+}
+
+macro_rules! factor_methods {
+    ($($name:ty),*) => ($(
+        impl $name {
+            /// cholesky factorization, see `Cholesky`
+            pub fn cholesky(&self) -> Result<Cholesky<Self>, FactorError> {
+                Cholesky::new(self)
+            }
+
+            /// L D Lᵀ factorization, see `Ldlt`
+            pub fn ldlt(&self) -> Result<Ldlt<Self>, FactorError> {
+                Ldlt::new(self)
+            }
+        }
+    )*);
+}
+
+factor_methods!(Matrix2x2, Matrix3x3, Matrix4x4);
+
+impl<T: Float> Matrix<T> {
+    /// cholesky factorization, see `Cholesky`
+    pub fn cholesky(&self) -> Result<Cholesky<Self>, FactorError> {
+        Cholesky::new(self)
+    }
+
+    /// L D Lᵀ factorization, see `Ldlt`
+    pub fn ldlt(&self) -> Result<Ldlt<Self>, FactorError> {
+        Ldlt::new(self)
+    }
+}
@@ -0,0 +1,90 @@
+//! eigen decomposition of symmetric matrices by cyclic jacobi rotations
+//!
+//! works on any `SquareMatrix`. only meant for the small, dense, symmetric
+//! matrices that come out of covariance and inertia computations.
+use num::{Float, One, Zero};
+use super::square::SquareMatrix;
+
+const MAX_SWEEPS: usize = 100;
+
+/// A = V Λ Vᵀ, eigenvalues sorted from largest to smallest
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricEigen<M: SquareMatrix> {
+    /// eigenvalues, largest first
+    pub values: Vec<M::Scalar>,
+    /// orthonormal eigenvectors, column k belongs to `values[k]`
+    pub vectors: M,
+}
+
+impl<M: SquareMatrix> SymmetricEigen<M> {
+    /// decompose a symmetric matrix, the upper triangle is assumed to mirror the lower one
+    pub fn new(a: &M) -> Self {
+        let n = a.dim();
+        let mut a = a.clone();
+        let mut v = a.zeros_like();
+        for i in 0..n {
+            v.set(i, i, M::Scalar::one());
+        }
+
+        for _ in 0..MAX_SWEEPS {
+            let mut off = M::Scalar::zero();
+            let mut total = M::Scalar::zero();
+            for i in 0..n {
+                for j in 0..n {
+                    let x = a.get(i, j) * a.get(i, j);
+                    total = total + x;
+                    if i != j {
+                        off = off + x;
+                    }
+                }
+            }
+            if off <= total * M::Scalar::epsilon() * M::Scalar::epsilon() {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a.get(p, q);
+                    if apq == M::Scalar::zero() {
+                        continue;
+                    }
+                    let two = M::Scalar::one() + M::Scalar::one();
+                    let theta = (a.get(q, q) - a.get(p, p)) / (two * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + M::Scalar::one()).sqrt());
+                    let c = M::Scalar::one() / (t * t + M::Scalar::one()).sqrt();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a.get(k, p), a.get(k, q));
+                        a.set(k, p, c * akp - s * akq);
+                        a.set(k, q, s * akp + c * akq);
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a.get(p, k), a.get(q, k));
+                        a.set(p, k, c * apk - s * aqk);
+                        a.set(q, k, s * apk + c * aqk);
+                    }
+                    a.set(p, q, M::Scalar::zero());
+                    a.set(q, p, M::Scalar::zero());
+                    for k in 0..n {
+                        let (vkp, vkq) = (v.get(k, p), v.get(k, q));
+                        v.set(k, p, c * vkp - s * vkq);
+                        v.set(k, q, s * vkp + c * vkq);
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a.get(j, j).partial_cmp(&a.get(i, i)).unwrap_or(std::cmp::Ordering::Equal));
+        let values = order.iter().map(|&i| a.get(i, i)).collect();
+        let mut vectors = v.zeros_like();
+        for (k, &i) in order.iter().enumerate() {
+            for r in 0..n {
+                vectors.set(r, k, v.get(r, i));
+            }
+        }
+
+        SymmetricEigen { values, vectors }
+    }
+}
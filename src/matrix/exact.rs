@@ -0,0 +1,198 @@
+//! exact linear algebra by fraction-free elimination
+//!
+//! bareiss' elimination divides only by the previous pivot, and those
+//! divisions are exact: every intermediate entry is a minor of the input.
+//! integer matrices stay integer and rational ones keep small denominators.
+//! everything here works for any `T: Clone + Num`, e.g. i64, `BigInt`,
+//! `Ratio<i64>` or `BigRational`. floats compile too, but get none of the
+//! exactness and no pivoting for stability, hence the `_exact` names.
+use std::fmt;
+use num::Num;
+use crate::vector::vectorn::VectorN;
+use super::matrixnxm::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExactError {
+    /// the matrix has to be square
+    NotSquare { rows: usize, cols: usize },
+    /// the right hand side doesn't have one entry per row
+    DimensionMismatch { expected: usize, found: usize },
+    Singular,
+}
+
+impl fmt::Display for ExactError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExactError::NotSquare { rows, cols } => write!(f, "matrix is {} x {}, not square", rows, cols),
+            ExactError::DimensionMismatch { expected, found } => write!(f, "expected {} entries, found {}", expected, found),
+            ExactError::Singular => write!(f, "matrix is singular"),
+        }
+    }
+}
+
+impl std::error::Error for ExactError {}
+
+/// fraction-free reduced row echelon form
+///
+/// every pivot entry equals `scale` and the rest of a pivot column is zero,
+/// dividing by `scale` gives the usual reduced row echelon form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rref<T> {
+    pub matrix: Matrix<T>,
+    /// pivot column of each nonzero row, increasing
+    pub pivots: Vec<usize>,
+    pub scale: T,
+    /// row swaps done, odd means the row order was reversed in sign
+    pub swaps: usize,
+}
+
+impl<T: Clone + Num> Rref<T> {
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// basis of the nullspace, one vector per non pivot column
+    ///
+    /// entries are minors of the input, so integer input gives integer vectors.
+    pub fn nullspace(&self) -> Vec<VectorN<T>> {
+        let r = &self.matrix;
+        (0..r.m).filter(|c| !self.pivots.contains(c)).map(|free| {
+            let mut x = VectorN::zeros(r.m);
+            x[free] = self.scale.clone();
+            for (i, &p) in self.pivots.iter().enumerate() {
+                x[p] = T::zero() - r[(i, free)].clone();
+            }
+            x
+        }).collect()
+    }
+}
+
+fn swap_rows<T>(a: &mut Matrix<T>, i: usize, j: usize) {
+    for c in 0..a.m {
+        a.data.swap(i * a.m + c, j * a.m + c);
+    }
+}
+
+/// row at or below `from` with a nonzero entry in column c
+fn pivot_row<T: Clone + Num>(a: &Matrix<T>, from: usize, c: usize) -> Option<usize> {
+    (from..a.n).find(|&i| !a[(i, c)].is_zero())
+}
+
+impl<T: Clone + Num> Matrix<T> {
+    /// determinant by bareiss elimination, exact for integers and rationals
+    pub fn bareiss_determinant(&self) -> Result<T, ExactError> {
+        if self.n != self.m {
+            return Err(ExactError::NotSquare { rows: self.n, cols: self.m });
+        }
+        let n = self.n;
+        let mut a = self.clone();
+        let mut negate = false;
+        let mut prev = T::one();
+        for k in 0..n {
+            match pivot_row(&a, k, k) {
+                Some(p) if p != k => {
+                    swap_rows(&mut a, p, k);
+                    negate = !negate;
+                }
+                Some(_) => {}
+                None => return Ok(T::zero()),
+            }
+            let akk = a[(k, k)].clone();
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let v = (a[(i, j)].clone() * akk.clone() - a[(i, k)].clone() * a[(k, j)].clone()) / prev.clone();
+                    a[(i, j)] = v;
+                }
+            }
+            prev = akk;
+        }
+        Ok(if negate { T::zero() - prev } else { prev })
+    }
+
+    /// fraction-free gauss-jordan elimination, see `Rref`
+    pub fn rref_exact(&self) -> Rref<T> {
+        let mut a = self.clone();
+        let mut pivots = Vec::new();
+        let mut swaps = 0;
+        let mut prev = T::one();
+        for c in 0..a.m {
+            let r = pivots.len();
+            if r == a.n {
+                break;
+            }
+            let p = match pivot_row(&a, r, c) {
+                Some(p) => p,
+                None => continue,
+            };
+            if p != r {
+                swap_rows(&mut a, p, r);
+                swaps += 1;
+            }
+            let arc = a[(r, c)].clone();
+            for i in (0..a.n).filter(|&i| i != r) {
+                let aic = a[(i, c)].clone();
+                for j in (0..a.m).filter(|&j| j != c) {
+                    let v = (arc.clone() * a[(i, j)].clone() - aic.clone() * a[(r, j)].clone()) / prev.clone();
+                    a[(i, j)] = v;
+                }
+                a[(i, c)] = T::zero();
+            }
+            prev = arc;
+            pivots.push(c);
+        }
+        Rref { matrix: a, pivots, scale: prev, swaps }
+    }
+
+    /// exact rank
+    pub fn rank_exact(&self) -> usize {
+        self.rref_exact().rank()
+    }
+
+    /// exact basis of {x : A x = 0}
+    pub fn nullspace_exact(&self) -> Vec<VectorN<T>> {
+        self.rref_exact().nullspace()
+    }
+
+    /// solve A x = b exactly
+    ///
+    /// the last step divides by a determinant, so integer types only give the
+    /// right answer when the solution is integral; use rationals otherwise.
+    pub fn solve_exact(&self, b: &VectorN<T>) -> Result<VectorN<T>, ExactError> {
+        if self.n != self.m {
+            return Err(ExactError::NotSquare { rows: self.n, cols: self.m });
+        }
+        if b.len() != self.n {
+            return Err(ExactError::DimensionMismatch { expected: self.n, found: b.len() });
+        }
+        let n = self.n;
+        let mut data = Vec::with_capacity(n * (n + 1));
+        for i in 0..n {
+            data.extend_from_slice(self.row(i));
+            data.push(b[i].clone());
+        }
+        let r = Matrix::from_vec(n, n + 1, data).rref_exact();
+        if r.pivots.len() < n || r.pivots.iter().any(|&p| p >= n) {
+            return Err(ExactError::Singular);
+        }
+        Ok((0..n).map(|i| r.matrix[(i, n)].clone() / r.scale.clone()).collect())
+    }
+
+    /// exact inverse by eliminating [A | I]
+    pub fn inverse_exact(&self) -> Result<Self, ExactError> {
+        if self.n != self.m {
+            return Err(ExactError::NotSquare { rows: self.n, cols: self.m });
+        }
+        let n = self.n;
+        let mut data = Vec::with_capacity(2 * n * n);
+        for i in 0..n {
+            data.extend_from_slice(self.row(i));
+            data.extend((0..n).map(|j| if i == j { T::one() } else { T::zero() }));
+        }
+        let r = Matrix::from_vec(n, 2 * n, data).rref_exact();
+        if r.pivots.len() < n || r.pivots.iter().any(|&p| p >= n) {
+            return Err(ExactError::Singular);
+        }
+        let inv = (0..n * n).map(|k| r.matrix[(k / n, n + k % n)].clone() / r.scale.clone()).collect();
+        Ok(Matrix::from_vec(n, n, inv))
+    }
+}
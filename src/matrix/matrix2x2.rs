@@ -2,6 +2,8 @@ use std::ops::{Add,Sub,Mul,Div,Neg,Index,IndexMut};
 
 /// 2x2 matrix with 32bit float number
 #[derive(Debug,Copy,Clone,PartialEq,PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "Mat2f", into = "Mat2f"))]
+#[repr(C)]
 pub struct Matrix2x2 {
     pub data: [[f32;2];2],
 }
@@ -16,12 +18,81 @@ impl Matrix2x2 {
         }
     }
 
+    /// get a matrix whose rows are r0 and r1
+    pub fn from_rows(r0: Vector2, r1: Vector2) -> Self {
+        Self {
+            data: [[r0.x, r0.y]
+                  ,[r1.x, r1.y]],
+        }
+    }
+
+    /// get a matrix whose columns are c0 and c1
+    pub fn from_cols(c0: Vector2, c1: Vector2) -> Self {
+        Self {
+            data: [[c0.x, c1.x]
+                  ,[c0.y, c1.y]],
+        }
+    }
+
+    /// get a matrix from 4 elements listed row by row
+    pub fn from_rows_array(a: &[f32;4]) -> Self {
+        let mut data = [[0.0;2];2];
+        for (i, e) in a.iter().enumerate() {
+            data[i / 2][i % 2] = *e;
+        }
+        Self { data }
+    }
+
+    /// get a matrix from 4 elements listed column by column
+    pub fn from_cols_array(a: &[f32;4]) -> Self {
+        let mut data = [[0.0;2];2];
+        for (i, e) in a.iter().enumerate() {
+            data[i % 2][i / 2] = *e;
+        }
+        Self { data }
+    }
+
+    /// get row i
+    pub fn row(&self, i: usize) -> Vector2 {
+        Vector2::from(self.data[i])
+    }
+
+    /// get column j
+    pub fn col(&self, j: usize) -> Vector2 {
+        Vector2::new(self[0][j], self[1][j])
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            data: [[self[0][0], self[1][0]]
+                  ,[self[0][1], self[1][1]]],
+        }
+    }
+
+    /// all elements listed row by row, the order they are stored in
+    pub fn to_rows_array(&self) -> [f32;4] {
+        let mut ret = [0.0;4];
+        for (i, e) in ret.iter_mut().enumerate() {
+            *e = self.data[i / 2][i % 2];
+        }
+        ret
+    }
+
+    /// all elements listed column by column, the order column-major apis expect
+    pub fn to_cols_array(&self) -> [f32;4] {
+        let mut ret = [0.0;4];
+        for (i, e) in ret.iter_mut().enumerate() {
+            *e = self.data[i % 2][i / 2];
+        }
+        ret
+    }
+
     pub fn trace(&self) -> f32 {
         self[0][0] + self[1][1]
     }
 
     pub fn determinate(&self) -> f32 {
-        self[0][0] * self[1][1] - self[0][1] * self[1][1]
+        self[0][0] * self[1][1] - self[0][1] * self[1][0]
     }
 
     pub fn translate1(x: f32) -> Self {
@@ -129,10 +200,10 @@ impl Mul for Matrix2x2 {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
-        let a00 = self[0][0] * self[0][0] + self[0][1] * rhs[1][0];
-        let a01 = self[0][0] * self[0][1] + self[0][1] * rhs[1][1];
-        let a10 = self[1][0] * self[0][0] + self[1][1] * rhs[1][0];
-        let a11 = self[1][0] * self[0][1] + self[1][1] * rhs[1][1];
+        let a00 = self[0][0] * rhs[0][0] + self[0][1] * rhs[1][0];
+        let a01 = self[0][0] * rhs[0][1] + self[0][1] * rhs[1][1];
+        let a10 = self[1][0] * rhs[0][0] + self[1][1] * rhs[1][0];
+        let a11 = self[1][0] * rhs[0][1] + self[1][1] * rhs[1][1];
 
         Self {
             data: [[a00, a01]
@@ -203,16 +274,7 @@ impl From<Matrix2x2> for Mat2f {
 
 use crate::vector::Vector2;
 
-impl Mul<Matrix2x2> for Vector2 {
-    type Output = Vector2;
-
-    fn mul(self, rhs: Matrix2x2) -> Vector2 {
-        let a = Vector2::dot(self, Vector2::from(rhs[0]));
-        let b = Vector2::dot(self, Vector2::from(rhs[1]));
-        Vector2::new(a, b)
-    }
-}
-
+/// matrix times column vector, m v
 impl Mul<Vector2> for Matrix2x2 {
     type Output = Vector2;
 
@@ -221,4 +283,20 @@ impl Mul<Vector2> for Matrix2x2 {
         let b = Vector2::dot(Vector2::from(self[1]), rhs);
         Vector2::new(a, b)
     }
-}
\ No newline at end of file
+}
+
+use std::fmt;
+
+impl fmt::Display for Matrix2x2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::write_matrix(f, 2, 2, |i, j| &self.data[i][j])
+    }
+}
+
+impl std::str::FromStr for Matrix2x2 {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_square(s).map(|data| Self { data })
+    }
+}
@@ -2,6 +2,8 @@ use std::ops::{Add,Sub,Mul,Div,Neg,Index,IndexMut};
 
 /// 3x3 matrix with 32bit float number
 #[derive(Debug,Copy,Clone,PartialEq,PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "Mat3f", into = "Mat3f"))]
+#[repr(C)]
 pub struct Matrix3x3 {
     pub data: [[f32;3];3],
 }
@@ -16,6 +18,78 @@ impl Matrix3x3 {
         }
     }
 
+    /// get a matrix whose rows are r0..r2
+    pub fn from_rows(r0: Vector3, r1: Vector3, r2: Vector3) -> Self {
+        Self {
+            data: [[r0.x, r0.y, r0.z]
+                  ,[r1.x, r1.y, r1.z]
+                  ,[r2.x, r2.y, r2.z]],
+        }
+    }
+
+    /// get a matrix whose columns are c0..c2
+    pub fn from_cols(c0: Vector3, c1: Vector3, c2: Vector3) -> Self {
+        Self {
+            data: [[c0.x, c1.x, c2.x]
+                  ,[c0.y, c1.y, c2.y]
+                  ,[c0.z, c1.z, c2.z]],
+        }
+    }
+
+    /// get a matrix from 9 elements listed row by row
+    pub fn from_rows_array(a: &[f32;9]) -> Self {
+        let mut data = [[0.0;3];3];
+        for (i, e) in a.iter().enumerate() {
+            data[i / 3][i % 3] = *e;
+        }
+        Self { data }
+    }
+
+    /// get a matrix from 9 elements listed column by column
+    pub fn from_cols_array(a: &[f32;9]) -> Self {
+        let mut data = [[0.0;3];3];
+        for (i, e) in a.iter().enumerate() {
+            data[i % 3][i / 3] = *e;
+        }
+        Self { data }
+    }
+
+    /// get row i
+    pub fn row(&self, i: usize) -> Vector3 {
+        Vector3::from(self.data[i])
+    }
+
+    /// get column j
+    pub fn col(&self, j: usize) -> Vector3 {
+        Vector3::new(self[0][j], self[1][j], self[2][j])
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            data: [[self[0][0], self[1][0], self[2][0]]
+                  ,[self[0][1], self[1][1], self[2][1]]
+                  ,[self[0][2], self[1][2], self[2][2]]],
+        }
+    }
+
+    /// all elements listed row by row, the order they are stored in
+    pub fn to_rows_array(&self) -> [f32;9] {
+        let mut ret = [0.0;9];
+        for (i, e) in ret.iter_mut().enumerate() {
+            *e = self.data[i / 3][i % 3];
+        }
+        ret
+    }
+
+    /// all elements listed column by column, the order column-major apis expect
+    pub fn to_cols_array(&self) -> [f32;9] {
+        let mut ret = [0.0;9];
+        for (i, e) in ret.iter_mut().enumerate() {
+            *e = self.data[i % 3][i / 3];
+        }
+        ret
+    }
+
     pub fn trace(&self) -> f32 {
         self[0][0] + self[1][1] + self[2][2]
     }
@@ -38,6 +112,7 @@ impl Matrix3x3 {
         Self::new(x, y, z)
     }
 
+    /// z-x-z proper euler angles, see `transform::rotate3`
     pub fn rotate3(x: f32, y: f32, z:f32) -> Self {
         use crate::matrix::transform::rotate3;
 
@@ -78,7 +153,7 @@ impl Add for Matrix3x3 {
         Self{
             data: [[self[0][0] + rhs[0][0], self[0][1] + rhs[0][1], self[0][2] + rhs[0][2]]
                   ,[self[1][0] + rhs[1][0], self[1][1] + rhs[1][1], self[1][2] + rhs[1][2]]
-                  ,[self[2][0] + rhs[2][2], self[2][1] + rhs[2][1], self[2][2] + rhs[2][2]]],
+                  ,[self[2][0] + rhs[2][0], self[2][1] + rhs[2][1], self[2][2] + rhs[2][2]]],
         }
     }
 }
@@ -139,9 +214,9 @@ impl Mul for Matrix3x3 {
         let a01 = self[0][0] * rhs[0][1] + self[0][1] * rhs[1][1] + self[0][2] * rhs[2][1];
         let a02 = self[0][0] * rhs[0][2] + self[0][1] * rhs[1][2] + self[0][2] * rhs[2][2];
 
-        let a10 = self[1][0] * rhs[1][0] + self[1][1] * rhs[1][0] + self[1][2] * rhs[2][0];
-        let a11 = self[1][0] * rhs[1][1] + self[1][1] * rhs[1][1] + self[1][2] * rhs[2][1];
-        let a12 = self[1][0] * rhs[1][2] + self[1][1] * rhs[1][2] + self[1][2] * rhs[2][2];
+        let a10 = self[1][0] * rhs[0][0] + self[1][1] * rhs[1][0] + self[1][2] * rhs[2][0];
+        let a11 = self[1][0] * rhs[0][1] + self[1][1] * rhs[1][1] + self[1][2] * rhs[2][1];
+        let a12 = self[1][0] * rhs[0][2] + self[1][1] * rhs[1][2] + self[1][2] * rhs[2][2];
         
         let a20 = self[2][0] * rhs[0][0] + self[2][1] * rhs[1][0] + self[2][2] * rhs[2][0];
         let a21 = self[2][0] * rhs[0][1] + self[2][1] * rhs[1][1] + self[2][2] * rhs[2][1];
@@ -221,17 +296,7 @@ impl From<Matrix3x3> for Mat3f {
 
 use crate::vector::Vector3;
 
-impl Mul<Matrix3x3> for Vector3 {
-    type Output = Vector3;
-
-    fn mul(self, rhs: Matrix3x3) -> Vector3 {
-        let a = Vector3::dot(self, Vector3::from(rhs[0]));
-        let b = Vector3::dot(self, Vector3::from(rhs[1]));
-        let c = Vector3::dot(self, Vector3::from(rhs[2]));
-        Vector3::new(a, b, c)
-    }
-}
-
+/// matrix times column vector, m v
 impl Mul<Vector3> for Matrix3x3 {
     type Output = Vector3;
 
@@ -241,4 +306,20 @@ impl Mul<Vector3> for Matrix3x3 {
         let c = Vector3::dot(Vector3::from(self[2]), rhs);
         Vector3::new(a, b, c)
     }
-}
\ No newline at end of file
+}
+
+use std::fmt;
+
+impl fmt::Display for Matrix3x3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::write_matrix(f, 3, 3, |i, j| &self.data[i][j])
+    }
+}
+
+impl std::str::FromStr for Matrix3x3 {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_square(s).map(|data| Self { data })
+    }
+}
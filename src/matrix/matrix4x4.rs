@@ -2,6 +2,8 @@ use std::ops::{Add,Sub,Mul,Div,Neg,Index,IndexMut};
 
 /// 4x4 matrix with 32bit float number
 #[derive(Debug,Copy,Clone,PartialEq,PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "Mat4f", into = "Mat4f"))]
+#[repr(C)]
 pub struct Matrix4x4 {
     pub data: [[f32;4];4]
 }
@@ -17,6 +19,81 @@ impl Matrix4x4 {
         }
     }
 
+    /// get a matrix whose rows are r0..r3
+    pub fn from_rows(r0: Vector4, r1: Vector4, r2: Vector4, r3: Vector4) -> Self {
+        Self {
+            data: [[r0.x, r0.y, r0.z, r0.w]
+                  ,[r1.x, r1.y, r1.z, r1.w]
+                  ,[r2.x, r2.y, r2.z, r2.w]
+                  ,[r3.x, r3.y, r3.z, r3.w]],
+        }
+    }
+
+    /// get a matrix whose columns are c0..c3
+    pub fn from_cols(c0: Vector4, c1: Vector4, c2: Vector4, c3: Vector4) -> Self {
+        Self {
+            data: [[c0.x, c1.x, c2.x, c3.x]
+                  ,[c0.y, c1.y, c2.y, c3.y]
+                  ,[c0.z, c1.z, c2.z, c3.z]
+                  ,[c0.w, c1.w, c2.w, c3.w]],
+        }
+    }
+
+    /// get a matrix from 16 elements listed row by row
+    pub fn from_rows_array(a: &[f32;16]) -> Self {
+        let mut data = [[0.0;4];4];
+        for (i, e) in a.iter().enumerate() {
+            data[i / 4][i % 4] = *e;
+        }
+        Self { data }
+    }
+
+    /// get a matrix from 16 elements listed column by column
+    pub fn from_cols_array(a: &[f32;16]) -> Self {
+        let mut data = [[0.0;4];4];
+        for (i, e) in a.iter().enumerate() {
+            data[i % 4][i / 4] = *e;
+        }
+        Self { data }
+    }
+
+    /// get row i
+    pub fn row(&self, i: usize) -> Vector4 {
+        Vector4::from(self.data[i])
+    }
+
+    /// get column j
+    pub fn col(&self, j: usize) -> Vector4 {
+        Vector4::new(self[0][j], self[1][j], self[2][j], self[3][j])
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            data: [[self[0][0], self[1][0], self[2][0], self[3][0]]
+                  ,[self[0][1], self[1][1], self[2][1], self[3][1]]
+                  ,[self[0][2], self[1][2], self[2][2], self[3][2]]
+                  ,[self[0][3], self[1][3], self[2][3], self[3][3]]],
+        }
+    }
+
+    /// all elements listed row by row, the order they are stored in
+    pub fn to_rows_array(&self) -> [f32;16] {
+        let mut ret = [0.0;16];
+        for (i, e) in ret.iter_mut().enumerate() {
+            *e = self.data[i / 4][i % 4];
+        }
+        ret
+    }
+
+    /// all elements listed column by column, the order column-major apis expect
+    pub fn to_cols_array(&self) -> [f32;16] {
+        let mut ret = [0.0;16];
+        for (i, e) in ret.iter_mut().enumerate() {
+            *e = self.data[i % 4][i / 4];
+        }
+        ret
+    }
+
     pub fn from(data: [[f32;4];4]) -> Self {
         Self { data }
     }
@@ -167,7 +244,7 @@ impl Mul<f32> for Matrix4x4 {
     fn mul(self, rhs: f32) -> Self {
         Self {
             data: [[self[0][0] * rhs, self[0][1] * rhs, self[0][2] * rhs, self[0][3] * rhs]
-                  ,[self[1][0] * rhs, self[1][1] * rhs, self[1][2] * rhs, self[0][3] * rhs]
+                  ,[self[1][0] * rhs, self[1][1] * rhs, self[1][2] * rhs, self[1][3] * rhs]
                   ,[self[2][0] * rhs, self[2][1] * rhs, self[2][2] * rhs, self[2][3] * rhs]
                   ,[self[3][0] * rhs, self[3][1] * rhs, self[3][2] * rhs, self[3][3] * rhs]],
         }
@@ -231,18 +308,7 @@ impl From<Matrix4x4> for Mat4f {
 
 use crate::vector::Vector4;
 
-impl Mul<Matrix4x4> for Vector4 {
-    type Output = Vector4;
-
-    fn mul(self, rhs: Matrix4x4) -> Vector4 {
-        let a = Vector4::dot(self, Vector4::from(rhs[0]));
-        let b = Vector4::dot(self, Vector4::from(rhs[1]));
-        let c = Vector4::dot(self, Vector4::from(rhs[2]));
-        let d = Vector4::dot(self, Vector4::from(rhs[3]));
-        Vector4::new(a, b, c, d)
-    }
-}
-
+/// matrix times column vector, m v
 impl Mul<Vector4> for Matrix4x4 {
     type Output = Vector4;
 
@@ -253,4 +319,20 @@ impl Mul<Vector4> for Matrix4x4 {
         let d = Vector4::dot(Vector4::from(self[3]), rhs);
         Vector4::new(a, b, c, d)
     }
-}
\ No newline at end of file
+}
+
+use std::fmt;
+
+impl fmt::Display for Matrix4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::write_matrix(f, 4, 4, |i, j| &self.data[i][j])
+    }
+}
+
+impl std::str::FromStr for Matrix4x4 {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_square(s).map(|data| Self { data })
+    }
+}
@@ -1,6 +1,7 @@
 #[macro_export]
 macro_rules! matrix_n {
     ($name:ident, $N: expr) => (
+        #[derive(Debug, Clone, PartialEq)]
         pub struct $name<T> {
             pub data : [[T;$N];$N],
         }
@@ -12,6 +13,87 @@ macro_rules! matrix_n {
                 }
             }
         }
+
+        impl<T: std::fmt::Display> std::fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                $crate::display::write_matrix(f, $N, $N, |i, j| &self.data[i][j])
+            }
+        }
+
+        impl<T: std::str::FromStr> std::str::FromStr for $name<T> {
+            type Err = $crate::parse::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::parse::parse_square(s).map(|data| $name { data })
+            }
+        }
+
+        impl<T: $crate::approx_eq::ApproxEq> $crate::approx_eq::ApproxEq for $name<T> {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                self.data.abs_diff_eq(&other.data, epsilon)
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                self.data.relative_eq(&other.data, epsilon, max_relative)
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                self.data.ulps_eq(&other.data, epsilon, max_ulps)
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T: Copy> $name<T> {
+            /// get a matrix from row arrays
+            pub fn from_rows(rows: [[T; $N];$N]) -> Self {
+                Self::new(rows)
+            }
+
+            /// get a matrix from column arrays
+            pub fn from_cols(cols: [[T; $N];$N]) -> Self {
+                Self::new(cols).transpose()
+            }
+
+            /// get row i
+            pub fn row(&self, i: usize) -> [T; $N] {
+                self.data[i]
+            }
+
+            /// get column j
+            pub fn col(&self, j: usize) -> [T; $N] {
+                let mut ret = self.data[0];
+                for (i, e) in ret.iter_mut().enumerate() {
+                    *e = self.data[i][j];
+                }
+                ret
+            }
+
+            pub fn transpose(&self) -> Self {
+                let mut data = self.data;
+                for (i, row) in data.iter_mut().enumerate() {
+                    for (j, e) in row.iter_mut().enumerate() {
+                        *e = self.data[j][i];
+                    }
+                }
+                $name { data }
+            }
+
+            /// all elements listed row by row, the order they are stored in
+            pub fn to_rows_array(&self) -> Vec<T> {
+                self.data.iter().flat_map(|row| row.iter().copied()).collect()
+            }
+
+            /// all elements listed column by column
+            pub fn to_cols_array(&self) -> Vec<T> {
+                self.transpose().to_rows_array()
+            }
+        }
     );
 }
 
@@ -19,5 +101,19 @@ macro_rules! matrix_n {
 #[test]
 fn matrix() {
     matrix_n!(Matrix2,2);
-    Matrix2::<f32>::new([[1f32,1f32],[1f32,1f32]]);
-}
\ No newline at end of file
+    let m = Matrix2::<f32>::new([[1f32,1f32],[1f32,1f32]]);
+    assert_eq!(m.data[1][1], 1f32);
+
+    matrix_n!(Matrix3,3);
+    let m = Matrix3::<i32>::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(m.col(1), [2, 5, 8]);
+    assert_eq!(m.transpose().row(1), [2, 5, 8]);
+    assert_eq!(Matrix3::from_cols(m.data).data, m.transpose().data);
+    assert_eq!(m.to_cols_array(), vec![1, 4, 7, 2, 5, 8, 3, 6, 9]);
+    assert_eq!(format!("{:#}", m), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+    assert_eq!(m.to_string().parse::<Matrix3<i32>>().unwrap(), m);
+
+    let a = Matrix2::new([[0.1f64 + 0.2, 1.0], [2.0, 3.0]]);
+    assert_ne!(a, Matrix2::new([[0.3, 1.0], [2.0, 3.0]]));
+    crate::assert_approx_eq!(a, Matrix2::new([[0.3, 1.0], [2.0, 3.0]]));
+}
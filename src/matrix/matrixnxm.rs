@@ -1,5 +1,207 @@
+use std::ops::{Index, IndexMut};
+
+/// n x m matrix with runtime size, n rows and m columns stored row by row
 pub struct Matrix<T> {
     pub n: usize,
     pub m: usize,
     pub data: Box<[T]>,
-}
\ No newline at end of file
+}
+
+impl<T: Clone> Matrix<T> {
+    /// get a n x m matrix from elements listed row by row, panics if data is not n * m long
+    pub fn from_vec(n: usize, m: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), n * m, "matrix data length is not n * m");
+        Self { n, m, data: data.into_boxed_slice() }
+    }
+
+    /// get a matrix from equally long rows
+    pub fn from_rows<R: AsRef<[T]>>(rows: &[R]) -> Self {
+        let n = rows.len();
+        let m = rows.first().map_or(0, |r| r.as_ref().len());
+        let mut data = Vec::with_capacity(n * m);
+        for r in rows {
+            assert_eq!(r.as_ref().len(), m, "matrix rows have different lengths");
+            data.extend_from_slice(r.as_ref());
+        }
+        Self::from_vec(n, m, data)
+    }
+
+    /// get a matrix from equally long columns
+    pub fn from_cols<C: AsRef<[T]>>(cols: &[C]) -> Self {
+        Self::from_rows(cols).transpose()
+    }
+
+    /// get row i
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.m..(i + 1) * self.m]
+    }
+
+    /// get column j
+    pub fn col(&self, j: usize) -> Vec<T> {
+        (0..self.n).map(|i| self.data[i * self.m + j].clone()).collect()
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.m {
+            for i in 0..self.n {
+                data.push(self.data[i * self.m + j].clone());
+            }
+        }
+        Self { n: self.m, m: self.n, data: data.into_boxed_slice() }
+    }
+
+    /// all elements listed row by row, the order they are stored in
+    pub fn to_rows_array(&self) -> Vec<T> {
+        self.data.to_vec()
+    }
+
+    /// all elements listed column by column
+    pub fn to_cols_array(&self) -> Vec<T> {
+        self.transpose().to_rows_array()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.n && j < self.m, "Invalid index into Matrix");
+        &self.data[i * self.m + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.n && j < self.m, "Invalid index into Matrix");
+        &mut self.data[i * self.m + j]
+    }
+}
+
+/// serialized as `{ n, m, data }` with data flattened row by row
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Matrix<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("Matrix", 3)?;
+        s.serialize_field("n", &self.n)?;
+        s.serialize_field("m", &self.m)?;
+        s.serialize_field("data", &self.data)?;
+        s.end()
+    }
+}
+
+/// rejects data whose length is not n * m
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Matrix<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Matrix")]
+        struct Raw<T> {
+            n: usize,
+            m: usize,
+            data: Vec<T>,
+        }
+
+        let Raw { n, m, data } = Raw::deserialize(deserializer)?;
+        match n.checked_mul(m) {
+            Some(len) if len == data.len() => Ok(Self { n, m, data: data.into_boxed_slice() }),
+            _ => Err(D::Error::invalid_length(data.len(), &"n * m elements")),
+        }
+    }
+}
+
+use std::ops::{Add, Mul};
+use num::{One, Zero};
+
+impl<T: Clone + Zero> Matrix<T> {
+    /// get a n x m matrix of zeros
+    pub fn zeros(n: usize, m: usize) -> Self {
+        Self { n, m, data: vec![T::zero(); n * m].into_boxed_slice() }
+    }
+}
+
+impl<T: Clone + Zero + One> Matrix<T> {
+    /// get the n x n identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut ret = Self::zeros(n, n);
+        for i in 0..n {
+            ret[(i, i)] = T::one();
+        }
+        ret
+    }
+}
+
+impl<T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Matrix<T> {
+    /// matrix times column vector, panics if x is not m long
+    pub fn mul_vec(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), self.m, "matrix vector dimension mismatch");
+        (0..self.n)
+            .map(|i| self.row(i).iter().zip(x).fold(T::zero(), |acc, (a, b)| acc + a.clone() * b.clone()))
+            .collect()
+    }
+}
+
+impl<T: Clone> Clone for Matrix<T> {
+    fn clone(&self) -> Self {
+        Self { n: self.n, m: self.m, data: self.data.clone() }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Matrix").field("n", &self.n).field("m", &self.m).field("data", &self.data).finish()
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        crate::display::write_matrix(f, self.n, self.m, |i, j| &self.data[i * self.m + j])
+    }
+}
+
+impl<T: std::str::FromStr> std::str::FromStr for Matrix<T> {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (n, m, data) = crate::parse::parse_matrix(s)?;
+        Ok(Self { n, m, data: data.into_boxed_slice() })
+    }
+}
+
+impl<T: PartialEq> PartialEq for Matrix<T> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.n == rhs.n && self.m == rhs.m && self.data == rhs.data
+    }
+}
+
+impl<T: Clone + Zero + Add<Output = T>> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Self) -> Matrix<T> {
+        assert!(self.n == rhs.n && self.m == rhs.m, "matrix dimension mismatch");
+        let data: Vec<T> = self.data.iter().zip(rhs.data.iter()).map(|(a, b)| a.clone() + b.clone()).collect();
+        Matrix { n: self.n, m: self.m, data: data.into_boxed_slice() }
+    }
+}
+
+impl<T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Matrix<T> {
+        assert_eq!(self.m, rhs.n, "matrix product dimension mismatch");
+        let mut ret: Matrix<T> = Matrix::zeros(self.n, rhs.m);
+        for i in 0..self.n {
+            for k in 0..self.m {
+                let a = &self[(i, k)];
+                for j in 0..rhs.m {
+                    ret[(i, j)] = ret[(i, j)].clone() + a.clone() * rhs[(k, j)].clone();
+                }
+            }
+        }
+        ret
+    }
+}
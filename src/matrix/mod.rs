@@ -1,3 +1,20 @@
+//! matrix math utils
+//!
+//! # storage and multiplication convention
+//!
+//! every matrix stores its elements row by row: `m.data[i]` (and `m[i]`) is
+//! row i, `m[i][j]` is the element in row i and column j. vectors are column
+//! vectors, `m * v` is the usual matrix-vector product and `a * b` applies
+//! `b` first. `transform::translate3` accordingly puts the translation in the
+//! last column.
+//!
+//! there is no `v * m`, write the row vector product vᵀ m as
+//! `m.transpose() * v`.
+//!
+//! graphics apis that want column-major data (opengl, vulkan, wgsl, glam)
+//! should be handed `to_cols_array()`, which lists the elements column by
+//! column. `to_rows_array()` is the storage order. no transpose flag is
+//! needed on the api side.
 pub mod matrix2x2;
 pub mod matrix3x3;
 pub mod matrix4x4;
@@ -9,4 +26,14 @@ pub use matrix2x2::*;
 pub use matrix3x3::*;
 pub use matrix4x4::*;
 
-pub mod transform;
\ No newline at end of file
+pub mod transform;
+pub mod batch;
+pub mod sparse;
+pub mod solver;
+pub mod square;
+pub mod cholesky;
+pub mod eigen;
+pub mod exact;
+pub mod normal_form;
+pub mod rotation;
+pub mod affine;
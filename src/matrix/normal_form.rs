@@ -0,0 +1,187 @@
+//! hermite and smith normal forms of integer matrices
+//!
+//! both come with the unimodular transforms that produce them, for any
+//! integer type: i64, i128 or `BigInt` when entries grow.
+use num::{Integer, Signed};
+use crate::number_theory::extended_gcd;
+use super::matrixnxm::Matrix;
+
+/// row style hermite normal form H = U·A
+///
+/// H is upper echelon, its pivots are positive and the entries above a
+/// pivot lie in [0, pivot). U is unimodular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HermiteForm<T> {
+    pub h: Matrix<T>,
+    pub u: Matrix<T>,
+}
+
+/// smith normal form D = U·A·V
+///
+/// D is diagonal with non-negative entries each dividing the next, U and
+/// V are unimodular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmithForm<T> {
+    pub d: Matrix<T>,
+    pub u: Matrix<T>,
+    pub v: Matrix<T>,
+}
+
+impl<T: Integer + Signed + Clone> SmithForm<T> {
+    /// the nonzero diagonal entries d₁ | d₂ | ...
+    pub fn invariant_factors(&self) -> Vec<T> {
+        (0..self.d.n.min(self.d.m)).map(|i| self.d[(i, i)].clone()).filter(|x| !x.is_zero()).collect()
+    }
+}
+
+fn swap_rows<T>(a: &mut Matrix<T>, i: usize, j: usize) {
+    for c in 0..a.m {
+        a.data.swap(i * a.m + c, j * a.m + c);
+    }
+}
+
+fn swap_cols<T>(a: &mut Matrix<T>, i: usize, j: usize) {
+    for r in 0..a.n {
+        a.data.swap(r * a.m + i, r * a.m + j);
+    }
+}
+
+/// row i ← row i - q·row k
+fn sub_row<T: Integer + Clone>(a: &mut Matrix<T>, i: usize, k: usize, q: &T) {
+    for c in 0..a.m {
+        let v = a[(i, c)].clone() - q.clone() * a[(k, c)].clone();
+        a[(i, c)] = v;
+    }
+}
+
+/// column j ← column j - q·column k
+fn sub_col<T: Integer + Clone>(a: &mut Matrix<T>, j: usize, k: usize, q: &T) {
+    for r in 0..a.n {
+        let v = a[(r, j)].clone() - q.clone() * a[(r, k)].clone();
+        a[(r, j)] = v;
+    }
+}
+
+fn negate_row<T: Signed + Clone>(a: &mut Matrix<T>, i: usize) {
+    for c in 0..a.m {
+        a[(i, c)] = -a[(i, c)].clone();
+    }
+}
+
+/// (row r, row i) ← ((x, y), (-b/g, a/g))·(row r, row i), determinant one
+fn combine_rows<T: Integer + Clone>(a: &mut Matrix<T>, r: usize, i: usize, m: &[T; 4]) {
+    for c in 0..a.m {
+        let (p, q) = (a[(r, c)].clone(), a[(i, c)].clone());
+        a[(r, c)] = m[0].clone() * p.clone() + m[1].clone() * q.clone();
+        a[(i, c)] = m[2].clone() * p + m[3].clone() * q;
+    }
+}
+
+impl<T: Integer + Signed + Clone> Matrix<T> {
+    pub fn hermite_normal_form(&self) -> HermiteForm<T> {
+        let mut h = self.clone();
+        let mut u = Matrix::identity(self.n);
+        let mut r = 0;
+        for c in 0..h.m {
+            if r == h.n {
+                break;
+            }
+            // gcd of the column into row r, zeros below
+            for i in r + 1..h.n {
+                if h[(i, c)].is_zero() {
+                    continue;
+                }
+                let (a, b) = (h[(r, c)].clone(), h[(i, c)].clone());
+                let (g, x, y) = extended_gcd(a.clone(), b.clone());
+                let m = [x, y, -(b / g.clone()), a / g];
+                combine_rows(&mut h, r, i, &m);
+                combine_rows(&mut u, r, i, &m);
+            }
+            if h[(r, c)].is_zero() {
+                continue;
+            }
+            if h[(r, c)].is_negative() {
+                negate_row(&mut h, r);
+                negate_row(&mut u, r);
+            }
+            let pivot = h[(r, c)].clone();
+            for i in 0..r {
+                let q = h[(i, c)].div_floor(&pivot);
+                sub_row(&mut h, i, r, &q);
+                sub_row(&mut u, i, r, &q);
+            }
+            r += 1;
+        }
+        HermiteForm { h, u }
+    }
+
+    pub fn smith_normal_form(&self) -> SmithForm<T> {
+        let (n, m) = (self.n, self.m);
+        let mut d = self.clone();
+        let mut u = Matrix::identity(n);
+        let mut v = Matrix::identity(m);
+        for t in 0..n.min(m) {
+            loop {
+                // smallest nonzero entry of the remaining block becomes the pivot
+                let mut best: Option<(usize, usize)> = None;
+                for i in t..n {
+                    for j in t..m {
+                        let x = d[(i, j)].abs();
+                        // Option::is_none_or needs rust 1.82
+                        let smaller = match best {
+                            Some((bi, bj)) => x < d[(bi, bj)].abs(),
+                            None => true,
+                        };
+                        if !x.is_zero() && smaller {
+                            best = Some((i, j));
+                        }
+                    }
+                }
+                let (pi, pj) = match best {
+                    Some(p) => p,
+                    None => return SmithForm { d, u, v },
+                };
+                if pi != t {
+                    swap_rows(&mut d, pi, t);
+                    swap_rows(&mut u, pi, t);
+                }
+                if pj != t {
+                    swap_cols(&mut d, pj, t);
+                    swap_cols(&mut v, pj, t);
+                }
+                let pivot = d[(t, t)].clone();
+                let mut clean = true;
+                for i in t + 1..n {
+                    let q = d[(i, t)].clone() / pivot.clone();
+                    sub_row(&mut d, i, t, &q);
+                    sub_row(&mut u, i, t, &q);
+                    clean &= d[(i, t)].is_zero();
+                }
+                for j in t + 1..m {
+                    let q = d[(t, j)].clone() / pivot.clone();
+                    sub_col(&mut d, j, t, &q);
+                    sub_col(&mut v, j, t, &q);
+                    clean &= d[(t, j)].is_zero();
+                }
+                if !clean {
+                    continue;
+                }
+                // the pivot has to divide the rest, else pull a row in and go again
+                let bad = (t + 1..n).find(|&i| (t + 1..m).any(|j| !d[(i, j)].is_multiple_of(&pivot)));
+                match bad {
+                    Some(i) => {
+                        let minus_one = -T::one();
+                        sub_row(&mut d, t, i, &minus_one);
+                        sub_row(&mut u, t, i, &minus_one);
+                    }
+                    None => break,
+                }
+            }
+            if d[(t, t)].is_negative() {
+                negate_row(&mut d, t);
+                negate_row(&mut u, t);
+            }
+        }
+        SmithForm { d, u, v }
+    }
+}
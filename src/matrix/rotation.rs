@@ -0,0 +1,282 @@
+//! rotations in R³ as unit quaternions and rotation matrices
+//!
+//! a quaternion is a `Vector4` (x, y, z, w) with w the scalar part, the
+//! same layout `random::rotation_quaternion` returns. rotation matrices are
+//! `Matrix3x3` applied as `m * v`.
+use crate::vector::{Vector3, Vector4};
+use super::Matrix3x3;
+
+/// the identity rotation
+pub const QUAT_IDENTITY: Vector4 = Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+
+/// rotation by angle radians about axis, counter clockwise looking down the axis
+pub fn quat_from_axis_angle(axis: Vector3, angle: f32) -> Vector4 {
+    let a = axis.normalized();
+    let (s, c) = (angle / 2.0).sin_cos();
+    Vector4::new(a.x * s, a.y * s, a.z * s, c)
+}
+
+/// hamilton product a·b, the rotation b followed by a
+pub fn quat_mul(a: Vector4, b: Vector4) -> Vector4 {
+    Vector4::new(
+        a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    )
+}
+
+/// conjugate, the inverse rotation of a unit quaternion
+pub fn quat_conjugate(q: Vector4) -> Vector4 {
+    Vector4::new(-q.x, -q.y, -q.z, q.w)
+}
+
+/// rotate v by the unit quaternion q
+pub fn quat_rotate(q: Vector4, v: Vector3) -> Vector3 {
+    // v + 2w(u × v) + 2u × (u × v)
+    let u = Vector3::new(q.x, q.y, q.z);
+    let t = Vector3::cross(u, v) * 2.0;
+    v + t * q.w + Vector3::cross(u, t)
+}
+
+/// rotation matrix of a unit quaternion
+pub fn quat_to_matrix3(q: Vector4) -> Matrix3x3 {
+    let (x, y, z, w) = (q.x, q.y, q.z, q.w);
+    Matrix3x3 {
+        data: [
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w)],
+            [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w)],
+            [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y)],
+        ],
+    }
+}
+
+/// unit quaternion of a rotation matrix, with w ≥ 0
+///
+/// picks the numerically largest component first (shepperd's method).
+pub fn quat_from_matrix3(m: &Matrix3x3) -> Vector4 {
+    let d = &m.data;
+    let trace = d[0][0] + d[1][1] + d[2][2];
+    let q = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        Vector4::new((d[2][1] - d[1][2]) / s, (d[0][2] - d[2][0]) / s, (d[1][0] - d[0][1]) / s, s / 4.0)
+    } else if d[0][0] > d[1][1] && d[0][0] > d[2][2] {
+        let s = (1.0 + d[0][0] - d[1][1] - d[2][2]).sqrt() * 2.0;
+        Vector4::new(s / 4.0, (d[0][1] + d[1][0]) / s, (d[0][2] + d[2][0]) / s, (d[2][1] - d[1][2]) / s)
+    } else if d[1][1] > d[2][2] {
+        let s = (1.0 + d[1][1] - d[0][0] - d[2][2]).sqrt() * 2.0;
+        Vector4::new((d[0][1] + d[1][0]) / s, s / 4.0, (d[1][2] + d[2][1]) / s, (d[0][2] - d[2][0]) / s)
+    } else {
+        let s = (1.0 + d[2][2] - d[0][0] - d[1][1]).sqrt() * 2.0;
+        Vector4::new((d[0][2] + d[2][0]) / s, (d[1][2] + d[2][1]) / s, s / 4.0, (d[1][0] - d[0][1]) / s)
+    };
+    let q = q.normalized();
+    if q.w < 0.0 { -q } else { q }
+}
+
+/// spherical linear interpolation between unit quaternions along the shorter arc
+pub fn quat_slerp(a: Vector4, b: Vector4, t: f32) -> Vector4 {
+    let mut cos = Vector4::dot(a, b);
+    // q and -q are the same rotation, go the short way
+    let b = if cos < 0.0 {
+        cos = -cos;
+        -b
+    } else {
+        b
+    };
+    if cos > 0.9995 {
+        // nearly parallel, lerp avoids dividing by sin ≈ 0
+        return (a + (b - a) * t).normalized();
+    }
+    let theta = cos.acos();
+    let s = theta.sin();
+    a * (((1.0 - t) * theta).sin() / s) + b * ((t * theta).sin() / s)
+}
+
+/// axis and angle of a unit quaternion, the angle in [0, π]
+///
+/// the identity has no axis and comes back as the x axis with angle 0.
+pub fn quat_to_axis_angle(q: Vector4) -> (Vector3, f32) {
+    let q = if q.w < 0.0 { -q } else { q };
+    let v = Vector3::new(q.x, q.y, q.z);
+    let s = v.length();
+    if s < 1e-7 {
+        return (Vector3::new(1.0, 0.0, 0.0), 0.0);
+    }
+    (v / s, 2.0 * s.atan2(q.w))
+}
+
+impl Matrix3x3 {
+    /// rotation by angle radians about axis, counter clockwise looking down the axis
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        let a = axis.normalized();
+        let (s, c) = angle.sin_cos();
+        let t = 1.0 - c;
+        Self {
+            data: [
+                [t * a.x * a.x + c, t * a.x * a.y - s * a.z, t * a.x * a.z + s * a.y],
+                [t * a.x * a.y + s * a.z, t * a.y * a.y + c, t * a.y * a.z - s * a.x],
+                [t * a.x * a.z - s * a.y, t * a.y * a.z + s * a.x, t * a.z * a.z + c],
+            ],
+        }
+    }
+
+    /// axis and angle of a rotation matrix, the angle in [0, π]
+    pub fn to_axis_angle(&self) -> (Vector3, f32) {
+        quat_to_axis_angle(quat_from_matrix3(self))
+    }
+
+    /// rotation matrix from euler angles, see `EulerRot`
+    pub fn from_euler(order: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        order.to_matrix3(a, b, c)
+    }
+
+    /// euler angles of a rotation matrix, see `EulerRot`
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32) {
+        order.from_matrix3(self)
+    }
+}
+
+/// axis order of a set of euler angles
+///
+/// `EulerRot::XYZ` with angles (a, b, c) is the matrix Rx(a)·Ry(b)·Rz(c):
+/// intrinsic rotations about x, then the new y, then the new z, which is the
+/// same as extrinsic rotations about fixed z, y, x in that order. the six
+/// orders with three distinct axes are tait–bryan angles, the six that
+/// repeat the first axis are proper euler angles.
+///
+/// extraction puts the middle angle in [-π/2, π/2] for tait–bryan orders and
+/// in [0, π] for proper euler orders, the other two in (-π, π]. at gimbal
+/// lock the first and last axes coincide, only their sum (or difference) is
+/// defined, and the last angle is returned as 0.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerRot {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+/// below this the middle angle is treated as gimbal locked
+const GIMBAL_EPSILON: f32 = 1e-6;
+
+fn axis_rotation(axis: usize, angle: f32) -> Matrix3x3 {
+    let (s, c) = angle.sin_cos();
+    let mut m = Matrix3x3::default();
+    let (j, k) = ((axis + 1) % 3, (axis + 2) % 3);
+    m[j][j] = c;
+    m[j][k] = -s;
+    m[k][j] = s;
+    m[k][k] = c;
+    m
+}
+
+impl EulerRot {
+    /// every order, tait–bryan first
+    pub const ALL: [EulerRot; 12] = [
+        EulerRot::XYZ,
+        EulerRot::XZY,
+        EulerRot::YXZ,
+        EulerRot::YZX,
+        EulerRot::ZXY,
+        EulerRot::ZYX,
+        EulerRot::XYX,
+        EulerRot::XZX,
+        EulerRot::YXY,
+        EulerRot::YZY,
+        EulerRot::ZXZ,
+        EulerRot::ZYZ,
+    ];
+
+    /// the three axes as indices 0 = x, 1 = y, 2 = z
+    pub fn axes(self) -> [usize; 3] {
+        use EulerRot::*;
+        match self {
+            XYZ => [0, 1, 2],
+            XZY => [0, 2, 1],
+            YXZ => [1, 0, 2],
+            YZX => [1, 2, 0],
+            ZXY => [2, 0, 1],
+            ZYX => [2, 1, 0],
+            XYX => [0, 1, 0],
+            XZX => [0, 2, 0],
+            YXY => [1, 0, 1],
+            YZY => [1, 2, 1],
+            ZXZ => [2, 0, 2],
+            ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// whether the first axis repeats as the last
+    pub fn is_proper_euler(self) -> bool {
+        let [i, _, k] = self.axes();
+        i == k
+    }
+
+    pub fn to_matrix3(self, a: f32, b: f32, c: f32) -> Matrix3x3 {
+        let [i, j, k] = self.axes();
+        axis_rotation(i, a) * axis_rotation(j, b) * axis_rotation(k, c)
+    }
+
+    pub fn from_matrix3(self, m: &Matrix3x3) -> (f32, f32, f32) {
+        let [i, j, _] = self.axes();
+        // the axis not among the first two, and whether i, j, k run cyclically
+        let k = 3 - i - j;
+        let sign = if (i + 1) % 3 == j { 1.0 } else { -1.0 };
+        let r = &m.data;
+
+        let (a, b, c, locked) = if self.is_proper_euler() {
+            let sb = r[i][j].hypot(r[i][k]);
+            let b = sb.atan2(r[i][i]);
+            let a = r[j][i].atan2(-sign * r[k][i]);
+            let c = r[i][j].atan2(sign * r[i][k]);
+            (a, b, c, sb < GIMBAL_EPSILON)
+        } else {
+            let cb = r[i][i].hypot(r[i][j]);
+            let b = (sign * r[i][k]).atan2(cb);
+            let a = (-sign * r[j][k]).atan2(r[k][k]);
+            let c = (-sign * r[i][j]).atan2(r[i][i]);
+            (a, b, c, cb < GIMBAL_EPSILON)
+        };
+        if locked {
+            // only the combined first and last rotation is known, fold it into a
+            ((sign * r[k][j]).atan2(r[j][j]), b, 0.0)
+        } else {
+            (a, b, c)
+        }
+    }
+
+    pub fn to_quat(self, a: f32, b: f32, c: f32) -> Vector4 {
+        let [i, j, k] = self.axes();
+        let axis = |n: usize| {
+            let mut v = Vector3::broadcast(0.0);
+            v[n] = 1.0;
+            v
+        };
+        quat_mul(
+            quat_mul(quat_from_axis_angle(axis(i), a), quat_from_axis_angle(axis(j), b)),
+            quat_from_axis_angle(axis(k), c),
+        )
+    }
+
+    pub fn from_quat(self, q: Vector4) -> (f32, f32, f32) {
+        self.from_matrix3(&quat_to_matrix3(q))
+    }
+
+    pub fn to_axis_angle(self, a: f32, b: f32, c: f32) -> (Vector3, f32) {
+        quat_to_axis_angle(self.to_quat(a, b, c))
+    }
+
+    pub fn from_axis_angle(self, axis: Vector3, angle: f32) -> (f32, f32, f32) {
+        self.from_matrix3(&Matrix3x3::from_axis_angle(axis, angle))
+    }
+}
@@ -0,0 +1,545 @@
+//! iterative linear solvers
+//!
+//! the solvers only need the product `A x`, supplied by a `LinearOperator`.
+//! dense `Matrix`, `CsrMatrix` and `CscMatrix` implement it, and `FnOperator`
+//! wraps a closure for matrix-free use. every solver stops once
+//! ‖b - A x‖ / ‖b‖ drops to `SolverOptions::tol` and reports the relative
+//! residual of each iteration.
+use std::fmt;
+use num::Float;
+use crate::vector::vectorn::VectorN;
+use super::matrixnxm::Matrix;
+use super::sparse::{CsrMatrix, CscMatrix};
+
+/// a square linear map y = A x
+pub trait LinearOperator<T> {
+    /// dimension of the square operator
+    fn dim(&self) -> usize;
+
+    /// write A x into y
+    fn apply(&self, x: &[T], y: &mut [T]);
+}
+
+/// approximate inverse z = M⁻¹ r used to speed up convergence
+pub trait Preconditioner<T> {
+    /// dimension M works on, None if it fits any dimension
+    fn dim(&self) -> Option<usize>;
+
+    fn apply(&self, r: &[T], z: &mut [T]);
+}
+
+/// matrix-free operator from a closure writing A x into y
+pub struct FnOperator<F> {
+    pub n: usize,
+    pub f: F,
+}
+
+impl<T, F: Fn(&[T], &mut [T])> LinearOperator<T> for FnOperator<F> {
+    fn dim(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        (self.f)(x, y)
+    }
+}
+
+impl<T: Float> LinearOperator<T> for Matrix<T> {
+    fn dim(&self) -> usize {
+        assert_eq!(self.n, self.m, "linear operator must be square");
+        self.n
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        for (i, yi) in y.iter_mut().enumerate() {
+            *yi = dot(self.row(i), x);
+        }
+    }
+}
+
+impl<T: Float> LinearOperator<T> for CsrMatrix<T> {
+    fn dim(&self) -> usize {
+        assert_eq!(self.n, self.m, "linear operator must be square");
+        self.n
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        self.mul_vec_into(x, y)
+    }
+}
+
+impl<T: Float> LinearOperator<T> for CscMatrix<T> {
+    fn dim(&self) -> usize {
+        assert_eq!(self.n, self.m, "linear operator must be square");
+        self.n
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        y.copy_from_slice(&self.mul_vec(x))
+    }
+}
+
+/// no preconditioning, M = I
+pub struct Identity;
+
+impl<T: Float> Preconditioner<T> for Identity {
+    fn dim(&self) -> Option<usize> {
+        None
+    }
+
+    fn apply(&self, r: &[T], z: &mut [T]) {
+        z.copy_from_slice(r)
+    }
+}
+
+/// jacobi preconditioner, M = diag(A)
+pub struct Jacobi<T> {
+    inv_diag: Vec<T>,
+}
+
+impl<T: Float> Jacobi<T> {
+    /// build from the diagonal of A, fails if an element is zero
+    pub fn new(diagonal: &[T]) -> Result<Self, SolverError> {
+        if diagonal.iter().any(|d| d.is_zero()) {
+            return Err(SolverError::ZeroDiagonal);
+        }
+        Ok(Self { inv_diag: diagonal.iter().map(|d| d.recip()).collect() })
+    }
+
+    pub fn from_csr(a: &CsrMatrix<T>) -> Result<Self, SolverError> {
+        Self::new(&a.diagonal())
+    }
+
+    pub fn from_dense(a: &Matrix<T>) -> Result<Self, SolverError> {
+        let diagonal: Vec<T> = (0..a.n.min(a.m)).map(|i| a[(i, i)]).collect();
+        Self::new(&diagonal)
+    }
+}
+
+impl<T: Float> Preconditioner<T> for Jacobi<T> {
+    fn dim(&self) -> Option<usize> {
+        Some(self.inv_diag.len())
+    }
+
+    fn apply(&self, r: &[T], z: &mut [T]) {
+        for ((zi, ri), d) in z.iter_mut().zip(r).zip(&self.inv_diag) {
+            *zi = *ri * *d;
+        }
+    }
+}
+
+/// zero fill-in incomplete cholesky preconditioner, M = L Lᵀ where L keeps
+/// the sparsity of the lower triangle of a symmetric positive definite A
+pub struct IncompleteCholesky<T> {
+    l: CsrMatrix<T>,
+}
+
+impl<T: Float> IncompleteCholesky<T> {
+    pub fn new(a: &CsrMatrix<T>) -> Result<Self, SolverError> {
+        if a.n != a.m {
+            return Err(SolverError::DimensionMismatch);
+        }
+
+        let mut row_ptr = Vec::with_capacity(a.n + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+        for i in 0..a.n {
+            let (cols, vals) = a.row(i);
+            for (j, v) in cols.iter().zip(vals) {
+                if *j <= i {
+                    col_idx.push(*j);
+                    values.push(*v);
+                }
+            }
+            // the factor needs a diagonal element to pivot on
+            if col_idx.last() != Some(&i) {
+                return Err(SolverError::NotPositiveDefinite);
+            }
+            row_ptr.push(col_idx.len());
+        }
+
+        for i in 0..a.n {
+            let (start, end) = (row_ptr[i], row_ptr[i + 1]);
+            for p in start..end {
+                let k = col_idx[p];
+                // sum of L[i][j] L[k][j] over the shared columns j < k
+                let mut sum = T::zero();
+                let (mut q, mut r) = (start, row_ptr[k]);
+                while q < p && r < row_ptr[k + 1] - 1 {
+                    match col_idx[q].cmp(&col_idx[r]) {
+                        std::cmp::Ordering::Less => q += 1,
+                        std::cmp::Ordering::Greater => r += 1,
+                        std::cmp::Ordering::Equal => {
+                            sum = sum + values[q] * values[r];
+                            q += 1;
+                            r += 1;
+                        }
+                    }
+                }
+                if k < i {
+                    values[p] = (values[p] - sum) / values[row_ptr[k + 1] - 1];
+                } else {
+                    let d = values[p] - sum;
+                    if d <= T::zero() || !d.is_finite() {
+                        return Err(SolverError::NotPositiveDefinite);
+                    }
+                    values[p] = d.sqrt();
+                }
+            }
+        }
+
+        Ok(Self { l: CsrMatrix { n: a.n, m: a.n, row_ptr, col_idx, values } })
+    }
+}
+
+impl<T: Float> Preconditioner<T> for IncompleteCholesky<T> {
+    fn dim(&self) -> Option<usize> {
+        Some(self.l.n)
+    }
+
+    fn apply(&self, r: &[T], z: &mut [T]) {
+        let l = &self.l;
+        // L y = r, the diagonal is the last element of every row
+        for i in 0..l.n {
+            let (cols, vals) = l.row(i);
+            let last = cols.len() - 1;
+            let mut s = r[i];
+            for (j, v) in cols[..last].iter().zip(vals) {
+                s = s - *v * z[*j];
+            }
+            z[i] = s / vals[last];
+        }
+        // Lᵀ z = y, walking the rows of L backwards as columns of Lᵀ
+        for i in (0..l.n).rev() {
+            let (cols, vals) = l.row(i);
+            let last = cols.len() - 1;
+            z[i] = z[i] / vals[last];
+            for (j, v) in cols[..last].iter().zip(vals) {
+                z[*j] = z[*j] - *v * z[i];
+            }
+        }
+    }
+}
+
+/// stopping criteria of the iterative solvers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverOptions<T> {
+    /// stop once ‖b - A x‖ / ‖b‖ <= tol
+    pub tol: T,
+    /// give up after this many iterations
+    pub max_iter: usize,
+    /// krylov subspace size before gmres restarts
+    pub restart: usize,
+}
+
+impl<T: Float> Default for SolverOptions<T> {
+    fn default() -> Self {
+        Self {
+            tol: T::epsilon().sqrt(),
+            max_iter: 1000,
+            restart: 30,
+        }
+    }
+}
+
+/// solution and convergence history of an iterative solve
+#[derive(Debug, Clone)]
+pub struct SolveReport<T> {
+    pub x: VectorN<T>,
+    /// iterations done, each one costs one operator application (two for bicgstab)
+    pub iterations: usize,
+    /// relative residual before the first iteration and after every iteration
+    pub residual_history: Vec<T>,
+    pub converged: bool,
+}
+
+impl<T: Float> SolveReport<T> {
+    /// last relative residual
+    pub fn residual(&self) -> T {
+        *self.residual_history.last().unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolverError {
+    /// b, x0 or a preconditioner does not match the operator dimension
+    DimensionMismatch,
+    /// the method broke down with a zero or non-finite denominator
+    Breakdown,
+    /// cg met a direction with pᵀ A p <= 0, or incomplete cholesky a non-positive pivot
+    NotPositiveDefinite,
+    /// jacobi preconditioner on a matrix with a zero diagonal element
+    ZeroDiagonal,
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolverError::DimensionMismatch => write!(f, "dimension mismatch"),
+            SolverError::Breakdown => write!(f, "iterative solver broke down"),
+            SolverError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            SolverError::ZeroDiagonal => write!(f, "matrix has a zero diagonal element"),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+#[inline]
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::zero(), |acc, (x, y)| acc + *x * *y)
+}
+
+#[inline]
+fn norm<T: Float>(a: &[T]) -> T {
+    dot(a, a).sqrt()
+}
+
+/// y += alpha x
+#[inline]
+fn axpy<T: Float>(alpha: T, x: &[T], y: &mut [T]) {
+    for (yi, xi) in y.iter_mut().zip(x) {
+        *yi = *yi + alpha * *xi;
+    }
+}
+
+/// check dimensions and set up x, r = b - A x and ‖b‖
+fn start<T, A, M>(a: &A, b: &VectorN<T>, x0: Option<&VectorN<T>>, m: &M) -> Result<(Vec<T>, Vec<T>, T), SolverError>
+where T: Float, A: LinearOperator<T>, M: Preconditioner<T> {
+    let n = a.dim();
+    if b.data.len() != n || x0.is_some_and(|x0| x0.data.len() != n) || m.dim().is_some_and(|d| d != n) {
+        return Err(SolverError::DimensionMismatch);
+    }
+    let x = x0.map_or_else(|| vec![T::zero(); n], |x0| x0.data.clone());
+    let mut r = vec![T::zero(); n];
+    a.apply(&x, &mut r);
+    for (ri, bi) in r.iter_mut().zip(&b.data) {
+        *ri = *bi - *ri;
+    }
+    let b_norm = norm(&b.data);
+    // a zero right hand side measures the absolute residual instead
+    let b_norm = if b_norm.is_zero() { T::one() } else { b_norm };
+    Ok((x, r, b_norm))
+}
+
+fn report<T>(x: Vec<T>, iterations: usize, residual_history: Vec<T>, converged: bool) -> SolveReport<T> {
+    SolveReport { x: VectorN { data: x }, iterations, residual_history, converged }
+}
+
+/// preconditioned conjugate gradient for symmetric positive definite A
+pub fn conjugate_gradient<T, A, M>(a: &A, b: &VectorN<T>, x0: Option<&VectorN<T>>, m: &M, options: &SolverOptions<T>) -> Result<SolveReport<T>, SolverError>
+where T: Float, A: LinearOperator<T>, M: Preconditioner<T> {
+    let (mut x, mut r, b_norm) = start(a, b, x0, m)?;
+    let n = x.len();
+    let mut history = vec![norm(&r) / b_norm];
+    if history[0] <= options.tol {
+        return Ok(report(x, 0, history, true));
+    }
+
+    let mut z = vec![T::zero(); n];
+    m.apply(&r, &mut z);
+    let mut p = z.clone();
+    let mut ap = vec![T::zero(); n];
+    let mut rz = dot(&r, &z);
+
+    for iter in 1..=options.max_iter {
+        a.apply(&p, &mut ap);
+        let pap = dot(&p, &ap);
+        if pap <= T::zero() || pap.is_nan() {
+            return Err(SolverError::NotPositiveDefinite);
+        }
+        let alpha = rz / pap;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &ap, &mut r);
+
+        let res = norm(&r) / b_norm;
+        history.push(res);
+        if res <= options.tol {
+            return Ok(report(x, iter, history, true));
+        }
+
+        m.apply(&r, &mut z);
+        let rz_next = dot(&r, &z);
+        if !rz_next.is_finite() {
+            return Err(SolverError::Breakdown);
+        }
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (pi, zi) in p.iter_mut().zip(&z) {
+            *pi = *zi + beta * *pi;
+        }
+    }
+    Ok(report(x, options.max_iter, history, false))
+}
+
+/// right-preconditioned biconjugate gradient stabilized for general A
+pub fn bicgstab<T, A, M>(a: &A, b: &VectorN<T>, x0: Option<&VectorN<T>>, m: &M, options: &SolverOptions<T>) -> Result<SolveReport<T>, SolverError>
+where T: Float, A: LinearOperator<T>, M: Preconditioner<T> {
+    let (mut x, mut r, b_norm) = start(a, b, x0, m)?;
+    let n = x.len();
+    let mut history = vec![norm(&r) / b_norm];
+    if history[0] <= options.tol {
+        return Ok(report(x, 0, history, true));
+    }
+
+    let r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut v = vec![T::zero(); n];
+    let mut p = vec![T::zero(); n];
+    let mut p_hat = vec![T::zero(); n];
+    let mut s_hat = vec![T::zero(); n];
+    let mut t = vec![T::zero(); n];
+
+    for iter in 1..=options.max_iter {
+        let rho_next = dot(&r_hat, &r);
+        if rho_next.is_zero() || !rho_next.is_finite() {
+            return Err(SolverError::Breakdown);
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for ((pi, ri), vi) in p.iter_mut().zip(&r).zip(&v) {
+            *pi = *ri + beta * (*pi - omega * *vi);
+        }
+
+        m.apply(&p, &mut p_hat);
+        a.apply(&p_hat, &mut v);
+        let rv = dot(&r_hat, &v);
+        if rv.is_zero() {
+            return Err(SolverError::Breakdown);
+        }
+        alpha = rho / rv;
+
+        // r now holds s = r - alpha v
+        axpy(-alpha, &v, &mut r);
+        axpy(alpha, &p_hat, &mut x);
+        let res = norm(&r) / b_norm;
+        if res <= options.tol {
+            history.push(res);
+            return Ok(report(x, iter, history, true));
+        }
+
+        m.apply(&r, &mut s_hat);
+        a.apply(&s_hat, &mut t);
+        let tt = dot(&t, &t);
+        if tt.is_zero() {
+            return Err(SolverError::Breakdown);
+        }
+        omega = dot(&t, &r) / tt;
+        axpy(omega, &s_hat, &mut x);
+        axpy(-omega, &t, &mut r);
+
+        let res = norm(&r) / b_norm;
+        history.push(res);
+        if res <= options.tol {
+            return Ok(report(x, iter, history, true));
+        }
+        if omega.is_zero() || !omega.is_finite() {
+            return Err(SolverError::Breakdown);
+        }
+    }
+    Ok(report(x, options.max_iter, history, false))
+}
+
+/// right-preconditioned restarted gmres for general A, restarting every `options.restart` iterations
+pub fn gmres<T, A, M>(a: &A, b: &VectorN<T>, x0: Option<&VectorN<T>>, m: &M, options: &SolverOptions<T>) -> Result<SolveReport<T>, SolverError>
+where T: Float, A: LinearOperator<T>, M: Preconditioner<T> {
+    let (mut x, mut r, b_norm) = start(a, b, x0, m)?;
+    let n = x.len();
+    let restart = options.restart.max(1).min(n.max(1));
+    let mut history = vec![norm(&r) / b_norm];
+    if history[0] <= options.tol {
+        return Ok(report(x, 0, history, true));
+    }
+
+    let mut iterations = 0;
+    let mut z = vec![T::zero(); n];
+    let mut w = vec![T::zero(); n];
+    loop {
+        let beta = norm(&r);
+        // krylov basis, hessenberg matrix stored by column, givens rotations
+        let mut basis: Vec<Vec<T>> = vec![r.iter().map(|ri| *ri / beta).collect()];
+        let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![T::zero(); restart + 1];
+        g[0] = beta;
+
+        for j in 0..restart {
+            m.apply(&basis[j], &mut z);
+            a.apply(&z, &mut w);
+
+            let mut col = vec![T::zero(); j + 2];
+            for (i, v) in basis.iter().enumerate() {
+                col[i] = dot(&w, v);
+                axpy(-col[i], v, &mut w);
+            }
+            col[j + 1] = norm(&w);
+            let lucky = col[j + 1] <= T::epsilon() * beta;
+            if !lucky {
+                basis.push(w.iter().map(|wi| *wi / col[j + 1]).collect());
+            }
+
+            for (i, (c, s)) in rotations.iter().enumerate() {
+                let (a0, a1) = (col[i], col[i + 1]);
+                col[i] = *c * a0 + *s * a1;
+                col[i + 1] = -*s * a0 + *c * a1;
+            }
+            let d = col[j].hypot(col[j + 1]);
+            if d.is_zero() {
+                return Err(SolverError::Breakdown);
+            }
+            let (c, s) = (col[j] / d, col[j + 1] / d);
+            col[j] = d;
+            col[j + 1] = T::zero();
+            g[j + 1] = -s * g[j];
+            g[j] = c * g[j];
+            rotations.push((c, s));
+            h.push(col);
+
+            iterations += 1;
+            let res = g[j + 1].abs() / b_norm;
+            history.push(res);
+            if res <= options.tol || lucky {
+                break;
+            }
+            if iterations >= options.max_iter {
+                break;
+            }
+        }
+
+        // back substitution on the triangular hessenberg, x += M⁻¹ V y
+        let k = h.len();
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let mut s = g[i];
+            for (l, yl) in y.iter().enumerate().skip(i + 1) {
+                s = s - h[l][i] * *yl;
+            }
+            y[i] = s / h[i][i];
+        }
+        let mut u = vec![T::zero(); n];
+        for (yi, v) in y.iter().zip(&basis) {
+            axpy(*yi, v, &mut u);
+        }
+        m.apply(&u, &mut z);
+        axpy(T::one(), &z, &mut x);
+
+        // restart from the true residual
+        a.apply(&x, &mut r);
+        for (ri, bi) in r.iter_mut().zip(&b.data) {
+            *ri = *bi - *ri;
+        }
+        // only the true residual decides, the givens estimate drifts from it
+        let res = norm(&r) / b_norm;
+        *history.last_mut().unwrap() = res;
+        if res <= options.tol {
+            return Ok(report(x, iterations, history, true));
+        }
+        if iterations >= options.max_iter {
+            return Ok(report(x, iterations, history, false));
+        }
+        if !res.is_finite() {
+            return Err(SolverError::Breakdown);
+        }
+    }
+}
@@ -0,0 +1,467 @@
+//! sparse matrix formats
+//!
+//! `CooMatrix` is for assembly: push (row, column, value) triplets in any
+//! order, duplicates are summed when converting. `CsrMatrix` and `CscMatrix`
+//! are the compressed row and column formats used for computation. indices
+//! inside each compressed row (column) are sorted and unique.
+use std::fmt;
+use std::ops::Mul;
+use num::{One, Zero};
+use super::matrixnxm::Matrix;
+use crate::display::write_sparse;
+
+/// coordinate (triplet) sparse matrix with n rows and m columns
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    pub n: usize,
+    pub m: usize,
+    pub rows: Vec<usize>,
+    pub cols: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+/// compressed sparse row matrix, row i holds `col_idx[row_ptr[i]..row_ptr[i + 1]]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    pub n: usize,
+    pub m: usize,
+    pub row_ptr: Vec<usize>,
+    pub col_idx: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+/// compressed sparse column matrix, column j holds `row_idx[col_ptr[j]..col_ptr[j + 1]]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T> {
+    pub n: usize,
+    pub m: usize,
+    pub col_ptr: Vec<usize>,
+    pub row_idx: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+/// compress (outer, inner, value) triplets along the outer index,
+/// sorting each outer slice by inner index and summing duplicates
+fn compress<T: Copy + Zero>(outer_len: usize, outer: &[usize], inner: &[usize], values: &[T]) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    let mut ptr = vec![0usize; outer_len + 1];
+    for o in outer {
+        ptr[o + 1] += 1;
+    }
+    for i in 0..outer_len {
+        ptr[i + 1] += ptr[i];
+    }
+
+    let mut next = ptr.clone();
+    let mut slots = vec![(0usize, T::zero()); values.len()];
+    for ((o, i), v) in outer.iter().zip(inner).zip(values) {
+        slots[next[*o]] = (*i, *v);
+        next[*o] += 1;
+    }
+
+    let mut new_ptr = Vec::with_capacity(outer_len + 1);
+    let mut idx = Vec::with_capacity(values.len());
+    let mut vals = Vec::with_capacity(values.len());
+    new_ptr.push(0);
+    for o in 0..outer_len {
+        let slice = &mut slots[ptr[o]..ptr[o + 1]];
+        slice.sort_by_key(|(i, _)| *i);
+        let start = idx.len();
+        for (i, v) in slice.iter() {
+            if idx.len() > start && idx[idx.len() - 1] == *i {
+                let last = vals.len() - 1;
+                vals[last] = vals[last] + *v;
+            } else {
+                idx.push(*i);
+                vals.push(*v);
+            }
+        }
+        new_ptr.push(idx.len());
+    }
+    (new_ptr, idx, vals)
+}
+
+/// swap the compressed axis, csr arrays become csc arrays and back
+fn recompress<T: Copy + Zero>(outer_len: usize, inner_len: usize, ptr: &[usize], idx: &[usize], values: &[T]) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    let mut new_ptr = vec![0usize; inner_len + 1];
+    for i in idx {
+        new_ptr[i + 1] += 1;
+    }
+    for i in 0..inner_len {
+        new_ptr[i + 1] += new_ptr[i];
+    }
+
+    let mut next = new_ptr.clone();
+    let mut new_idx = vec![0usize; idx.len()];
+    let mut new_vals = vec![T::zero(); values.len()];
+    // walking the outer axis in order keeps every new slice sorted
+    for o in 0..outer_len {
+        for k in ptr[o]..ptr[o + 1] {
+            let slot = next[idx[k]];
+            new_idx[slot] = o;
+            new_vals[slot] = values[k];
+            next[idx[k]] += 1;
+        }
+    }
+    (new_ptr, new_idx, new_vals)
+}
+
+impl<T: Copy + Zero> CooMatrix<T> {
+    /// get an empty n x m matrix
+    pub fn new(n: usize, m: usize) -> Self {
+        Self { n, m, rows: Vec::new(), cols: Vec::new(), values: Vec::new() }
+    }
+
+    /// get an empty n x m matrix with room for nnz entries
+    pub fn with_capacity(n: usize, m: usize, nnz: usize) -> Self {
+        Self { n, m, rows: Vec::with_capacity(nnz), cols: Vec::with_capacity(nnz), values: Vec::with_capacity(nnz) }
+    }
+
+    /// add value at (i, j), entries pushed to the same place are summed
+    pub fn push(&mut self, i: usize, j: usize, value: T) {
+        assert!(i < self.n && j < self.m, "Invalid index into CooMatrix");
+        self.rows.push(i);
+        self.cols.push(j);
+        self.values.push(value);
+    }
+
+    /// number of stored entries, duplicates counted separately
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// iterate over the stored (row, column, value) entries in push order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        self.rows.iter().zip(self.cols.iter()).zip(self.values.iter()).map(|((i, j), v)| (*i, *j, *v))
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (row_ptr, col_idx, values) = compress(self.n, &self.rows, &self.cols, &self.values);
+        CsrMatrix { n: self.n, m: self.m, row_ptr, col_idx, values }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (col_ptr, row_idx, values) = compress(self.m, &self.cols, &self.rows, &self.values);
+        CscMatrix { n: self.n, m: self.m, col_ptr, row_idx, values }
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut ret = Matrix::zeros(self.n, self.m);
+        for (i, j, v) in self.iter() {
+            ret[(i, j)] = ret[(i, j)] + v;
+        }
+        ret
+    }
+
+    /// collect the nonzero elements of a dense matrix
+    pub fn from_dense(dense: &Matrix<T>) -> Self {
+        let mut ret = Self::new(dense.n, dense.m);
+        for i in 0..dense.n {
+            for (j, v) in dense.row(i).iter().enumerate() {
+                if !v.is_zero() {
+                    ret.push(i, j, *v);
+                }
+            }
+        }
+        ret
+    }
+}
+
+impl<T: Copy + Zero> CsrMatrix<T> {
+    /// get an n x m matrix with no stored entries
+    pub fn zeros(n: usize, m: usize) -> Self {
+        Self { n, m, row_ptr: vec![0; n + 1], col_idx: Vec::new(), values: Vec::new() }
+    }
+
+    /// number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// column indices and values stored in row i
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.row_ptr[i]..self.row_ptr[i + 1];
+        (&self.col_idx[range.clone()], &self.values[range])
+    }
+
+    /// element at (i, j), zero if it is not stored
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let (cols, values) = self.row(i);
+        match cols.binary_search(&j) {
+            Ok(k) => values[k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// iterate over the stored (row, column, value) entries row by row
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.n).flat_map(move |i| {
+            (self.row_ptr[i]..self.row_ptr[i + 1]).map(move |k| (i, self.col_idx[k], self.values[k]))
+        })
+    }
+
+    pub fn transpose(&self) -> Self {
+        let (row_ptr, col_idx, values) = recompress(self.n, self.m, &self.row_ptr, &self.col_idx, &self.values);
+        Self { n: self.m, m: self.n, row_ptr, col_idx, values }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (col_ptr, row_idx, values) = recompress(self.n, self.m, &self.row_ptr, &self.col_idx, &self.values);
+        CscMatrix { n: self.n, m: self.m, col_ptr, row_idx, values }
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        let mut ret = CooMatrix::with_capacity(self.n, self.m, self.nnz());
+        for (i, j, v) in self.iter() {
+            ret.push(i, j, v);
+        }
+        ret
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut ret = Matrix::zeros(self.n, self.m);
+        for (i, j, v) in self.iter() {
+            ret[(i, j)] = v;
+        }
+        ret
+    }
+
+    /// collect the nonzero elements of a dense matrix
+    pub fn from_dense(dense: &Matrix<T>) -> Self {
+        CooMatrix::from_dense(dense).to_csr()
+    }
+
+    /// the diagonal, zero where nothing is stored
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.n.min(self.m)).map(|i| self.get(i, i)).collect()
+    }
+}
+
+impl<T: Copy + Zero + One> CsrMatrix<T> {
+    /// get the n x n identity matrix
+    pub fn identity(n: usize) -> Self {
+        Self { n, m: n, row_ptr: (0..=n).collect(), col_idx: (0..n).collect(), values: vec![T::one(); n] }
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> CsrMatrix<T> {
+    /// sparse matrix times dense column vector, panics if x is not m long
+    pub fn mul_vec(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), self.m, "matrix vector dimension mismatch");
+        let mut y = vec![T::zero(); self.n];
+        self.mul_vec_into(x, &mut y);
+        y
+    }
+
+    /// sparse matrix times dense column vector written into y
+    pub fn mul_vec_into(&self, x: &[T], y: &mut [T]) {
+        assert_eq!(x.len(), self.m, "matrix vector dimension mismatch");
+        assert_eq!(y.len(), self.n, "matrix vector dimension mismatch");
+        for (i, yi) in y.iter_mut().enumerate() {
+            let mut acc = T::zero();
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                acc = acc + self.values[k] * x[self.col_idx[k]];
+            }
+            *yi = acc;
+        }
+    }
+
+    /// sparse matrix product, gustavson's row by row algorithm
+    pub fn mul_sparse(&self, rhs: &Self) -> Self {
+        assert_eq!(self.m, rhs.n, "matrix product dimension mismatch");
+        let mut row_ptr = Vec::with_capacity(self.n + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        let mut acc = vec![T::zero(); rhs.m];
+        let mut used = vec![false; rhs.m];
+        let mut cols = Vec::new();
+
+        row_ptr.push(0);
+        for i in 0..self.n {
+            for ka in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let (k, a) = (self.col_idx[ka], self.values[ka]);
+                for kb in rhs.row_ptr[k]..rhs.row_ptr[k + 1] {
+                    let j = rhs.col_idx[kb];
+                    if !used[j] {
+                        used[j] = true;
+                        cols.push(j);
+                    }
+                    acc[j] = acc[j] + a * rhs.values[kb];
+                }
+            }
+            cols.sort_unstable();
+            for j in cols.drain(..) {
+                col_idx.push(j);
+                values.push(acc[j]);
+                acc[j] = T::zero();
+                used[j] = false;
+            }
+            row_ptr.push(col_idx.len());
+        }
+        Self { n: self.n, m: rhs.m, row_ptr, col_idx, values }
+    }
+}
+
+impl<T: Copy + Zero> CscMatrix<T> {
+    /// number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// row indices and values stored in column j
+    pub fn col(&self, j: usize) -> (&[usize], &[T]) {
+        let range = self.col_ptr[j]..self.col_ptr[j + 1];
+        (&self.row_idx[range.clone()], &self.values[range])
+    }
+
+    /// element at (i, j), zero if it is not stored
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let (rows, values) = self.col(j);
+        match rows.binary_search(&i) {
+            Ok(k) => values[k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// iterate over the stored (row, column, value) entries column by column
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.m).flat_map(move |j| {
+            (self.col_ptr[j]..self.col_ptr[j + 1]).map(move |k| (self.row_idx[k], j, self.values[k]))
+        })
+    }
+
+    pub fn transpose(&self) -> Self {
+        let (col_ptr, row_idx, values) = recompress(self.m, self.n, &self.col_ptr, &self.row_idx, &self.values);
+        Self { n: self.m, m: self.n, col_ptr, row_idx, values }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (row_ptr, col_idx, values) = recompress(self.m, self.n, &self.col_ptr, &self.row_idx, &self.values);
+        CsrMatrix { n: self.n, m: self.m, row_ptr, col_idx, values }
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        let mut ret = CooMatrix::with_capacity(self.n, self.m, self.nnz());
+        for (i, j, v) in self.iter() {
+            ret.push(i, j, v);
+        }
+        ret
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut ret = Matrix::zeros(self.n, self.m);
+        for (i, j, v) in self.iter() {
+            ret[(i, j)] = v;
+        }
+        ret
+    }
+
+    /// collect the nonzero elements of a dense matrix
+    pub fn from_dense(dense: &Matrix<T>) -> Self {
+        CooMatrix::from_dense(dense).to_csc()
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> CscMatrix<T> {
+    /// sparse matrix times dense column vector, panics if x is not m long
+    pub fn mul_vec(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(x.len(), self.m, "matrix vector dimension mismatch");
+        let mut y = vec![T::zero(); self.n];
+        for (j, xj) in x.iter().enumerate() {
+            for k in self.col_ptr[j]..self.col_ptr[j + 1] {
+                y[self.row_idx[k]] = y[self.row_idx[k]] + self.values[k] * *xj;
+            }
+        }
+        y
+    }
+
+    /// sparse matrix product, computed as (rhsᵀ selfᵀ)ᵀ on the csr arrays
+    pub fn mul_sparse(&self, rhs: &Self) -> Self {
+        assert_eq!(self.m, rhs.n, "matrix product dimension mismatch");
+        // the csc arrays of a matrix are the csr arrays of its transpose
+        let at = CsrMatrix { n: self.m, m: self.n, row_ptr: self.col_ptr.clone(), col_idx: self.row_idx.clone(), values: self.values.clone() };
+        let bt = CsrMatrix { n: rhs.m, m: rhs.n, row_ptr: rhs.col_ptr.clone(), col_idx: rhs.row_idx.clone(), values: rhs.values.clone() };
+        let ct = bt.mul_sparse(&at);
+        Self { n: self.n, m: rhs.m, col_ptr: ct.row_ptr, row_idx: ct.col_idx, values: ct.values }
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> Mul<&[T]> for &CsrMatrix<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, rhs: &[T]) -> Vec<T> {
+        self.mul_vec(rhs)
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> Mul for &CsrMatrix<T> {
+    type Output = CsrMatrix<T>;
+
+    fn mul(self, rhs: Self) -> CsrMatrix<T> {
+        self.mul_sparse(rhs)
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> Mul<&[T]> for &CscMatrix<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, rhs: &[T]) -> Vec<T> {
+        self.mul_vec(rhs)
+    }
+}
+
+impl<T: Copy + Zero + Mul<Output = T>> Mul for &CscMatrix<T> {
+    type Output = CscMatrix<T>;
+
+    fn mul(self, rhs: Self) -> CscMatrix<T> {
+        self.mul_sparse(rhs)
+    }
+}
+
+impl<T: Copy + Zero> From<&CooMatrix<T>> for CsrMatrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_csr()
+    }
+}
+
+impl<T: Copy + Zero> From<&CooMatrix<T>> for CscMatrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_csc()
+    }
+}
+
+impl<T: Copy + Zero> From<&CsrMatrix<T>> for CscMatrix<T> {
+    fn from(csr: &CsrMatrix<T>) -> Self {
+        csr.to_csc()
+    }
+}
+
+impl<T: Copy + Zero> From<&CscMatrix<T>> for CsrMatrix<T> {
+    fn from(csc: &CscMatrix<T>) -> Self {
+        csc.to_csr()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CooMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries = self.rows.iter().zip(self.cols.iter()).zip(self.values.iter()).map(|((i, j), v)| (*i, *j, v));
+        write_sparse(f, "coo", (self.n, self.m), entries)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CsrMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries = (0..self.n).flat_map(|i| {
+            (self.row_ptr[i]..self.row_ptr[i + 1]).map(move |k| (i, self.col_idx[k], &self.values[k]))
+        });
+        write_sparse(f, "csr", (self.n, self.m), entries)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CscMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries = (0..self.m).flat_map(|j| {
+            (self.col_ptr[j]..self.col_ptr[j + 1]).map(move |k| (self.row_idx[k], j, &self.values[k]))
+        });
+        write_sparse(f, "csc", (self.n, self.m), entries)
+    }
+}
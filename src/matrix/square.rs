@@ -0,0 +1,127 @@
+//! element access shared by the square matrix types
+//!
+//! lets one factorization routine work on `Matrix2x2`, `Matrix3x3`,
+//! `Matrix4x4` and the runtime sized `Matrix`.
+use num::Float;
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::vector::vectorn::VectorN;
+use super::{Matrix2x2, Matrix3x3, Matrix4x4};
+use super::matrixnxm::Matrix;
+
+pub trait SquareMatrix: Clone {
+    type Scalar: Float;
+    /// the column vector type the matrix multiplies
+    type Vector: Clone;
+
+    fn dim(&self) -> usize;
+    /// rows and columns, only a runtime sized matrix can be non-square
+    fn shape(&self) -> (usize, usize) {
+        (self.dim(), self.dim())
+    }
+    fn get(&self, i: usize, j: usize) -> Self::Scalar;
+    fn set(&mut self, i: usize, j: usize, v: Self::Scalar);
+    /// a zero matrix of the same dimension
+    fn zeros_like(&self) -> Self;
+    /// a zero vector of the same dimension
+    fn zero_vector(&self) -> Self::Vector;
+    fn vector_get(v: &Self::Vector, i: usize) -> Self::Scalar;
+    fn vector_set(v: &mut Self::Vector, i: usize, x: Self::Scalar);
+    /// dimension of a vector, checked against the matrix before solving
+    fn vector_dim(v: &Self::Vector) -> usize;
+}
+
+macro_rules! square_matrix {
+    ($name:ident, $vector:ident, $n:expr) => (
+        impl SquareMatrix for $name {
+            type Scalar = f32;
+            type Vector = $vector;
+
+            #[inline]
+            fn dim(&self) -> usize {
+                $n
+            }
+
+            #[inline]
+            fn get(&self, i: usize, j: usize) -> f32 {
+                self.data[i][j]
+            }
+
+            #[inline]
+            fn set(&mut self, i: usize, j: usize, v: f32) {
+                self.data[i][j] = v;
+            }
+
+            fn zeros_like(&self) -> Self {
+                $name { data: [[0.0;$n];$n] }
+            }
+
+            fn zero_vector(&self) -> $vector {
+                $vector::default()
+            }
+
+            #[inline]
+            fn vector_get(v: &$vector, i: usize) -> f32 {
+                v[i]
+            }
+
+            #[inline]
+            fn vector_set(v: &mut $vector, i: usize, x: f32) {
+                v[i] = x;
+            }
+
+            fn vector_dim(_: &$vector) -> usize {
+                $n
+            }
+        }
+    );
+}
+
+square_matrix!(Matrix2x2, Vector2, 2);
+square_matrix!(Matrix3x3, Vector3, 3);
+square_matrix!(Matrix4x4, Vector4, 4);
+
+impl<T: Float> SquareMatrix for Matrix<T> {
+    type Scalar = T;
+    type Vector = VectorN<T>;
+
+    fn dim(&self) -> usize {
+        assert_eq!(self.n, self.m, "matrix is not square");
+        self.n
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.m)
+    }
+
+    #[inline]
+    fn get(&self, i: usize, j: usize) -> T {
+        self[(i, j)]
+    }
+
+    #[inline]
+    fn set(&mut self, i: usize, j: usize, v: T) {
+        self[(i, j)] = v;
+    }
+
+    fn zeros_like(&self) -> Self {
+        Matrix::zeros(self.n, self.m)
+    }
+
+    fn zero_vector(&self) -> VectorN<T> {
+        VectorN { data: vec![T::zero(); self.n] }
+    }
+
+    #[inline]
+    fn vector_get(v: &VectorN<T>, i: usize) -> T {
+        v.data[i]
+    }
+
+    #[inline]
+    fn vector_set(v: &mut VectorN<T>, i: usize, x: T) {
+        v.data[i] = x;
+    }
+
+    fn vector_dim(v: &VectorN<T>) -> usize {
+        v.data.len()
+    }
+}
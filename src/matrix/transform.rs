@@ -16,10 +16,12 @@ pub fn scale3(x:f32, y: f32, z:f32) -> Mat3f {
     ,[0.0, 0.0,  z]]
 }
 
+/// proper euler angles in z-x-z order, `EulerRot::ZXZ` with angles (x, y, z):
+/// rotate by x about z, then by y about the new x, then by z about the new z
 pub fn rotate3(x: f32, y: f32, z:f32) -> Mat3f {
-    [[z.cos()*x.cos()-y.cos()*x.sin()*z.sin(), -z.sin()*y.sin()*x.sin() - x.cos()*z.sin(), x.sin()*y.sin() ]
-    ,[z.cos()*x.sin()+x.cos()*y.cos()*z.sin(), x.cos()*y.cos()*z.cos() - x.sin()*z.cos() , -x.cos()*y.sin()]
-    ,[         y.sin()*z.cos()               ,                z.sin()*y.cos()            ,     y.cos()     ]]
+    [[x.cos()*z.cos()-y.cos()*x.sin()*z.sin(), -x.sin()*y.cos()*z.cos() - x.cos()*z.sin(), x.sin()*y.sin() ]
+    ,[z.cos()*x.sin()+x.cos()*y.cos()*z.sin(), x.cos()*y.cos()*z.cos() - x.sin()*z.sin() , -x.cos()*y.sin()]
+    ,[         y.sin()*z.sin()               ,                y.sin()*z.cos()            ,     y.cos()     ]]
 }
 
 pub fn translate1(x: f32) -> Mat2f {
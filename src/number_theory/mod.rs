@@ -0,0 +1,100 @@
+//! elementary number theory
+//!
+//! gcd and friends are generic over `num::Integer`, the modular routines
+//! work on u64 with u128 intermediates so no product can overflow.
+use num::{Integer, Signed};
+
+pub mod primes;
+pub mod zp;
+
+pub use primes::{factorize, is_prime, sieve};
+pub use zp::Zp;
+
+/// greatest common divisor, never negative
+pub fn gcd<T: Integer + Clone>(a: T, b: T) -> T {
+    a.gcd(&b)
+}
+
+/// least common multiple, never negative
+pub fn lcm<T: Integer + Clone>(a: T, b: T) -> T {
+    a.lcm(&b)
+}
+
+/// (g, x, y) with a·x + b·y = g = gcd(a, b) and g ≥ 0
+pub fn extended_gcd<T: Integer + Signed + Clone>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::one(), T::zero());
+    let (mut y0, mut y1) = (T::zero(), T::one());
+    while !r1.is_zero() {
+        let q = r0.clone() / r1.clone();
+        let r = r0 - q.clone() * r1.clone();
+        r0 = std::mem::replace(&mut r1, r);
+        let x = x0 - q.clone() * x1.clone();
+        x0 = std::mem::replace(&mut x1, x);
+        let y = y0 - q * y1.clone();
+        y0 = std::mem::replace(&mut y1, y);
+    }
+    if r0.is_negative() {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// a·b mod m
+#[inline]
+pub fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// baseᵉ mod m by repeated squaring
+pub fn mod_pow(base: u64, exp: u64, m: u64) -> u64 {
+    assert!(m > 0, "modulus must be positive");
+    let mut result = 1 % m;
+    let mut b = base % m;
+    let mut e = exp;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = mod_mul(result, b, m);
+        }
+        b = mod_mul(b, b, m);
+        e >>= 1;
+    }
+    result
+}
+
+/// x with a·x ≡ 1 (mod m), none if a and m share a factor
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    assert!(m > 0, "modulus must be positive");
+    let (g, x, _) = extended_gcd((a % m) as i128, m as i128);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m as i128) as u64)
+}
+
+/// chinese remainder theorem: x ≡ rᵢ (mod mᵢ) for every (rᵢ, mᵢ)
+///
+/// returns (x, lcm of the moduli) with 0 ≤ x < lcm, or none if the
+/// congruences contradict each other. moduli need not be coprime, but
+/// their lcm has to fit in a u64.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for &(r, mi) in congruences {
+        assert!(mi > 0, "modulus must be positive");
+        let (r, mi) = ((r % mi) as i128, mi as i128);
+        // x + m·k ≡ r (mod mi)
+        let (g, p, _) = extended_gcd(m, mi);
+        if (r - x) % g != 0 {
+            return None;
+        }
+        let step = mi / g;
+        let a = ((r - x) / g).rem_euclid(step) as u128;
+        let b = p.rem_euclid(step) as u128;
+        let k = (a * b % step as u128) as i128;
+        x += m * k;
+        m *= step;
+        x = x.rem_euclid(m);
+    }
+    Some((x as u64, m as u64))
+}
@@ -0,0 +1,143 @@
+//! primality, prime sieves and factorization of u64
+use super::{gcd, mod_mul, mod_pow};
+
+/// witnesses that make miller-rabin deterministic for every u64
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// deterministic miller-rabin test
+// is_multiple_of needs rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &WITNESSES {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+    // n - 1 = d·2ˢ
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for &a in &WITNESSES {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mod_mul(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// all primes up to and including limit, by the sieve of eratosthenes
+pub fn sieve(limit: usize) -> Vec<usize> {
+    if limit < 2 {
+        return Vec::new();
+    }
+    let mut composite = vec![false; limit + 1];
+    let mut i = 2;
+    while i * i <= limit {
+        if !composite[i] {
+            for j in (i * i..=limit).step_by(i) {
+                composite[j] = true;
+            }
+        }
+        i += 1;
+    }
+    (2..=limit).filter(|&i| !composite[i]).collect()
+}
+
+/// prime factorization as (prime, exponent) pairs, primes increasing
+///
+/// small factors by trial division, the rest by pollard's rho.
+/// factorize(0) and factorize(1) are empty.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n < 2 {
+        return factors;
+    }
+    let mut n = n;
+    for p in [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47] {
+        let mut e = 0;
+        while n % p == 0 {
+            n /= p;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((p, e));
+        }
+    }
+    let mut stack = vec![n];
+    let mut large = Vec::new();
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            large.push(m);
+            continue;
+        }
+        let d = rho(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+    large.sort_unstable();
+    for p in large {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// a nontrivial factor of the odd composite n, brent's variant of pollard's rho
+fn rho(n: u64) -> u64 {
+    let mut c = 1;
+    loop {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut g) = (2u64, 2u64, 1u64);
+        let mut q = 1u64;
+        let mut ys = y;
+        let mut r = 1u64;
+        // batch 128 differences into one gcd
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..128.min(r - k) {
+                    y = f(y);
+                    q = mod_mul(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += 128;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // the batch overshot, step back one at a time
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+        c += 1;
+    }
+}
@@ -0,0 +1,161 @@
+//! integers modulo a prime
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use num::{Num, One, Zero};
+use super::{mod_inverse, mod_mul, mod_pow};
+
+/// an element of ℤ/pℤ
+///
+/// implements `num::Num`, so `Matrix<Zp<P>>` gets the exact routines of
+/// `matrix::exact`: determinant, rank, nullspace, solve and inverse mod p.
+/// division needs P prime (any modulus works for + - ·), and P must stay
+/// below 2⁶³.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Zp<const P: u64>(u64);
+
+impl<const P: u64> Zp<P> {
+    /// n mod P
+    pub fn new(n: u64) -> Self {
+        Zp(n % P)
+    }
+
+    /// the representative in [0, P)
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, e: u64) -> Self {
+        Zp(mod_pow(self.0, e, P))
+    }
+
+    /// multiplicative inverse, none for zero
+    pub fn inverse(self) -> Option<Self> {
+        mod_inverse(self.0, P).map(Zp)
+    }
+}
+
+impl<const P: u64> From<u64> for Zp<P> {
+    fn from(n: u64) -> Self {
+        Zp::new(n)
+    }
+}
+
+impl<const P: u64> From<i64> for Zp<P> {
+    fn from(n: i64) -> Self {
+        Zp((n as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> fmt::Debug for Zp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+impl<const P: u64> fmt::Display for Zp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Add for Zp<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let s = self.0 + rhs.0;
+        Zp(if s >= P { s - P } else { s })
+    }
+}
+
+impl<const P: u64> Sub for Zp<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Zp(if self.0 >= rhs.0 { self.0 - rhs.0 } else { self.0 + P - rhs.0 })
+    }
+}
+
+impl<const P: u64> Mul for Zp<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Zp(mod_mul(self.0, rhs.0, P))
+    }
+}
+
+impl<const P: u64> Div for Zp<P> {
+    type Output = Self;
+
+    /// panics when dividing by zero or by a non unit of a composite modulus
+    fn div(self, rhs: Self) -> Self {
+        match rhs.inverse() {
+            Some(inv) => Zp(mod_mul(self.0, inv.0, P)),
+            None => panic!("{} has no inverse mod {}", rhs.0, P),
+        }
+    }
+}
+
+impl<const P: u64> Rem for Zp<P> {
+    type Output = Self;
+
+    /// ℤ/pℤ is a field, every division leaves no remainder
+    fn rem(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            panic!("remainder by zero");
+        }
+        Zp(0)
+    }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Zp(if self.0 == 0 { 0 } else { P - self.0 })
+    }
+}
+
+impl<const P: u64> AddAssign for Zp<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for Zp<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for Zp<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> Zero for Zp<P> {
+    fn zero() -> Self {
+        Zp(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Zp<P> {
+    fn one() -> Self {
+        Zp(1 % P)
+    }
+}
+
+impl<const P: u64> Num for Zp<P> {
+    type FromStrRadixErr = std::num::ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if let Some(neg) = s.strip_prefix('-') {
+            return u64::from_str_radix(neg, radix).map(|n| -Zp::new(n));
+        }
+        u64::from_str_radix(s, radix).map(Zp::new)
+    }
+}
@@ -0,0 +1,131 @@
+//! numerical integration, root finding and optimization
+//!
+//! quadrature and root finding work on scalar functions `Fn(T) -> T`, the
+//! ode integrators and minimizers work on any `State`, which is implemented
+//! for `Vector<T, N>` and `VectorN<T>`.
+use num::Float;
+use crate::vector::vector::Vector;
+use crate::vector::vectorn::VectorN;
+
+pub mod quadrature;
+pub mod ode;
+pub mod roots;
+pub mod optimize;
+
+pub use quadrature::{adaptive_simpson, gauss_legendre, romberg, GaussLegendre};
+pub use ode::{euler, euler_step, rk4, rk4_step, rk45, velocity_verlet, velocity_verlet_step, OdeError, OdeSolution, Rk45Options};
+pub use roots::{bisection, brent, newton, secant, Root, RootError};
+pub use optimize::{bfgs, gradient_descent, lbfgs, levenberg_marquardt, nelder_mead, Minimum, Objective, Residuals, WithGradient, WithJacobian};
+
+/// state vector of an ode, y in y' = f(t, y)
+pub trait State: Clone {
+    type Scalar: Float;
+
+    fn dim(&self) -> usize;
+    fn get(&self, i: usize) -> Self::Scalar;
+    fn set(&mut self, i: usize, x: Self::Scalar);
+
+    /// self + h·k
+    fn add_scaled(&self, h: Self::Scalar, k: &Self) -> Self {
+        let mut r = self.clone();
+        for i in 0..self.dim() {
+            r.set(i, self.get(i) + h * k.get(i));
+        }
+        r
+    }
+}
+
+impl<T: Float, const N: usize> State for Vector<T, N> {
+    type Scalar = T;
+
+    #[inline]
+    fn dim(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> T {
+        self.data[i]
+    }
+
+    #[inline]
+    fn set(&mut self, i: usize, x: T) {
+        self.data[i] = x;
+    }
+}
+
+impl<T: Float> State for VectorN<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn dim(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> T {
+        self[i]
+    }
+
+    #[inline]
+    fn set(&mut self, i: usize, x: T) {
+        self[i] = x;
+    }
+}
+
+/// when an iterative method stops
+///
+/// a tolerance of zero switches its test off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Criteria<T> {
+    pub max_iterations: usize,
+    /// largest component of the last step
+    pub x_tol: T,
+    /// change of the objective relative to its size, for root finders |f(x)| itself
+    pub f_tol: T,
+    /// largest component of the gradient
+    pub g_tol: T,
+}
+
+impl<T: Float> Default for Criteria<T> {
+    fn default() -> Self {
+        Criteria {
+            max_iterations: 1000,
+            x_tol: T::epsilon().sqrt(),
+            f_tol: T::epsilon(),
+            g_tol: T::epsilon().sqrt(),
+        }
+    }
+}
+
+/// the criterion that stopped an iterative method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    XTolerance,
+    FTolerance,
+    GradientTolerance,
+    /// ran out of iterations without meeting a tolerance
+    MaxIterations,
+}
+
+impl Termination {
+    pub fn converged(self) -> bool {
+        self != Termination::MaxIterations
+    }
+}
+
+/// y + Σ hᵢ·kᵢ
+fn combine<S: State>(y: &S, terms: &[(S::Scalar, &S)]) -> S {
+    let mut r = y.clone();
+    for i in 0..y.dim() {
+        let x = terms.iter().fold(y.get(i), |acc, (h, k)| acc + *h * k.get(i));
+        r.set(i, x);
+    }
+    r
+}
+
+/// f64 constant as T
+#[inline]
+fn lit<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
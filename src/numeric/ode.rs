@@ -0,0 +1,211 @@
+//! initial value problems y' = f(t, y)
+//!
+//! the right hand side is a closure `Fn(t, &y) -> y'` returning a state of
+//! the same dimension as y.
+use std::fmt;
+use num::{Float, One, Zero};
+use super::{combine, lit, State};
+
+/// one explicit euler step of size h
+pub fn euler_step<S: State, F: Fn(S::Scalar, &S) -> S>(f: F, t: S::Scalar, y: &S, h: S::Scalar) -> S {
+    y.add_scaled(h, &f(t, y))
+}
+
+/// one classic fourth order runge-kutta step of size h
+pub fn rk4_step<S: State, F: Fn(S::Scalar, &S) -> S>(f: F, t: S::Scalar, y: &S, h: S::Scalar) -> S {
+    let half = h / lit(2.0);
+    let k1 = f(t, y);
+    let k2 = f(t + half, &y.add_scaled(half, &k1));
+    let k3 = f(t + half, &y.add_scaled(half, &k2));
+    let k4 = f(t + h, &y.add_scaled(h, &k3));
+    let sixth = h / lit(6.0);
+    let third = h / lit(3.0);
+    combine(y, &[(sixth, &k1), (third, &k2), (third, &k3), (sixth, &k4)])
+}
+
+/// y(t1) by `steps` euler steps from y(t0) = y0
+pub fn euler<S: State, F: Fn(S::Scalar, &S) -> S>(f: F, t0: S::Scalar, y0: S, t1: S::Scalar, steps: usize) -> S {
+    fixed(|t, y, h| euler_step(&f, t, y, h), t0, y0, t1, steps)
+}
+
+/// y(t1) by `steps` rk4 steps from y(t0) = y0
+pub fn rk4<S: State, F: Fn(S::Scalar, &S) -> S>(f: F, t0: S::Scalar, y0: S, t1: S::Scalar, steps: usize) -> S {
+    fixed(|t, y, h| rk4_step(&f, t, y, h), t0, y0, t1, steps)
+}
+
+fn fixed<S: State, G: Fn(S::Scalar, &S, S::Scalar) -> S>(step: G, t0: S::Scalar, y0: S, t1: S::Scalar, steps: usize) -> S {
+    let steps = steps.max(1);
+    let h = (t1 - t0) / lit(steps as f64);
+    (0..steps).fold(y0, |y, i| step(t0 + h * lit(i as f64), &y, h))
+}
+
+/// one velocity verlet step for x'' = a(t, x)
+///
+/// returns the new position and velocity. the method is symplectic, so
+/// energy of a conservative system oscillates instead of drifting.
+pub fn velocity_verlet_step<S: State, A: Fn(S::Scalar, &S) -> S>(a: A, t: S::Scalar, x: &S, v: &S, h: S::Scalar) -> (S, S) {
+    let half = h / lit(2.0);
+    let a0 = a(t, x);
+    let v_half = v.add_scaled(half, &a0);
+    let x1 = x.add_scaled(h, &v_half);
+    let a1 = a(t + h, &x1);
+    let v1 = v_half.add_scaled(half, &a1);
+    (x1, v1)
+}
+
+/// position and velocity at t1 by `steps` velocity verlet steps
+pub fn velocity_verlet<S: State, A: Fn(S::Scalar, &S) -> S>(a: A, t0: S::Scalar, x0: S, v0: S, t1: S::Scalar, steps: usize) -> (S, S) {
+    let steps = steps.max(1);
+    let h = (t1 - t0) / lit(steps as f64);
+    (0..steps).fold((x0, v0), |(x, v), i| velocity_verlet_step(&a, t0 + h * lit(i as f64), &x, &v, h))
+}
+
+/// tolerances and limits of the adaptive integrator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rk45Options<T> {
+    /// relative tolerance per component
+    pub rtol: T,
+    /// absolute tolerance per component
+    pub atol: T,
+    /// first step size, a hundredth of the interval if none
+    pub initial_step: Option<T>,
+    /// largest allowed step size
+    pub max_step: Option<T>,
+    /// smallest allowed step size before giving up
+    pub min_step: T,
+    /// accepted plus rejected steps before giving up
+    pub max_steps: usize,
+}
+
+impl<T: Float> Default for Rk45Options<T> {
+    fn default() -> Self {
+        Rk45Options {
+            rtol: lit(1e-6),
+            atol: lit(1e-9),
+            initial_step: None,
+            max_step: None,
+            min_step: T::epsilon(),
+            max_steps: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OdeError<T> {
+    /// the step size needed fell under `min_step` at t
+    StepTooSmall { t: T },
+    /// `max_steps` were used up at t
+    TooManySteps { t: T },
+}
+
+impl<T: fmt::Display> fmt::Display for OdeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OdeError::StepTooSmall { t } => write!(f, "step size underflow at t = {}", t),
+            OdeError::TooManySteps { t } => write!(f, "step limit reached at t = {}", t),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for OdeError<T> {}
+
+/// accepted steps of an adaptive integration
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeSolution<S: State> {
+    /// times of the accepted steps, starting at t0 and ending at t1
+    pub t: Vec<S::Scalar>,
+    /// state at every time in `t`
+    pub y: Vec<S>,
+    /// steps thrown away because their error was too large
+    pub rejected: usize,
+}
+
+impl<S: State> OdeSolution<S> {
+    /// the state at t1
+    pub fn last(&self) -> &S {
+        &self.y[self.y.len() - 1]
+    }
+}
+
+// dormand-prince 5(4) tableau
+const C: [f64; 6] = [1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A2: [f64; 1] = [1.0 / 5.0];
+const A3: [f64; 2] = [3.0 / 40.0, 9.0 / 40.0];
+const A4: [f64; 3] = [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0];
+const A5: [f64; 4] = [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0];
+const A6: [f64; 5] = [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0];
+/// fifth order weights, also the last stage
+const B: [f64; 6] = [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0];
+/// difference between the fifth and fourth order weights
+const E: [f64; 7] = [71.0 / 57600.0, 0.0, -71.0 / 16695.0, 71.0 / 1920.0, -17253.0 / 339200.0, 22.0 / 525.0, -1.0 / 40.0];
+
+/// integrate from y(t0) = y0 to t1 with the adaptive dormand-prince method
+///
+/// the step size is chosen so the local error estimate of every component
+/// stays within atol + rtol·|y|. t1 may lie before t0.
+pub fn rk45<S: State, F: Fn(S::Scalar, &S) -> S>(f: F, t0: S::Scalar, y0: S, t1: S::Scalar, options: &Rk45Options<S::Scalar>) -> Result<OdeSolution<S>, OdeError<S::Scalar>> {
+    let span = (t1 - t0).abs();
+    let dir = if t1 < t0 { -S::Scalar::one() } else { S::Scalar::one() };
+    let max_step = options.max_step.unwrap_or(span);
+    let mut h = options.initial_step.unwrap_or(span / lit(100.0)).abs().min(max_step);
+    let mut t = t0;
+    let mut y = y0;
+    let mut k1 = f(t, &y);
+    let mut solution = OdeSolution { t: vec![t], y: vec![y.clone()], rejected: 0 };
+    let c = |x: f64| -> S::Scalar { lit(x) };
+    if span <= S::Scalar::zero() {
+        return Ok(solution);
+    }
+
+    for _ in 0..options.max_steps {
+        let left = (t1 - t).abs();
+        if h < options.min_step {
+            return Err(OdeError::StepTooSmall { t });
+        }
+        // finish exactly on t1
+        let last = h >= left;
+        let step = if last { left } else { h } * dir;
+
+        let k2 = f(t + c(C[0]) * step, &combine(&y, &[(c(A2[0]) * step, &k1)]));
+        let k3 = f(t + c(C[1]) * step, &combine(&y, &[(c(A3[0]) * step, &k1), (c(A3[1]) * step, &k2)]));
+        let k4 = f(t + c(C[2]) * step, &combine(&y, &[(c(A4[0]) * step, &k1), (c(A4[1]) * step, &k2), (c(A4[2]) * step, &k3)]));
+        let k5 = f(t + c(C[3]) * step, &combine(&y, &[(c(A5[0]) * step, &k1), (c(A5[1]) * step, &k2), (c(A5[2]) * step, &k3), (c(A5[3]) * step, &k4)]));
+        let k6 = f(t + c(C[4]) * step, &combine(&y, &[(c(A6[0]) * step, &k1), (c(A6[1]) * step, &k2), (c(A6[2]) * step, &k3), (c(A6[3]) * step, &k4), (c(A6[4]) * step, &k5)]));
+        let y_new = combine(&y, &[(c(B[0]) * step, &k1), (c(B[2]) * step, &k3), (c(B[3]) * step, &k4), (c(B[4]) * step, &k5), (c(B[5]) * step, &k6)]);
+        let t_new = if last { t1 } else { t + step };
+        let k7 = f(t_new, &y_new);
+
+        // rms of the error estimate relative to the tolerance
+        let ks = [&k1, &k2, &k3, &k4, &k5, &k6, &k7];
+        let n = y.dim();
+        let mut sum = S::Scalar::zero();
+        for i in 0..n {
+            let e = ks.iter().zip(E.iter()).fold(S::Scalar::zero(), |acc, (k, &e)| acc + c(e) * k.get(i)) * step;
+            let scale = options.atol + options.rtol * y.get(i).abs().max(y_new.get(i).abs());
+            sum = sum + (e / scale) * (e / scale);
+        }
+        let err = if n > 0 { (sum / lit(n as f64)).sqrt() } else { S::Scalar::zero() };
+
+        let factor = if err > S::Scalar::zero() {
+            (c(0.9) * err.powf(c(-0.2))).max(c(0.2)).min(c(5.0))
+        } else {
+            c(5.0)
+        };
+        if err <= S::Scalar::one() {
+            t = t_new;
+            y = y_new;
+            // first same as last: the final stage is the next first stage
+            k1 = k7;
+            solution.t.push(t);
+            solution.y.push(y.clone());
+            if last {
+                return Ok(solution);
+            }
+            h = (h * factor).min(max_step);
+        } else {
+            solution.rejected += 1;
+            h = h * factor.min(S::Scalar::one());
+        }
+    }
+    Err(OdeError::TooManySteps { t })
+}
@@ -0,0 +1,183 @@
+//! gradient based minimizers with a backtracking line search
+//!
+//! a line search that can't decrease f any further ends the run with
+//! `Termination::XTolerance`, the step has shrunk to nothing.
+use std::collections::VecDeque;
+use num::{Float, One, Zero};
+use crate::matrix::matrixnxm::Matrix;
+use super::super::{lit, Criteria, State, Termination};
+use super::{dot, norm_inf, scaled, sub, f_converged, Counted, Minimum, Objective};
+
+/// halving steps from alpha along d until the armijo condition holds
+fn line_search<S: State, O: Objective<S>>(obj: &Counted<O>, x: &S, f: S::Scalar, g: &S, d: &S, alpha: S::Scalar) -> Option<(S::Scalar, S, S::Scalar)> {
+    let slope = dot(g, d);
+    if slope >= S::Scalar::zero() {
+        return None;
+    }
+    let c1 = lit::<S::Scalar>(1e-4);
+    let mut alpha = alpha;
+    for _ in 0..60 {
+        let xn = x.add_scaled(alpha, d);
+        let fnew = obj.value(&xn);
+        if fnew <= f + c1 * alpha * slope {
+            return Some((alpha, xn, fnew));
+        }
+        alpha = alpha * lit(0.5);
+    }
+    None
+}
+
+/// first trial step, the first move is at most one unit in every component
+fn first_step<S: State>(g: &S) -> S::Scalar {
+    S::Scalar::one().min(S::Scalar::one() / norm_inf(g))
+}
+
+/// shared driver: `direction` picks the search direction and first trial
+/// step given the last accepted step length, `update` sees every accepted
+/// step s with its gradient change y
+fn descend<S, O, D, U>(objective: &O, x0: S, criteria: &Criteria<S::Scalar>, mut direction: D, mut update: U) -> Minimum<S>
+where
+    S: State,
+    O: Objective<S>,
+    D: FnMut(&S, usize, Option<S::Scalar>) -> (S, S::Scalar),
+    U: FnMut(&S, &S),
+{
+    let obj = Counted::new(objective);
+    let mut x = x0;
+    let mut f = obj.value(&x);
+    let mut g = obj.gradient(&x);
+    let mut last = None;
+    for k in 1..=criteria.max_iterations {
+        if norm_inf(&g) <= criteria.g_tol {
+            return obj.minimum(x, f, k - 1, Termination::GradientTolerance);
+        }
+        let (d, alpha) = direction(&g, k, last);
+        let (xn, fnew) = match line_search(&obj, &x, f, &g, &d, alpha) {
+            Some((a, xn, fnew)) => {
+                last = Some(a);
+                (xn, fnew)
+            }
+            None => return obj.minimum(x, f, k, Termination::XTolerance),
+        };
+        let gn = obj.gradient(&xn);
+        let s = sub(&xn, &x);
+        update(&s, &sub(&gn, &g));
+        let done = if norm_inf(&s) <= criteria.x_tol {
+            Some(Termination::XTolerance)
+        } else if f_converged(f, fnew, criteria.f_tol) {
+            Some(Termination::FTolerance)
+        } else {
+            None
+        };
+        x = xn;
+        f = fnew;
+        g = gn;
+        if let Some(t) = done {
+            return obj.minimum(x, f, k, t);
+        }
+    }
+    obj.minimum(x, f, criteria.max_iterations, Termination::MaxIterations)
+}
+
+/// steepest descent, each line search starts from twice the last step
+pub fn gradient_descent<S: State, O: Objective<S>>(objective: &O, x0: S, criteria: &Criteria<S::Scalar>) -> Minimum<S> {
+    let mut last = S::Scalar::zero();
+    descend(objective, x0, criteria, |g, k, alpha| {
+        if let Some(a) = alpha {
+            last = a;
+        }
+        let a = if k == 1 { first_step(g) } else { last * lit(2.0) };
+        (scaled(g, -S::Scalar::one()), a)
+    }, |_, _| {})
+}
+
+/// quasi-newton BFGS keeping a dense inverse hessian estimate
+pub fn bfgs<S: State, O: Objective<S>>(objective: &O, x0: S, criteria: &Criteria<S::Scalar>) -> Minimum<S> {
+    let n = x0.dim();
+    let h = std::cell::RefCell::new(Matrix::<S::Scalar>::identity(n));
+    let fresh = std::cell::Cell::new(true);
+    descend(objective, x0, criteria, |g, _, _| {
+        let mut h = h.borrow_mut();
+        let d = scaled(&mul(&h, g), -S::Scalar::one());
+        if dot(g, &d) < S::Scalar::zero() {
+            let alpha = if fresh.get() { first_step(g) } else { S::Scalar::one() };
+            return (d, alpha);
+        }
+        // not a descent direction, start over from steepest descent
+        *h = Matrix::identity(n);
+        fresh.set(true);
+        (scaled(g, -S::Scalar::one()), first_step(g))
+    }, |s, y| {
+        let sy = dot(s, y);
+        if sy <= S::Scalar::zero() {
+            return;
+        }
+        let mut h = h.borrow_mut();
+        if fresh.replace(false) {
+            // scale the identity to the curvature seen along the first step
+            let gamma = sy / dot(y, y);
+            for i in 0..n {
+                h[(i, i)] = gamma;
+            }
+        }
+        // H ← (I - ρ s yᵀ) H (I - ρ y sᵀ) + ρ s sᵀ
+        let rho = S::Scalar::one() / sy;
+        let hy = mul(&h, y);
+        let c = rho * rho * dot(y, &hy) + rho;
+        for i in 0..n {
+            for j in 0..n {
+                let v = h[(i, j)] - rho * (s.get(i) * hy.get(j) + hy.get(i) * s.get(j)) + c * s.get(i) * s.get(j);
+                h[(i, j)] = v;
+            }
+        }
+    })
+}
+
+/// limited memory BFGS remembering the last `memory` steps
+///
+/// needs O(memory·n) storage instead of the O(n²) of `bfgs`, the choice
+/// for problems with many variables.
+pub fn lbfgs<S: State, O: Objective<S>>(objective: &O, x0: S, memory: usize, criteria: &Criteria<S::Scalar>) -> Minimum<S> {
+    let memory = memory.max(1);
+    let history = std::cell::RefCell::new(VecDeque::<(S, S, S::Scalar)>::with_capacity(memory));
+    descend(objective, x0, criteria, |g, _, _| {
+        let history = history.borrow();
+        if history.is_empty() {
+            return (scaled(g, -S::Scalar::one()), first_step(g));
+        }
+        // two loop recursion
+        let mut q = g.clone();
+        let mut a = Vec::with_capacity(history.len());
+        for (s, y, rho) in history.iter().rev() {
+            let ai = *rho * dot(s, &q);
+            q = q.add_scaled(-ai, y);
+            a.push(ai);
+        }
+        let (s, y, _) = &history[history.len() - 1];
+        let mut r = scaled(&q, dot(s, y) / dot(y, y));
+        for ((s, y, rho), ai) in history.iter().zip(a.iter().rev()) {
+            let b = *rho * dot(y, &r);
+            r = r.add_scaled(*ai - b, s);
+        }
+        (scaled(&r, -S::Scalar::one()), S::Scalar::one())
+    }, |s, y| {
+        let sy = dot(s, y);
+        if sy <= S::Scalar::zero() {
+            return;
+        }
+        let mut history = history.borrow_mut();
+        if history.len() == memory {
+            history.pop_front();
+        }
+        history.push_back((s.clone(), y.clone(), S::Scalar::one() / sy));
+    })
+}
+
+/// H·v
+fn mul<S: State>(h: &Matrix<S::Scalar>, v: &S) -> S {
+    let mut r = v.clone();
+    for i in 0..v.dim() {
+        r.set(i, (0..v.dim()).fold(S::Scalar::zero(), |acc, j| acc + h[(i, j)] * v.get(j)));
+    }
+    r
+}
@@ -0,0 +1,94 @@
+//! nonlinear least squares by levenberg-marquardt
+use num::{Float, One, Zero};
+use crate::vector::vectorn::VectorN;
+use super::super::{lit, Criteria, State, Termination};
+use super::{norm_inf, f_converged, Minimum, Residuals};
+
+/// minimize ½‖r(x)‖² from x0
+///
+/// each step solves (JᵀJ + λ·diag(JᵀJ)) δ = -Jᵀr by cholesky; λ moves
+/// between gradient descent (large) and gauss-newton (small) depending on
+/// how well the linear model predicted the decrease. `Minimum::gradients`
+/// counts jacobian evaluations.
+pub fn levenberg_marquardt<S: State, R: Residuals<S>>(residuals: &R, x0: S, criteria: &Criteria<S::Scalar>) -> Minimum<S> {
+    let (zero, one, half) = (S::Scalar::zero(), S::Scalar::one(), lit::<S::Scalar>(0.5));
+    let n = x0.dim();
+    let mut x = x0;
+    let mut r = residuals.residuals(&x);
+    let mut cost = half * VectorN::dot(&r, &r);
+    let (mut evaluations, mut jacobians) = (1, 0);
+    let report = |x, value, iterations, evaluations, gradients, termination| Minimum { x, value, iterations, evaluations, gradients, termination };
+
+    let mut lambda = None;
+    let mut nu = lit::<S::Scalar>(2.0);
+    let mut normal = None;
+    for k in 1..=criteria.max_iterations {
+        // the normal equations only change when a step was accepted
+        let (a, g) = match normal.take() {
+            Some(ag) => ag,
+            None => {
+                let j = residuals.jacobian(&x);
+                jacobians += 1;
+                let jt = j.transpose();
+                let a = &jt * &j;
+                let g = VectorN::new(jt.mul_vec(r.as_slice()));
+                (a, g)
+            }
+        };
+        if norm_inf(&g) <= criteria.g_tol {
+            return report(x, cost, k - 1, evaluations, jacobians, Termination::GradientTolerance);
+        }
+        let diag: Vec<S::Scalar> = (0..n).map(|i| a[(i, i)].max(S::Scalar::epsilon())).collect();
+        let l = *lambda.get_or_insert_with(|| lit::<S::Scalar>(1e-3) * diag.iter().fold(zero, |m, &d| m.max(d)));
+
+        let mut damped = a.clone();
+        for i in 0..n {
+            damped[(i, i)] = damped[(i, i)] + l * diag[i];
+        }
+        let delta = match damped.cholesky() {
+            Ok(c) => c.solve(&(-g.clone())),
+            Err(_) => {
+                lambda = Some(l * nu);
+                nu = nu * lit(2.0);
+                normal = Some((a, g));
+                continue;
+            }
+        };
+        let mut xn = x.clone();
+        for i in 0..n {
+            xn.set(i, x.get(i) + delta[i]);
+        }
+        let rn = residuals.residuals(&xn);
+        evaluations += 1;
+        let cost_new = half * VectorN::dot(&rn, &rn);
+        // decrease predicted by the linear model, ½ δᵀ(λ·D·δ - g)
+        let predicted = (0..n).fold(zero, |acc, i| acc + delta[i] * (l * diag[i] * delta[i] - g[i])) * half;
+        let step = norm_inf(&delta);
+
+        if cost_new < cost && predicted > zero {
+            let rho = (cost - cost_new) / predicted;
+            let t = lit::<S::Scalar>(2.0) * rho - one;
+            lambda = Some(l * lit::<S::Scalar>(1.0 / 3.0).max(one - t * t * t));
+            nu = lit(2.0);
+            let f_done = f_converged(cost, cost_new, criteria.f_tol);
+            x = xn;
+            r = rn;
+            cost = cost_new;
+            if step <= criteria.x_tol {
+                return report(x, cost, k, evaluations, jacobians, Termination::XTolerance);
+            }
+            if f_done {
+                return report(x, cost, k, evaluations, jacobians, Termination::FTolerance);
+            }
+        } else {
+            if step <= criteria.x_tol {
+                return report(x, cost, k, evaluations, jacobians, Termination::XTolerance);
+            }
+            lambda = Some(l * nu);
+            nu = nu * lit(2.0);
+            normal = Some((a, g));
+        }
+    }
+    report(x, cost, criteria.max_iterations, evaluations, jacobians, Termination::MaxIterations)
+}
+
@@ -0,0 +1,179 @@
+//! unconstrained minimization of f: Rⁿ → R
+//!
+//! every minimizer takes an `Objective` (or `Residuals` for least squares),
+//! a starting point and `Criteria`, and returns a `Minimum` telling where it
+//! stopped and why.
+use std::cell::Cell;
+use num::{Float, One, Zero};
+use crate::matrix::matrixnxm::Matrix;
+use crate::vector::vectorn::VectorN;
+use super::{State, Termination};
+
+pub mod descent;
+pub mod nelder_mead;
+pub mod least_squares;
+
+pub use descent::{bfgs, gradient_descent, lbfgs};
+pub use nelder_mead::nelder_mead;
+pub use least_squares::levenberg_marquardt;
+
+/// a function to minimize
+///
+/// closures `Fn(&S) -> S::Scalar` are objectives with a finite difference
+/// gradient, wrap them in `WithGradient` to supply the gradient.
+pub trait Objective<S: State> {
+    fn value(&self, x: &S) -> S::Scalar;
+
+    /// ∇f(x), central differences unless overridden
+    fn gradient(&self, x: &S) -> S {
+        let mut g = x.clone();
+        let mut y = x.clone();
+        let step = S::Scalar::epsilon().cbrt();
+        for i in 0..x.dim() {
+            let xi = x.get(i);
+            let h = step * xi.abs().max(S::Scalar::one());
+            y.set(i, xi + h);
+            let up = self.value(&y);
+            y.set(i, xi - h);
+            let down = self.value(&y);
+            y.set(i, xi);
+            g.set(i, (up - down) / (h + h));
+        }
+        g
+    }
+}
+
+impl<S: State, F: Fn(&S) -> S::Scalar> Objective<S> for F {
+    fn value(&self, x: &S) -> S::Scalar {
+        self(x)
+    }
+}
+
+/// an objective f with its analytic gradient g
+#[derive(Debug, Clone, Copy)]
+pub struct WithGradient<F, G>(pub F, pub G);
+
+impl<S: State, F: Fn(&S) -> S::Scalar, G: Fn(&S) -> S> Objective<S> for WithGradient<F, G> {
+    fn value(&self, x: &S) -> S::Scalar {
+        (self.0)(x)
+    }
+
+    fn gradient(&self, x: &S) -> S {
+        (self.1)(x)
+    }
+}
+
+/// residuals r(x) of a least squares problem, minimizing ½‖r(x)‖²
+///
+/// closures `Fn(&S) -> VectorN<S::Scalar>` get a finite difference
+/// jacobian, wrap them in `WithJacobian` to supply it.
+pub trait Residuals<S: State> {
+    fn residuals(&self, x: &S) -> VectorN<S::Scalar>;
+
+    /// jacobian ∂rᵢ/∂xⱼ, one row per residual, forward differences unless overridden
+    fn jacobian(&self, x: &S) -> Matrix<S::Scalar> {
+        let r = self.residuals(x);
+        let mut j = Matrix::zeros(r.len(), x.dim());
+        let mut y = x.clone();
+        let step = S::Scalar::epsilon().sqrt();
+        for c in 0..x.dim() {
+            let xc = x.get(c);
+            let h = step * xc.abs().max(S::Scalar::one());
+            y.set(c, xc + h);
+            let rh = self.residuals(&y);
+            y.set(c, xc);
+            for i in 0..r.len() {
+                j[(i, c)] = (rh[i] - r[i]) / h;
+            }
+        }
+        j
+    }
+}
+
+impl<S: State, F: Fn(&S) -> VectorN<S::Scalar>> Residuals<S> for F {
+    fn residuals(&self, x: &S) -> VectorN<S::Scalar> {
+        self(x)
+    }
+}
+
+/// residuals r with their analytic jacobian j
+#[derive(Debug, Clone, Copy)]
+pub struct WithJacobian<R, J>(pub R, pub J);
+
+impl<S: State, R: Fn(&S) -> VectorN<S::Scalar>, J: Fn(&S) -> Matrix<S::Scalar>> Residuals<S> for WithJacobian<R, J> {
+    fn residuals(&self, x: &S) -> VectorN<S::Scalar> {
+        (self.0)(x)
+    }
+
+    fn jacobian(&self, x: &S) -> Matrix<S::Scalar> {
+        (self.1)(x)
+    }
+}
+
+/// where a minimizer stopped
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimum<S: State> {
+    pub x: S,
+    /// objective at x, ½‖r(x)‖² for least squares
+    pub value: S::Scalar,
+    pub iterations: usize,
+    /// objective or residual evaluations
+    pub evaluations: usize,
+    /// gradient or jacobian evaluations
+    pub gradients: usize,
+    pub termination: Termination,
+}
+
+/// objective that counts its calls
+struct Counted<'a, O> {
+    inner: &'a O,
+    values: Cell<usize>,
+    gradients: Cell<usize>,
+}
+
+impl<'a, O> Counted<'a, O> {
+    fn new(inner: &'a O) -> Self {
+        Counted { inner, values: Cell::new(0), gradients: Cell::new(0) }
+    }
+
+    fn value<S: State>(&self, x: &S) -> S::Scalar where O: Objective<S> {
+        self.values.set(self.values.get() + 1);
+        self.inner.value(x)
+    }
+
+    fn gradient<S: State>(&self, x: &S) -> S where O: Objective<S> {
+        self.gradients.set(self.gradients.get() + 1);
+        self.inner.gradient(x)
+    }
+
+    fn minimum<S: State>(&self, x: S, value: S::Scalar, iterations: usize, termination: Termination) -> Minimum<S> {
+        Minimum { x, value, iterations, evaluations: self.values.get(), gradients: self.gradients.get(), termination }
+    }
+}
+
+fn dot<S: State>(a: &S, b: &S) -> S::Scalar {
+    (0..a.dim()).fold(S::Scalar::zero(), |acc, i| acc + a.get(i) * b.get(i))
+}
+
+fn norm_inf<S: State>(a: &S) -> S::Scalar {
+    (0..a.dim()).fold(S::Scalar::zero(), |acc, i| acc.max(a.get(i).abs()))
+}
+
+/// c·a
+fn scaled<S: State>(a: &S, c: S::Scalar) -> S {
+    let mut r = a.clone();
+    for i in 0..a.dim() {
+        r.set(i, c * a.get(i));
+    }
+    r
+}
+
+/// a - b
+fn sub<S: State>(a: &S, b: &S) -> S {
+    a.add_scaled(-S::Scalar::one(), b)
+}
+
+/// |f_new - f| small relative to |f|
+fn f_converged<T: Float>(f: T, f_new: T, tol: T) -> bool {
+    (f - f_new).abs() <= tol * f.abs().max(f_new.abs())
+}
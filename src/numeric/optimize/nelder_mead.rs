@@ -0,0 +1,81 @@
+//! derivative free downhill simplex
+use num::{Float, One, Zero};
+use super::super::{lit, Criteria, State, Termination};
+use super::{norm_inf, sub, Counted, Minimum, Objective};
+
+/// nelder-mead simplex search from x0
+///
+/// the first simplex is x0 plus `step` along every axis. stops when the
+/// simplex is narrower than x_tol in every component (XTolerance) or the
+/// objective differs by less than f_tol across it (FTolerance).
+pub fn nelder_mead<S: State, O: Objective<S>>(objective: &O, x0: S, step: S::Scalar, criteria: &Criteria<S::Scalar>) -> Minimum<S> {
+    let obj = Counted::new(objective);
+    let n = x0.dim();
+    let (one, half, two) = (S::Scalar::one(), lit::<S::Scalar>(0.5), lit::<S::Scalar>(2.0));
+    let mut simplex: Vec<(S, S::Scalar)> = Vec::with_capacity(n + 1);
+    for i in 0..=n {
+        let mut x = x0.clone();
+        if i > 0 {
+            x.set(i - 1, x.get(i - 1) + step);
+        }
+        let f = obj.value(&x);
+        simplex.push((x, f));
+    }
+    let order = |simplex: &mut Vec<(S, S::Scalar)>| {
+        simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    };
+    order(&mut simplex);
+
+    for k in 1..=criteria.max_iterations {
+        let (best, worst) = (simplex[0].1, simplex[n].1);
+        let width = simplex[1..].iter().fold(S::Scalar::zero(), |acc, (x, _)| acc.max(norm_inf(&sub(x, &simplex[0].0))));
+        if width <= criteria.x_tol {
+            return obj.minimum(simplex.swap_remove(0).0, best, k - 1, Termination::XTolerance);
+        }
+        if (worst - best).abs() <= criteria.f_tol * best.abs().max(worst.abs()) {
+            return obj.minimum(simplex.swap_remove(0).0, best, k - 1, Termination::FTolerance);
+        }
+
+        // centroid of all but the worst point
+        let mut c = simplex[0].0.clone();
+        for (x, _) in &simplex[1..n] {
+            c = c.add_scaled(one, x);
+        }
+        c = super::scaled(&c, one / lit(n as f64));
+        let toward = sub(&c, &simplex[n].0);
+
+        let xr = c.add_scaled(one, &toward);
+        let fr = obj.value(&xr);
+        if fr < simplex[0].1 {
+            let xe = c.add_scaled(two, &toward);
+            let fe = obj.value(&xe);
+            simplex[n] = if fe < fr { (xe, fe) } else { (xr, fr) };
+        } else if fr < simplex[n - 1].1 {
+            simplex[n] = (xr, fr);
+        } else {
+            // contract on the better side of the worst point
+            let (xc, fc) = if fr < worst {
+                let x = c.add_scaled(half, &toward);
+                let f = obj.value(&x);
+                (x, f)
+            } else {
+                let x = c.add_scaled(-half, &toward);
+                let f = obj.value(&x);
+                (x, f)
+            };
+            if fc < worst.min(fr) {
+                simplex[n] = (xc, fc);
+            } else {
+                // shrink toward the best point
+                let x0 = simplex[0].0.clone();
+                for (x, f) in simplex.iter_mut().skip(1) {
+                    *x = x0.add_scaled(half, &sub(x, &x0));
+                    *f = obj.value(x);
+                }
+            }
+        }
+        order(&mut simplex);
+    }
+    let (x, f) = simplex.swap_remove(0);
+    obj.minimum(x, f, criteria.max_iterations, Termination::MaxIterations)
+}
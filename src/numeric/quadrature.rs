@@ -0,0 +1,125 @@
+//! definite integrals of scalar functions over [a, b]
+use num::Float;
+use super::lit;
+
+/// adaptive simpson's rule to absolute tolerance tol
+///
+/// intervals are halved until the richardson error estimate drops under
+/// the tolerance or max_depth levels are reached.
+pub fn adaptive_simpson<T: Float, F: Fn(T) -> T>(f: F, a: T, b: T, tol: T, max_depth: usize) -> T {
+    let m = (a + b) / lit(2.0);
+    let (fa, fm, fb) = (f(a), f(m), f(b));
+    let whole = simpson(a, b, fa, fm, fb);
+    simpson_step(&f, a, b, fa, fm, fb, whole, tol, max_depth)
+}
+
+fn simpson<T: Float>(a: T, b: T, fa: T, fm: T, fb: T) -> T {
+    (b - a) / lit(6.0) * (fa + lit::<T>(4.0) * fm + fb)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn simpson_step<T: Float, F: Fn(T) -> T>(f: &F, a: T, b: T, fa: T, fm: T, fb: T, whole: T, tol: T, depth: usize) -> T {
+    let m = (a + b) / lit(2.0);
+    let (lm, rm) = ((a + m) / lit(2.0), (m + b) / lit(2.0));
+    let (flm, frm) = (f(lm), f(rm));
+    let left = simpson(a, m, fa, flm, fm);
+    let right = simpson(m, b, fm, frm, fb);
+    let delta = left + right - whole;
+    if depth == 0 || delta.abs() <= lit::<T>(15.0) * tol {
+        return left + right + delta / lit(15.0);
+    }
+    let half = tol / lit(2.0);
+    simpson_step(f, a, m, fa, flm, fm, left, half, depth - 1) + simpson_step(f, m, b, fm, frm, fb, right, half, depth - 1)
+}
+
+/// n point gauss-legendre rule, exact for polynomials up to degree 2n - 1
+///
+/// building the rule costs O(n²), keep it around when integrating many times.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussLegendre<T> {
+    nodes: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T: Float> GaussLegendre<T> {
+    /// nodes and weights on [-1, 1], found by newton's method on Pₙ
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "gauss-legendre needs at least one node");
+        let mut nodes = vec![T::zero(); n];
+        let mut weights = vec![T::zero(); n];
+        let nf = n as f64;
+        for i in 0..n.div_ceil(2) {
+            let mut x = (std::f64::consts::PI * (i as f64 + 0.75) / (nf + 0.5)).cos();
+            let mut dp = 1.0;
+            for _ in 0..100 {
+                // Pₙ(x) and Pₙ₋₁(x) by the three term recurrence
+                let (mut p0, mut p1) = (1.0, 0.0);
+                for j in 1..=n {
+                    let p2 = p1;
+                    p1 = p0;
+                    p0 = ((2 * j - 1) as f64 * x * p1 - (j - 1) as f64 * p2) / j as f64;
+                }
+                dp = nf * (x * p0 - p1) / (x * x - 1.0);
+                let dx = p0 / dp;
+                x -= dx;
+                if dx.abs() < 1e-15 {
+                    break;
+                }
+            }
+            let w = 2.0 / ((1.0 - x * x) * dp * dp);
+            nodes[i] = lit(-x);
+            nodes[n - 1 - i] = lit(x);
+            weights[i] = lit(w);
+            weights[n - 1 - i] = lit(w);
+        }
+        GaussLegendre { nodes, weights }
+    }
+
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T] {
+        &self.weights
+    }
+
+    /// ∫ f over [a, b]
+    pub fn integrate<F: Fn(T) -> T>(&self, f: F, a: T, b: T) -> T {
+        let half = (b - a) / lit(2.0);
+        let mid = (a + b) / lit(2.0);
+        self.nodes.iter().zip(self.weights.iter())
+            .fold(T::zero(), |acc, (&x, &w)| acc + w * f(mid + half * x)) * half
+    }
+}
+
+/// ∫ f over [a, b] with an n point gauss-legendre rule
+pub fn gauss_legendre<T: Float, F: Fn(T) -> T>(f: F, a: T, b: T, n: usize) -> T {
+    GaussLegendre::new(n).integrate(f, a, b)
+}
+
+/// romberg integration, richardson extrapolation of the trapezoid rule
+///
+/// stops when two diagonal entries agree to tol or after max_levels halvings.
+pub fn romberg<T: Float, F: Fn(T) -> T>(f: F, a: T, b: T, tol: T, max_levels: usize) -> T {
+    let mut h = b - a;
+    let mut prev = vec![(f(a) + f(b)) * h / lit(2.0)];
+    for k in 1..=max_levels {
+        h = h / lit(2.0);
+        // the trapezoid sum only needs the new midpoints
+        let fresh = (0..1usize << (k - 1))
+            .fold(T::zero(), |acc, i| acc + f(a + h * lit((2 * i + 1) as f64)));
+        let mut row = Vec::with_capacity(k + 1);
+        row.push(prev[0] / lit(2.0) + h * fresh);
+        let mut scale = T::one();
+        for j in 1..=k {
+            scale = scale * lit(4.0);
+            let r = row[j - 1] + (row[j - 1] - prev[j - 1]) / (scale - T::one());
+            row.push(r);
+        }
+        if (row[k] - prev[k - 1]).abs() <= tol && k > 1 {
+            return row[k];
+        }
+        prev = row;
+    }
+    prev[prev.len() - 1]
+}
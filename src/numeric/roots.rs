@@ -0,0 +1,212 @@
+//! roots of scalar functions f(x) = 0
+//!
+//! bracketing methods need f(a) and f(b) of opposite sign and always
+//! converge, newton and secant are faster but need a good starting point.
+use std::fmt;
+use num::Float;
+use super::{lit, Criteria, Termination};
+
+/// a root and how it was found
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root<T> {
+    pub x: T,
+    /// f(x)
+    pub value: T,
+    pub iterations: usize,
+    pub termination: Termination,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError<T> {
+    /// f(a) and f(b) have the same sign
+    NotBracketed { fa: T, fb: T },
+    /// the derivative or secant slope vanished at x
+    ZeroSlope { x: T },
+    /// the iterate left the finite numbers
+    Diverged,
+}
+
+impl<T: fmt::Display> fmt::Display for RootError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RootError::NotBracketed { fa, fb } => write!(f, "root is not bracketed, f(a) = {} and f(b) = {}", fa, fb),
+            RootError::ZeroSlope { x } => write!(f, "zero slope at x = {}", x),
+            RootError::Diverged => write!(f, "iteration diverged"),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for RootError<T> {}
+
+fn root<T>(x: T, value: T, iterations: usize, termination: Termination) -> Root<T> {
+    Root { x, value, iterations, termination }
+}
+
+/// check the bracket [a, b], an endpoint that is already a root is returned
+fn bracket<T: Float>(a: T, b: T, fa: T, fb: T) -> Result<Option<Root<T>>, RootError<T>> {
+    if fa == T::zero() {
+        return Ok(Some(root(a, fa, 0, Termination::FTolerance)));
+    }
+    if fb == T::zero() {
+        return Ok(Some(root(b, fb, 0, Termination::FTolerance)));
+    }
+    if fa.signum() == fb.signum() {
+        return Err(RootError::NotBracketed { fa, fb });
+    }
+    Ok(None)
+}
+
+/// halve the bracket [a, b] until it is narrower than x_tol
+pub fn bisection<T: Float, F: Fn(T) -> T>(f: F, a: T, b: T, criteria: &Criteria<T>) -> Result<Root<T>, RootError<T>> {
+    let (mut a, mut b) = (a, b);
+    let (mut fa, fb) = (f(a), f(b));
+    if let Some(r) = bracket(a, b, fa, fb)? {
+        return Ok(r);
+    }
+    let two = lit::<T>(2.0);
+    for i in 1..=criteria.max_iterations {
+        let m = a + (b - a) / two;
+        let fm = f(m);
+        if fm.abs() <= criteria.f_tol {
+            return Ok(root(m, fm, i, Termination::FTolerance));
+        }
+        if (b - a).abs() / two <= criteria.x_tol {
+            return Ok(root(m, fm, i, Termination::XTolerance));
+        }
+        if fm.signum() == fa.signum() {
+            a = m;
+            fa = fm;
+        } else {
+            b = m;
+        }
+    }
+    let m = a + (b - a) / two;
+    Ok(root(m, f(m), criteria.max_iterations, Termination::MaxIterations))
+}
+
+/// brent's method: inverse quadratic interpolation guarded by bisection
+///
+/// follows `zeroin` from r. p. brent, "algorithms for minimization without
+/// derivatives", 1973, chapter 4, which the author placed in the public domain.
+pub fn brent<T: Float, F: Fn(T) -> T>(f: F, a: T, b: T, criteria: &Criteria<T>) -> Result<Root<T>, RootError<T>> {
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if let Some(r) = bracket(a, b, fa, fb)? {
+        return Ok(r);
+    }
+    let (one, two, three, half) = (T::one(), lit::<T>(2.0), lit::<T>(3.0), lit::<T>(0.5));
+    // b is the best estimate, a the previous one and c keeps f(c) opposite to
+    // f(b). e is the step before last, d the last one
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+    for i in 1..=criteria.max_iterations {
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol = two * T::epsilon() * b.abs() + half * criteria.x_tol;
+        let m = half * (c - b);
+        if fb.abs() <= criteria.f_tol {
+            return Ok(root(b, fb, i, Termination::FTolerance));
+        }
+        if m.abs() <= tol {
+            return Ok(root(b, fb, i, Termination::XTolerance));
+        }
+
+        // interpolate only while the steps shrink fast enough, else bisect
+        let mut bisect = true;
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // the step is p / q, computed with q of the sign that makes p positive
+            let s = fb / fa;
+            let (p, q) = if a == c {
+                // linear interpolation
+                (two * m * s, one - s)
+            } else {
+                // inverse quadratic interpolation through a, b and c
+                let q = fa / fc;
+                let r = fb / fc;
+                (s * (two * m * q * (q - r) - (b - a) * (r - one)), (q - one) * (r - one) * (s - one))
+            };
+            let (p, q) = if p > T::zero() { (p, -q) } else { (-p, q) };
+            // stay inside the bracket and at least halve the step of two iterations ago
+            if two * p < three * m * q - (tol * q).abs() && p < (half * e * q).abs() {
+                e = d;
+                d = p / q;
+                bisect = false;
+            }
+        }
+        if bisect {
+            d = m;
+            e = m;
+        }
+
+        a = b;
+        fa = fb;
+        // never step by less than the tolerance
+        b = b + if d.abs() > tol { d } else { tol.copysign(m) };
+        fb = f(b);
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+    }
+    Ok(root(b, fb, criteria.max_iterations, Termination::MaxIterations))
+}
+
+/// newton's method from x0 with the derivative df
+pub fn newton<T: Float, F: Fn(T) -> T, D: Fn(T) -> T>(f: F, df: D, x0: T, criteria: &Criteria<T>) -> Result<Root<T>, RootError<T>> {
+    let mut x = x0;
+    let mut fx = f(x);
+    for i in 1..=criteria.max_iterations {
+        if fx.abs() <= criteria.f_tol {
+            return Ok(root(x, fx, i - 1, Termination::FTolerance));
+        }
+        let d = df(x);
+        if d == T::zero() {
+            return Err(RootError::ZeroSlope { x });
+        }
+        let step = fx / d;
+        x = x - step;
+        if !x.is_finite() {
+            return Err(RootError::Diverged);
+        }
+        fx = f(x);
+        if step.abs() <= criteria.x_tol {
+            return Ok(root(x, fx, i, Termination::XTolerance));
+        }
+    }
+    Ok(root(x, fx, criteria.max_iterations, Termination::MaxIterations))
+}
+
+/// secant method from the two starting points x0 and x1
+pub fn secant<T: Float, F: Fn(T) -> T>(f: F, x0: T, x1: T, criteria: &Criteria<T>) -> Result<Root<T>, RootError<T>> {
+    let (mut x0, mut x1) = (x0, x1);
+    let (mut f0, mut f1) = (f(x0), f(x1));
+    for i in 1..=criteria.max_iterations {
+        if f1.abs() <= criteria.f_tol {
+            return Ok(root(x1, f1, i - 1, Termination::FTolerance));
+        }
+        if f1 == f0 {
+            return Err(RootError::ZeroSlope { x: x1 });
+        }
+        let step = f1 * (x1 - x0) / (f1 - f0);
+        x0 = x1;
+        f0 = f1;
+        x1 = x1 - step;
+        if !x1.is_finite() {
+            return Err(RootError::Diverged);
+        }
+        f1 = f(x1);
+        if step.abs() <= criteria.x_tol {
+            return Ok(root(x1, f1, i, Termination::XTolerance));
+        }
+    }
+    Ok(root(x1, f1, criteria.max_iterations, Termination::MaxIterations))
+}
@@ -0,0 +1,164 @@
+//! parse vectors and matrices from text
+//!
+//! accepted forms, all of which may mix `,` and whitespace between elements:
+//!
+//! - what `Display` prints: `(1, -2.5, 3)`, `[1, 2]\n[3, 4]`, `[[1, 2], [3, 4]]`
+//! - bare lists: `1, 2, 3` or `1 2 3`, optionally in `[]` or `()`
+//! - matlab style rows separated by `;` or newlines: `1 2; 3 4`, `[1 2; 3 4]`
+//!
+//! a vector may be written as a single row or a single column. elements are
+//! parsed with the element type's `FromStr`.
+use std::array;
+use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
+
+/// why a string is not a vector or matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// the string holds no elements and no brackets
+    Empty,
+    /// the bracket at this byte offset is unmatched or nested more than two deep
+    Brackets { position: usize },
+    /// the element in row `row` and column `col` is not a number
+    InvalidNumber { row: usize, col: usize, text: String },
+    /// row `row` has a different number of elements than the first row
+    RaggedRows { row: usize, expected: usize, found: usize },
+    /// a vector with more than one row and more than one column
+    NotAVector { rows: usize, cols: usize },
+    /// a fixed size vector of the wrong length
+    WrongLength { expected: usize, found: usize },
+    /// a fixed size matrix of the wrong shape, as (rows, cols)
+    WrongShape { expected: (usize, usize), found: (usize, usize) },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "no elements to parse"),
+            ParseError::Brackets { position } => write!(f, "unbalanced or too deeply nested bracket at offset {}", position),
+            ParseError::InvalidNumber { row, col, text } => write!(f, "invalid number {:?} at ({}, {})", text, row, col),
+            ParseError::RaggedRows { row, expected, found } => {
+                write!(f, "row {} has {} elements, expected {}", row, found, expected)
+            }
+            ParseError::NotAVector { rows, cols } => write!(f, "expected a vector, found a {} x {} matrix", rows, cols),
+            ParseError::WrongLength { expected, found } => write!(f, "expected {} elements, found {}", expected, found),
+            ParseError::WrongShape { expected, found } => {
+                write!(f, "expected a {} x {} matrix, found {} x {}", expected.0, expected.1, found.0, found.1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// check brackets pair up and return the deepest nesting
+fn bracket_depth(s: &str) -> Result<usize, ParseError> {
+    let mut open: Vec<(char, usize)> = Vec::new();
+    let mut max = 0;
+    for (position, c) in s.char_indices() {
+        match c {
+            '[' | '(' => {
+                open.push((c, position));
+                if open.len() > 2 {
+                    return Err(ParseError::Brackets { position });
+                }
+                max = max.max(open.len());
+            }
+            ']' | ')' => match open.pop() {
+                Some(('[', _)) if c == ']' => {}
+                Some(('(', _)) if c == ')' => {}
+                _ => return Err(ParseError::Brackets { position }),
+            },
+            _ => {}
+        }
+    }
+    match open.first() {
+        Some(&(_, position)) => Err(ParseError::Brackets { position }),
+        None => Ok(max),
+    }
+}
+
+/// split into rows of element strings
+///
+/// a row ends at `;`, a newline, or the close of an innermost bracket.
+/// empty rows are dropped, so `[[1, 2],\n [3, 4]]` is two rows.
+fn split_rows(s: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+    let max_depth = bracket_depth(s)?;
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut start = None;
+    let mut depth = 0;
+
+    for (k, c) in s.char_indices().chain(std::iter::once((s.len(), '\n'))) {
+        let delimiter = c == ',' || c == ';' || c.is_whitespace() || "[]()".contains(c);
+        if !delimiter {
+            start.get_or_insert(k);
+            continue;
+        }
+        if let Some(begin) = start.take() {
+            row.push(&s[begin..k]);
+        }
+        let end_of_row = match c {
+            '[' | '(' => {
+                depth += 1;
+                false
+            }
+            ']' | ')' => {
+                depth -= 1;
+                depth + 1 == max_depth
+            }
+            ';' | '\n' => true,
+            _ => false,
+        };
+        if end_of_row && !row.is_empty() {
+            rows.push(std::mem::take(&mut row));
+        }
+    }
+    if rows.is_empty() && max_depth == 0 {
+        return Err(ParseError::Empty);
+    }
+    Ok(rows)
+}
+
+/// parse a matrix of any shape, returning rows, columns and the elements row by row
+pub fn parse_matrix<T: FromStr>(s: &str) -> Result<(usize, usize, Vec<T>), ParseError> {
+    let rows = split_rows(s)?;
+    let m = rows.first().map_or(0, |r| r.len());
+    if let Some((row, r)) = rows.iter().enumerate().find(|(_, r)| r.len() != m) {
+        return Err(ParseError::RaggedRows { row, expected: m, found: r.len() });
+    }
+    let mut data = Vec::with_capacity(rows.len() * m);
+    for (row, r) in rows.iter().enumerate() {
+        for (col, text) in r.iter().enumerate() {
+            let x = text.parse().map_err(|_| ParseError::InvalidNumber { row, col, text: text.to_string() })?;
+            data.push(x);
+        }
+    }
+    Ok((rows.len(), m, data))
+}
+
+/// parse a vector of any length, written as one row or one column
+pub fn parse_vector<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    let (n, m, data) = parse_matrix(s)?;
+    if n > 1 && m > 1 {
+        return Err(ParseError::NotAVector { rows: n, cols: m });
+    }
+    Ok(data)
+}
+
+/// parse a vector of exactly N elements
+pub fn parse_array<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], ParseError> {
+    let data: Vec<T> = parse_vector(s)?;
+    data.try_into().map_err(|data: Vec<T>| ParseError::WrongLength { expected: N, found: data.len() })
+}
+
+/// parse an N x N matrix into row arrays
+pub fn parse_square<T: FromStr, const N: usize>(s: &str) -> Result<[[T; N]; N], ParseError> {
+    let (n, m, data) = parse_matrix(s)?;
+    if (n, m) != (N, N) {
+        return Err(ParseError::WrongShape { expected: (N, N), found: (n, m) });
+    }
+    let mut elements = data.into_iter();
+    Ok(array::from_fn(|_| array::from_fn(|_| elements.next().unwrap())))
+}
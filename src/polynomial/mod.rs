@@ -0,0 +1,321 @@
+//! polynomials in one variable with coefficients stored in a `VectorN`
+//!
+//! coefficient i belongs to xⁱ, lowest degree first. trailing zero
+//! coefficients are always trimmed, so the zero polynomial has none.
+use std::ops::{Add,Sub,Mul,Div,Rem,Neg};
+use num::{Complex, Float, Num, NumCast};
+use crate::vector::vectorn::VectorN;
+use crate::matrix::matrixnxm::Matrix;
+
+pub mod roots;
+
+pub use roots::{solve_linear, solve_quadratic, solve_cubic, solve_quartic, RootsError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<T> {
+    coeffs: VectorN<T>,
+}
+
+impl<T: Copy + Num> Polynomial<T> {
+    /// get a polynomial from coefficients listed lowest degree first
+    pub fn new(coeffs: Vec<T>) -> Self {
+        let mut p = Polynomial { coeffs: VectorN::new(coeffs) };
+        p.trim();
+        p
+    }
+
+    pub fn zero() -> Self {
+        Polynomial { coeffs: VectorN::default() }
+    }
+
+    pub fn constant(c: T) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// the polynomial x
+    pub fn x() -> Self {
+        Self::new(vec![T::zero(), T::one()])
+    }
+
+    /// monic polynomial (x - r₀)(x - r₁)··· with the given roots
+    pub fn from_roots(roots: &[T]) -> Self {
+        roots.iter().fold(Self::constant(T::one()), |p, &r| &p * &Self::new(vec![T::zero() - r, T::one()]))
+    }
+
+    fn trim(&mut self) {
+        while self.coeffs.data.last().is_some_and(|c| c.is_zero()) {
+            self.coeffs.data.pop();
+        }
+    }
+
+    /// coefficients lowest degree first, empty for the zero polynomial
+    pub fn coeffs(&self) -> &VectorN<T> {
+        &self.coeffs
+    }
+
+    pub fn into_coeffs(self) -> VectorN<T> {
+        self.coeffs
+    }
+
+    /// coefficient of xⁱ, zero past the degree
+    pub fn coeff(&self, i: usize) -> T {
+        self.coeffs.data.get(i).copied().unwrap_or_else(T::zero)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// highest power with a nonzero coefficient, 0 for constants and the zero polynomial
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// coefficient of the highest power, zero for the zero polynomial
+    pub fn leading_coefficient(&self) -> T {
+        self.coeffs.data.last().copied().unwrap_or_else(T::zero)
+    }
+
+    /// evaluate by horner's scheme
+    pub fn eval(&self, x: T) -> T {
+        self.coeffs.data.iter().rev().fold(T::zero(), |acc, &c| acc * x + c)
+    }
+
+    /// p(x) and p'(x) in one horner pass
+    pub fn eval_with_derivative(&self, x: T) -> (T, T) {
+        let mut p = T::zero();
+        let mut dp = T::zero();
+        for &c in self.coeffs.data.iter().rev() {
+            dp = dp * x + p;
+            p = p * x + c;
+        }
+        (p, dp)
+    }
+
+    /// p(q(x)), by horner's scheme over polynomials
+    pub fn compose(&self, q: &Self) -> Self {
+        self.coeffs.data.iter().rev().fold(Self::zero(), |acc, &c| &(&acc * q) + &Self::constant(c))
+    }
+
+    /// quotient and remainder of self / d, None if d is zero
+    ///
+    /// the remainder has a lower degree than d. integer coefficients are
+    /// divided exactly or not at all: None if a quotient coefficient is not
+    /// an integer, e.g. for x² / 2x over i32.
+    pub fn checked_div_rem(&self, d: &Self) -> Option<(Self, Self)> {
+        if d.is_zero() {
+            return None;
+        }
+        // integer types are the ones where 1 / 2 truncates to zero
+        let truncating = (T::one() / (T::one() + T::one())).is_zero();
+        let n = d.coeffs.len();
+        let lead = d.leading_coefficient();
+        let mut r = self.coeffs.data.clone();
+        if r.len() < n {
+            return Some((Self::zero(), self.clone()));
+        }
+        let mut q = vec![T::zero(); r.len() - n + 1];
+        for k in (0..q.len()).rev() {
+            let c = r[k + n - 1] / lead;
+            q[k] = c;
+            for (j, &dj) in d.coeffs.data.iter().enumerate() {
+                r[k + j] = r[k + j] - c * dj;
+            }
+            if truncating {
+                if !r[k + n - 1].is_zero() {
+                    return None;
+                }
+            } else {
+                // cancelled exactly by construction, even when rounding says otherwise
+                r[k + n - 1] = T::zero();
+            }
+        }
+        Some((Self::new(q), Self::new(r)))
+    }
+
+    /// quotient and remainder of self / d, panics where `checked_div_rem` fails
+    pub fn div_rem(&self, d: &Self) -> (Self, Self) {
+        self.checked_div_rem(d).expect("polynomial division by zero or with a non-integer quotient")
+    }
+}
+
+impl<T: Copy + Num + NumCast> Polynomial<T> {
+    pub fn derivative(&self) -> Self {
+        let data = self.coeffs.data.iter().enumerate().skip(1)
+            .map(|(i, &c)| c * T::from(i).expect("power fits in the coefficient type"))
+            .collect();
+        Self::new(data)
+    }
+}
+
+impl<T: Float> Polynomial<T> {
+    /// antiderivative with constant term zero
+    ///
+    /// float coefficients only, the divisions by the powers would truncate
+    /// integer ones.
+    pub fn integral(&self) -> Self {
+        let mut data = Vec::with_capacity(self.coeffs.len() + 1);
+        data.push(T::zero());
+        for (i, &c) in self.coeffs.data.iter().enumerate() {
+            data.push(c / T::from(i + 1).expect("power fits in the coefficient type"));
+        }
+        Self::new(data)
+    }
+
+    /// evaluate at a complex point
+    pub fn eval_complex(&self, z: Complex<T>) -> Complex<T> {
+        self.coeffs.data.iter().rev().fold(Complex::new(T::zero(), T::zero()), |acc, &c| acc * z + c)
+    }
+
+    /// companion matrix of the monic polynomial, its eigenvalues are the roots
+    ///
+    /// upper hessenberg: the first row is -cₙ₋₁/cₙ ... -c₀/cₙ and ones sit
+    /// below the diagonal. panics for constants.
+    pub fn companion_matrix(&self) -> Matrix<T> {
+        let n = self.degree();
+        assert!(n > 0, "a constant has no companion matrix");
+        let lead = self.leading_coefficient();
+        let mut m = Matrix::zeros(n, n);
+        for j in 0..n {
+            m[(0, j)] = -self.coeffs[n - 1 - j] / lead;
+        }
+        for i in 1..n {
+            m[(i, i - 1)] = T::one();
+        }
+        m
+    }
+
+    /// all complex roots with multiplicity
+    ///
+    /// closed forms up to degree 2, above that the eigenvalues of the
+    /// balanced companion matrix by the shifted hessenberg QR algorithm,
+    /// polished with newton steps. the zero polynomial and constants have no
+    /// roots. fails if QR does not converge.
+    pub fn roots(&self) -> Result<Vec<Complex<T>>, RootsError> {
+        roots::complex_roots(self)
+    }
+
+    /// real roots sorted ascending, repeated roots listed once per multiplicity found
+    ///
+    /// degree ≤ 4 uses the closed forms, higher degrees keep the complex
+    /// roots whose imaginary part vanishes within rounding, and fail like
+    /// `roots` if QR does not converge.
+    pub fn real_roots(&self) -> Result<Vec<T>, RootsError> {
+        roots::real_roots(self)
+    }
+}
+
+impl<T: Copy + Num> From<VectorN<T>> for Polynomial<T> {
+    fn from(coeffs: VectorN<T>) -> Self {
+        Self::new(coeffs.data)
+    }
+}
+
+impl<T: Copy + Num> From<Polynomial<T>> for VectorN<T> {
+    fn from(p: Polynomial<T>) -> Self {
+        p.coeffs
+    }
+}
+
+impl<T: Copy + Num> Add for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Polynomial<T> {
+        let n = self.coeffs.len().max(rhs.coeffs.len());
+        Polynomial::new((0..n).map(|i| self.coeff(i) + rhs.coeff(i)).collect())
+    }
+}
+
+impl<T: Copy + Num> Sub for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Polynomial<T> {
+        let n = self.coeffs.len().max(rhs.coeffs.len());
+        Polynomial::new((0..n).map(|i| self.coeff(i) - rhs.coeff(i)).collect())
+    }
+}
+
+impl<T: Copy + Num> Mul for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Self) -> Polynomial<T> {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut data = vec![T::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.data.iter().enumerate() {
+            for (j, &b) in rhs.coeffs.data.iter().enumerate() {
+                data[i + j] = data[i + j] + a * b;
+            }
+        }
+        Polynomial::new(data)
+    }
+}
+
+impl<T: Copy + Num> Div for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    /// quotient, panics where `checked_div_rem` fails
+    fn div(self, rhs: Self) -> Polynomial<T> {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<T: Copy + Num> Rem for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    /// remainder, panics where `checked_div_rem` fails
+    fn rem(self, rhs: Self) -> Polynomial<T> {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<T: Copy + Num + Neg<Output=T>> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        Polynomial::new(self.coeffs.data.iter().map(|&c| -c).collect())
+    }
+}
+
+impl<T: Copy + Num> Mul<T> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: T) -> Polynomial<T> {
+        Polynomial::new(self.coeffs.data.iter().map(|&c| c * rhs).collect())
+    }
+}
+
+macro_rules! by_value {
+    ($tr:ident, $f:ident) => (
+        impl<T: Copy + Num> $tr for Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            fn $f(self, rhs: Self) -> Polynomial<T> {
+                (&self).$f(&rhs)
+            }
+        }
+    );
+}
+
+by_value!(Add, add);
+by_value!(Sub, sub);
+by_value!(Mul, mul);
+by_value!(Div, div);
+by_value!(Rem, rem);
+
+impl<T: Copy + Num> Mul<T> for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: T) -> Polynomial<T> {
+        &self * rhs
+    }
+}
+
+impl<T: Copy + Num + Neg<Output=T>> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        -&self
+    }
+}
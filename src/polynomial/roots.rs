@@ -0,0 +1,408 @@
+//! closed form solvers up to degree four and companion matrix roots above
+//!
+//! the closed forms take coefficients highest degree first, `a x² + b x + c`,
+//! return the real roots sorted ascending and polish each one with a few
+//! newton steps. a vanishing leading coefficient drops to the next lower degree.
+use std::fmt;
+use num::{Complex, Float, Zero};
+use crate::matrix::matrixnxm::Matrix;
+use super::Polynomial;
+
+const NEWTON_STEPS: usize = 4;
+/// QR steps allowed between two deflations
+const MAX_QR_ITER: usize = 60;
+
+/// why the roots of a polynomial could not be found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootsError {
+    /// QR on the companion matrix stopped converging after `found` roots
+    NoConvergence { found: usize },
+}
+
+impl fmt::Display for RootsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RootsError::NoConvergence { found } => write!(f, "QR iteration did not converge after {} roots", found),
+        }
+    }
+}
+
+impl std::error::Error for RootsError {}
+
+#[inline]
+fn lit<T: Float>(x: f64) -> T {
+    T::from(x).expect("constant fits in a float")
+}
+
+/// newton steps on a polynomial given highest degree first, kept only while they help
+fn polish<T: Float>(coeffs: &[T], mut x: T) -> T {
+    let eval = |x: T| {
+        let mut p = T::zero();
+        let mut dp = T::zero();
+        for &k in coeffs {
+            dp = dp * x + p;
+            p = p * x + k;
+        }
+        (p, dp)
+    };
+    let (mut p, _) = eval(x);
+    for _ in 0..NEWTON_STEPS {
+        let (_, dp) = eval(x);
+        if p == T::zero() || dp == T::zero() {
+            break;
+        }
+        let next = x - p / dp;
+        let (pn, _) = eval(next);
+        if pn.abs() >= p.abs() {
+            break;
+        }
+        x = next;
+        p = pn;
+    }
+    x
+}
+
+fn finish<T: Float>(coeffs: &[T], roots: Vec<T>) -> Vec<T> {
+    let mut roots: Vec<T> = roots.into_iter().map(|x| polish(coeffs, x)).collect();
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    roots
+}
+
+/// root of a x + b, none if a is zero
+pub fn solve_linear<T: Float>(a: T, b: T) -> Vec<T> {
+    if a == T::zero() {
+        Vec::new()
+    } else {
+        vec![-b / a]
+    }
+}
+
+/// real roots of a x² + b x + c
+///
+/// uses the cancellation free form q = -(b + sign(b)√Δ)/2, x = q/a, c/q. a
+/// discriminant that is negative only by rounding counts as a double root.
+pub fn solve_quadratic<T: Float>(a: T, b: T, c: T) -> Vec<T> {
+    if a == T::zero() {
+        return solve_linear(b, c);
+    }
+    let four = lit::<T>(4.0);
+    let mut disc = b * b - four * a * c;
+    if disc < T::zero() {
+        if disc >= -four * T::epsilon() * (b * b + (four * a * c).abs()) {
+            disc = T::zero();
+        } else {
+            return Vec::new();
+        }
+    }
+    let q = -(b + b.signum() * disc.sqrt()) / lit(2.0);
+    let roots = if q == T::zero() { vec![T::zero(), T::zero()] } else { vec![q / a, c / q] };
+    finish(&[a, b, c], roots)
+}
+
+/// real roots of a x³ + b x² + c x + d, repeated roots listed by multiplicity
+pub fn solve_cubic<T: Float>(a: T, b: T, c: T, d: T) -> Vec<T> {
+    if a == T::zero() {
+        return solve_quadratic(b, c, d);
+    }
+    let (p, q, r) = (b / a, c / a, d / a);
+    let third = p / lit(3.0);
+    let qq = (p * p - lit::<T>(3.0) * q) / lit(9.0);
+    let rr = (lit::<T>(2.0) * p * p * p - lit::<T>(9.0) * p * q + lit::<T>(27.0) * r)
+        / lit(54.0);
+    let q3 = qq * qq * qq;
+    let r2 = rr * rr;
+
+    let roots = if r2 < q3 {
+        // three distinct real roots, trigonometric form
+        let theta = (rr / q3.sqrt()).max(-T::one()).min(T::one()).acos();
+        let m = lit::<T>(-2.0) * qq.sqrt();
+        let tau = lit::<T>(2.0 * std::f64::consts::PI);
+        let three = lit::<T>(3.0);
+        vec![
+            m * (theta / three).cos() - third,
+            m * ((theta + tau) / three).cos() - third,
+            m * ((theta - tau) / three).cos() - third,
+        ]
+    } else {
+        // cardano, one real root and a complex pair
+        let big_a = -rr.signum() * (rr.abs() + (r2 - q3).sqrt()).cbrt();
+        let big_b = if big_a == T::zero() { T::zero() } else { qq / big_a };
+        let x = big_a + big_b - third;
+        if big_a == T::zero() {
+            vec![x, x, x]
+        } else if (big_a - big_b).abs() <= lit::<T>(4.0) * T::epsilon().sqrt() * big_a.abs() {
+            // the pair's imaginary part √3/2 (A - B) vanished: a double root
+            let y = -(big_a + big_b) / lit(2.0) - third;
+            vec![x, y, y]
+        } else {
+            vec![x]
+        }
+    };
+    finish(&[a, b, c, d], roots)
+}
+
+/// real roots of a x⁴ + b x³ + c x² + d x + e by ferrari's resolvent cubic
+pub fn solve_quartic<T: Float>(a: T, b: T, c: T, d: T, e: T) -> Vec<T> {
+    if a == T::zero() {
+        return solve_cubic(b, c, d, e);
+    }
+    let (bb, cc, dd, ee) = (b / a, c / a, d / a, e / a);
+    // depressed quartic y⁴ + p y² + q y + r with x = y - b/4
+    let shift = bb / lit::<T>(4.0);
+    let b2 = bb * bb;
+    let p = cc - lit::<T>(3.0) * b2 / lit::<T>(8.0);
+    let q = dd - bb * cc / lit::<T>(2.0) + b2 * bb / lit::<T>(8.0);
+    let r = ee - bb * dd / lit::<T>(4.0) + b2 * cc / lit::<T>(16.0) - lit::<T>(3.0) * b2 * b2 / lit::<T>(256.0);
+
+    let scale = T::one() + p.abs() + r.abs().sqrt() + b2;
+    let mut ys = Vec::new();
+    if q.abs() <= lit::<T>(16.0) * T::epsilon() * scale {
+        // biquadratic, z = y²
+        for z in solve_quadratic(T::one(), p, r) {
+            if z > T::zero() {
+                ys.push(z.sqrt());
+                ys.push(-z.sqrt());
+            } else if z >= -lit::<T>(16.0) * T::epsilon() * scale {
+                ys.push(T::zero());
+                ys.push(T::zero());
+            }
+        }
+    } else {
+        // largest root of m³ + p m² + (p²/4 - r) m - q²/8 is positive
+        let m = solve_cubic(T::one(), p, p * p / lit::<T>(4.0) - r, -q * q / lit::<T>(8.0))
+            .into_iter()
+            .fold(T::neg_infinity(), T::max);
+        if m > T::zero() {
+            let s = (lit::<T>(2.0) * m).sqrt();
+            let t = q / (lit::<T>(2.0) * s);
+            ys.extend(solve_quadratic(T::one(), -s, p / lit::<T>(2.0) + m + t));
+            ys.extend(solve_quadratic(T::one(), s, p / lit::<T>(2.0) + m - t));
+        }
+    }
+    let roots = ys.into_iter().map(|y| y - shift).collect();
+    finish(&[a, b, c, d, e], roots)
+}
+
+pub(crate) fn real_roots<T: Float>(poly: &Polynomial<T>) -> Result<Vec<T>, RootsError> {
+    let p = |i| poly.coeff(i);
+    Ok(match poly.degree() {
+        _ if poly.is_zero() => Vec::new(),
+        0 => Vec::new(),
+        1 => solve_linear(p(1), p(0)),
+        2 => solve_quadratic(p(2), p(1), p(0)),
+        3 => solve_cubic(p(3), p(2), p(1), p(0)),
+        4 => solve_quartic(p(4), p(3), p(2), p(1), p(0)),
+        _ => {
+            let tol = lit::<T>(8.0) * T::epsilon().sqrt();
+            let highest: Vec<T> = poly.coeffs().iter().rev().copied().collect();
+            let roots = complex_roots(poly)?
+                .into_iter()
+                .filter(|z| z.im.abs() <= tol * T::one().max(z.norm()))
+                .map(|z| z.re)
+                .collect();
+            finish(&highest, roots)
+        }
+    })
+}
+
+pub(crate) fn complex_roots<T: Float>(poly: &Polynomial<T>) -> Result<Vec<Complex<T>>, RootsError> {
+    if poly.degree() == 0 {
+        return Ok(Vec::new());
+    }
+    // roots at zero are exact, factor them out first
+    let zeros = poly.coeffs().iter().take_while(|c| c.is_zero()).count();
+    let mut roots = vec![Complex::zero(); zeros];
+    let reduced = Polynomial::new(poly.coeffs().as_slice()[zeros..].to_vec());
+    let n = reduced.degree();
+    let p = |i| reduced.coeff(i);
+
+    match n {
+        0 => {}
+        1 => roots.push(Complex::new(-p(0) / p(1), T::zero())),
+        2 => {
+            let (a, b, cc) = (p(2), p(1), p(0));
+            let disc = Complex::new(b * b - lit::<T>(4.0) * a * cc, T::zero()).sqrt();
+            let q = if b >= T::zero() { (disc + b) * lit::<T>(-0.5) } else { (disc - b) * lit::<T>(0.5) };
+            roots.push(q / a);
+            roots.push(Complex::new(cc, T::zero()) / q);
+        }
+        _ => {
+            let mut a = reduced.companion_matrix();
+            balance(&mut a);
+            for z in hessenberg_eigenvalues(a)? {
+                roots.push(polish_complex(&reduced, z));
+            }
+        }
+    }
+    Ok(roots)
+}
+
+fn polish_complex<T: Float>(poly: &Polynomial<T>, mut z: Complex<T>) -> Complex<T> {
+    if z.re.is_nan() {
+        return z;
+    }
+    let d = poly.derivative();
+    let mut pz = poly.eval_complex(z);
+    for _ in 0..NEWTON_STEPS {
+        let dz = d.eval_complex(z);
+        if pz.is_zero() || dz.is_zero() {
+            break;
+        }
+        let next = z - pz / dz;
+        let pn = poly.eval_complex(next);
+        if pn.norm() >= pz.norm() {
+            break;
+        }
+        z = next;
+        pz = pn;
+    }
+    z
+}
+
+/// scale rows and columns by powers of two until every row and its column
+/// have comparable norms, a similarity transform that leaves the eigenvalues
+/// alone but makes QR more accurate (parlett and reinsch, "balancing a
+/// matrix for calculation of eigenvalues and eigenvectors", 1969)
+fn balance<T: Float>(a: &mut Matrix<T>) {
+    let n = a.n;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..n {
+            let (mut c, mut r) = (T::zero(), T::zero());
+            for j in (0..n).filter(|&j| j != i) {
+                c = c + a[(j, i)].abs();
+                r = r + a[(i, j)].abs();
+            }
+            if c.is_zero() || r.is_zero() {
+                continue;
+            }
+            // c f + r / f is smallest near f = √(r / c), rounded to a power of two
+            let f = lit::<T>(2.0).powi(((r / c).log2() / lit(2.0)).round().to_i32().unwrap_or(0));
+            if f == T::one() || !f.is_normal() || c * f + r / f >= lit::<T>(0.9) * (c + r) {
+                continue;
+            }
+            changed = true;
+            for j in 0..n {
+                a[(i, j)] = a[(i, j)] / f;
+                a[(j, i)] = a[(j, i)] * f;
+            }
+        }
+    }
+}
+
+/// householder reflector I - 2 v vᵀ / vᵀv taking u to a multiple of e₁,
+/// applied to rows and columns k.. of the active block lo..hi of h
+fn reflect<T: Float>(h: &mut Matrix<T>, k: usize, u: &[T], lo: usize, hi: usize) {
+    let norm = u.iter().fold(T::zero(), |acc, x| acc.hypot(*x));
+    if norm.is_zero() {
+        return;
+    }
+    let mut v = [T::zero(); 3];
+    let v = &mut v[..u.len()];
+    v.copy_from_slice(u);
+    // subtract on the side that avoids cancellation
+    v[0] = v[0] + if u[0] < T::zero() { -norm } else { norm };
+    let vv = v.iter().fold(T::zero(), |acc, x| acc + *x * *x);
+    let beta = lit::<T>(2.0) / vv;
+
+    for j in k.saturating_sub(1).max(lo)..hi {
+        let d = v.iter().enumerate().fold(T::zero(), |acc, (t, x)| acc + *x * h[(k + t, j)]) * beta;
+        for (t, x) in v.iter().enumerate() {
+            h[(k + t, j)] = h[(k + t, j)] - d * *x;
+        }
+    }
+    for i in lo..hi.min(k + v.len() + 1) {
+        let d = v.iter().enumerate().fold(T::zero(), |acc, (t, x)| acc + *x * h[(i, k + t)]) * beta;
+        for (t, x) in v.iter().enumerate() {
+            h[(i, k + t)] = h[(i, k + t)] - d * *x;
+        }
+    }
+}
+
+/// eigenvalues of the 2 x 2 block [a b; c d]
+fn eigenvalues2<T: Float>(a: T, b: T, c: T, d: T) -> [Complex<T>; 2] {
+    let p = (a - d) / lit(2.0);
+    let disc = p * p + b * c;
+    if disc < T::zero() {
+        let im = (-disc).sqrt();
+        return [Complex::new(d + p, im), Complex::new(d + p, -im)];
+    }
+    // d + p ± √disc, the smaller one through the product to avoid cancellation
+    let z = p + disc.sqrt().copysign(p);
+    let small = if z.is_zero() { d } else { d - b * c / z };
+    [Complex::new(d + z, T::zero()), Complex::new(small, T::zero())]
+}
+
+/// eigenvalues of an upper hessenberg matrix by francis double shift QR
+/// steps, following golub and van loan, "matrix computations", algorithm
+/// 7.5.1, with deflation of negligible subdiagonal elements
+fn hessenberg_eigenvalues<T: Float>(mut h: Matrix<T>) -> Result<Vec<Complex<T>>, RootsError> {
+    let n = h.n;
+    let scale = (0..n * n).fold(T::zero(), |acc, k| acc + h[(k / n, k % n)].abs());
+    let mut eigenvalues = Vec::with_capacity(n);
+    // the rows and columns lo..hi are still being iterated on
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let mut s = h[(lo - 1, lo - 1)].abs() + h[(lo, lo)].abs();
+            if s.is_zero() {
+                s = scale;
+            }
+            if h[(lo, lo - 1)].abs() <= T::epsilon() * s {
+                h[(lo, lo - 1)] = T::zero();
+                break;
+            }
+            lo -= 1;
+        }
+
+        if hi - lo <= 2 {
+            if hi - lo == 1 {
+                eigenvalues.push(Complex::new(h[(lo, lo)], T::zero()));
+            } else {
+                eigenvalues.extend(eigenvalues2(h[(lo, lo)], h[(lo, lo + 1)], h[(lo + 1, lo)], h[(lo + 1, lo + 1)]));
+            }
+            hi = lo;
+            iterations = 0;
+            continue;
+        }
+        if iterations == MAX_QR_ITER {
+            return Err(RootsError::NoConvergence { found: eigenvalues.len() });
+        }
+        iterations += 1;
+
+        // the shifts are the eigenvalues of the trailing 2 x 2 block, passed
+        // as their sum s and product t so the step stays real
+        let (p, q) = (hi - 2, hi - 1);
+        let (mut s, mut t) = (h[(p, p)] + h[(q, q)], h[(p, p)] * h[(q, q)] - h[(p, q)] * h[(q, p)]);
+        if iterations % 8 == 0 {
+            // a made up double shift breaks the rare cycles of the standard one
+            let mu = h[(q, q)] + h[(q, p)].abs() + h[(p, p - 1)].abs();
+            s = mu + mu;
+            t = mu * mu;
+        }
+
+        // first column of (H - μ₁)(H - μ₂), then chase the bulge down
+        let (h00, h01, h10) = (h[(lo, lo)], h[(lo, lo + 1)], h[(lo + 1, lo)]);
+        let mut x = h00 * h00 + h01 * h10 - s * h00 + t;
+        let mut y = h10 * (h00 + h[(lo + 1, lo + 1)] - s);
+        let mut z = h10 * h[(lo + 2, lo + 1)];
+        for k in lo..hi - 2 {
+            reflect(&mut h, k, &[x, y, z], lo, hi);
+            if k > lo {
+                h[(k + 1, k - 1)] = T::zero();
+                h[(k + 2, k - 1)] = T::zero();
+            }
+            x = h[(k + 1, k)];
+            y = h[(k + 2, k)];
+            if k + 3 < hi {
+                z = h[(k + 3, k)];
+            }
+        }
+        reflect(&mut h, hi - 2, &[x, y], lo, hi);
+        h[(hi - 1, hi - 3)] = T::zero();
+    }
+    Ok(eigenvalues)
+}
@@ -0,0 +1,119 @@
+//! uniform points in and on simple shapes, unit vectors and rotations
+use std::f32::consts::PI;
+use rand::Rng;
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::matrix::Matrix3x3;
+
+/// point uniform on the unit circle
+pub fn on_unit_circle<R: Rng + ?Sized>(rng: &mut R) -> Vector2 {
+    let theta = rng.gen_range(0.0..2.0 * PI);
+    Vector2::new(theta.cos(), theta.sin())
+}
+
+/// point uniform in the unit disk
+pub fn in_unit_disk<R: Rng + ?Sized>(rng: &mut R) -> Vector2 {
+    // area grows with r², so r = √u keeps the density flat
+    let r = rng.gen::<f32>().sqrt();
+    on_unit_circle(rng) * r
+}
+
+/// unit vector uniform on the unit sphere
+pub fn unit_vector3<R: Rng + ?Sized>(rng: &mut R) -> Vector3 {
+    // archimedes: z is uniform on [-1, 1] for a uniform point on the sphere
+    let z = rng.gen_range(-1.0f32..=1.0);
+    let theta = rng.gen_range(0.0..2.0 * PI);
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    Vector3::new(r * theta.cos(), r * theta.sin(), z)
+}
+
+/// point uniform on the unit sphere, same as `unit_vector3`
+pub fn on_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vector3 {
+    unit_vector3(rng)
+}
+
+/// point uniform in the unit ball
+pub fn in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vector3 {
+    let r = rng.gen::<f32>().cbrt();
+    unit_vector3(rng) * r
+}
+
+/// barycentric weights uniform over a triangle
+fn barycentric<R: Rng + ?Sized>(rng: &mut R) -> (f32, f32, f32) {
+    let s = rng.gen::<f32>().sqrt();
+    let t = rng.gen::<f32>();
+    (1.0 - s, s * (1.0 - t), s * t)
+}
+
+/// point uniform in the triangle abc
+pub fn in_triangle<R: Rng + ?Sized>(rng: &mut R, a: Vector3, b: Vector3, c: Vector3) -> Vector3 {
+    let (u, v, w) = barycentric(rng);
+    a * u + b * v + c * w
+}
+
+/// point uniform in the 2d triangle abc
+pub fn in_triangle2<R: Rng + ?Sized>(rng: &mut R, a: Vector2, b: Vector2, c: Vector2) -> Vector2 {
+    let (u, v, w) = barycentric(rng);
+    a * u + b * v + c * w
+}
+
+fn lerp<R: Rng + ?Sized>(rng: &mut R, lo: f32, hi: f32) -> f32 {
+    lo + (hi - lo) * rng.gen::<f32>()
+}
+
+/// point uniform in the axis aligned box from min to max
+pub fn in_aabb<R: Rng + ?Sized>(rng: &mut R, min: Vector3, max: Vector3) -> Vector3 {
+    Vector3::new(lerp(rng, min.x, max.x), lerp(rng, min.y, max.y), lerp(rng, min.z, max.z))
+}
+
+/// point uniform in the axis aligned rectangle from min to max
+pub fn in_aabb2<R: Rng + ?Sized>(rng: &mut R, min: Vector2, max: Vector2) -> Vector2 {
+    Vector2::new(lerp(rng, min.x, max.x), lerp(rng, min.y, max.y))
+}
+
+/// point uniform on the surface of the axis aligned box from min to max
+pub fn on_aabb<R: Rng + ?Sized>(rng: &mut R, min: Vector3, max: Vector3) -> Vector3 {
+    let d = max - min;
+    // faces come in pairs normal to x, y and z, pick one by area
+    let areas = [d.y * d.z, d.x * d.z, d.x * d.y];
+    let total = areas[0] + areas[1] + areas[2];
+    let mut p = in_aabb(rng, min, max);
+    if total <= 0.0 {
+        return p;
+    }
+    let mut pick = rng.gen::<f32>() * total;
+    let mut axis = 2;
+    for (i, a) in areas.iter().enumerate() {
+        if pick < *a {
+            axis = i;
+            break;
+        }
+        pick -= a;
+    }
+    p[axis] = if rng.gen::<bool>() { max[axis] } else { min[axis] };
+    p
+}
+
+/// unit quaternion uniform over rotations, stored as (x, y, z, w) with w the scalar part
+///
+/// Shoemake's subgroup algorithm.
+pub fn rotation_quaternion<R: Rng + ?Sized>(rng: &mut R) -> Vector4 {
+    let u1 = rng.gen::<f32>();
+    let u2 = rng.gen_range(0.0..2.0 * PI);
+    let u3 = rng.gen_range(0.0..2.0 * PI);
+    let a = (1.0 - u1).sqrt();
+    let b = u1.sqrt();
+    Vector4::new(a * u2.sin(), a * u2.cos(), b * u3.sin(), b * u3.cos())
+}
+
+/// rotation matrix uniform over rotations, `m * v` rotates v
+pub fn rotation_matrix3<R: Rng + ?Sized>(rng: &mut R) -> Matrix3x3 {
+    let q = rotation_quaternion(rng);
+    let (x, y, z, w) = (q.x, q.y, q.z, q.w);
+    Matrix3x3 {
+        data: [
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w)],
+            [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w)],
+            [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y)],
+        ],
+    }
+}
@@ -0,0 +1,107 @@
+//! random graphs for property tests
+//!
+//! nodes are labelled "0" to "n-1" and hold `node(i)`. undirected edges are
+//! labelled "a-b" with a < b.
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::combinatorics::graph::{DGraph, Graph};
+
+fn with_nodes<T, F: FnMut(usize) -> T>(n: usize, mut node: F) -> Graph<T> {
+    let mut g = Graph::new();
+    for i in 0..n {
+        g.add_node(i.to_string(), node(i));
+    }
+    g
+}
+
+fn link<T>(g: &mut Graph<T>, a: usize, b: usize) {
+    let (a, b) = if a < b { (a, b) } else { (b, a) };
+    g.connect(format!("{}-{}", a, b), a.to_string(), b.to_string())
+        .expect("both nodes were added first");
+}
+
+/// Erdős–Rényi G(n, p): every one of the n(n-1)/2 edges is present with probability p
+///
+/// panics if p is not in [0, 1].
+pub fn erdos_renyi<T, F, R>(rng: &mut R, n: usize, p: f64, node: F) -> Graph<T>
+where
+    F: FnMut(usize) -> T,
+    R: Rng + ?Sized,
+{
+    assert!((0.0..=1.0).contains(&p), "erdos_renyi needs p in [0, 1], got {}", p);
+    let mut g = with_nodes(n, node);
+    for a in 0..n {
+        for b in a + 1..n {
+            if rng.gen_bool(p) {
+                link(&mut g, a, b);
+            }
+        }
+    }
+    g
+}
+
+/// Barabási–Albert preferential attachment
+///
+/// starts from a complete graph on m + 1 nodes, then every further node
+/// connects to m distinct earlier nodes picked with probability proportional
+/// to their degree. panics if m is zero.
+pub fn barabasi_albert<T, F, R>(rng: &mut R, n: usize, m: usize, node: F) -> Graph<T>
+where
+    F: FnMut(usize) -> T,
+    R: Rng + ?Sized,
+{
+    assert!(m > 0, "barabasi_albert needs m > 0");
+    let mut g = with_nodes(n, node);
+    let seed = n.min(m + 1);
+    // every node appears once per edge end, so a uniform pick is degree weighted
+    let mut ends = Vec::new();
+    for a in 0..seed {
+        for b in a + 1..seed {
+            link(&mut g, a, b);
+            ends.push(a);
+            ends.push(b);
+        }
+    }
+    for v in seed..n {
+        let mut targets: Vec<usize> = Vec::with_capacity(m);
+        while targets.len() < m {
+            let t = *ends.choose(rng).expect("seed graph has edges");
+            if !targets.contains(&t) {
+                targets.push(t);
+            }
+        }
+        for t in targets {
+            link(&mut g, v, t);
+            ends.push(v);
+            ends.push(t);
+        }
+    }
+    g
+}
+
+/// random directed acyclic graph
+///
+/// the nodes are put in a random order and every edge pointing forward in
+/// that order is present with probability p. panics if p is not in [0, 1].
+pub fn random_dag<T, F, R>(rng: &mut R, n: usize, p: f64, mut node: F) -> DGraph<T>
+where
+    F: FnMut(usize) -> T,
+    R: Rng + ?Sized,
+{
+    assert!((0.0..=1.0).contains(&p), "random_dag needs p in [0, 1], got {}", p);
+    let mut g = DGraph::new();
+    for i in 0..n {
+        g.add_node(i.to_string(), node(i));
+    }
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+    for i in 0..n {
+        for j in i + 1..n {
+            if rng.gen_bool(p) {
+                g.connect(order[i].to_string(), order[j].to_string())
+                    .expect("both nodes were added first");
+            }
+        }
+    }
+    g
+}
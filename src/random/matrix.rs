@@ -0,0 +1,39 @@
+//! matrices and vectors with elements drawn from a distribution
+//!
+//! pass any `rand::distributions::Distribution`, e.g. `Uniform::new(-1.0, 1.0)`
+//! or a normal distribution from `rand_distr`.
+use rand::Rng;
+use rand::distributions::Distribution;
+use crate::matrix::{Matrix2x2, Matrix3x3, Matrix4x4};
+use crate::matrix::matrixnxm::Matrix;
+use crate::vector::vectorn::VectorN;
+
+/// n x m matrix with independent elements from dist
+pub fn matrix<T, D: Distribution<T>, R: Rng + ?Sized>(rng: &mut R, n: usize, m: usize, dist: &D) -> Matrix<T> {
+    let data: Vec<T> = (0..n * m).map(|_| dist.sample(rng)).collect();
+    Matrix { n, m, data: data.into_boxed_slice() }
+}
+
+/// vector of length n with independent elements from dist
+pub fn vectorn<T, D: Distribution<T>, R: Rng + ?Sized>(rng: &mut R, n: usize, dist: &D) -> VectorN<T> {
+    VectorN::from_fn(n, |_| dist.sample(rng))
+}
+
+macro_rules! random_fixed_matrix {
+    ($fn:ident, $name:ident, $n:expr) => (
+        /// matrix with independent elements from dist, drawn row by row
+        pub fn $fn<D: Distribution<f32>, R: Rng + ?Sized>(rng: &mut R, dist: &D) -> $name {
+            let mut data = [[0.0; $n]; $n];
+            for row in data.iter_mut() {
+                for e in row.iter_mut() {
+                    *e = dist.sample(rng);
+                }
+            }
+            $name { data }
+        }
+    );
+}
+
+random_fixed_matrix!(matrix2x2, Matrix2x2, 2);
+random_fixed_matrix!(matrix3x3, Matrix3x3, 3);
+random_fixed_matrix!(matrix4x4, Matrix4x4, 4);
@@ -0,0 +1,16 @@
+//! random rmu values, enabled by the `rand` feature
+//!
+//! every function takes the generator as `&mut R` and draws nothing else, so
+//! a generator seeded with `SeedableRng::seed_from_u64` gives the same values
+//! on every run. `StdRng` may change its algorithm between rand releases; use
+//! a named generator such as `rand_chacha::ChaCha8Rng` when the values have to
+//! stay fixed across dependency upgrades too.
+pub mod geometry;
+pub mod matrix;
+pub mod graph;
+
+pub use rand;
+
+pub use geometry::*;
+pub use matrix::*;
+pub use graph::*;
@@ -1,4 +1,4 @@
-/// The raw math type alias
+//! The raw math type alias
 
 /// R² 32bit float vector
 pub type Vec2f = [f32;2];
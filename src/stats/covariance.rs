@@ -0,0 +1,128 @@
+//! covariance and correlation matrices of vector samples
+//!
+//! every function uses the unbiased sample covariance (dividing by n - 1),
+//! so at least two samples are needed.
+use std::fmt;
+use num::Float;
+use crate::matrix::Matrix3x3;
+use crate::matrix::matrixnxm::Matrix;
+use crate::matrix::square::SquareMatrix;
+use crate::vector::Vector3;
+use crate::vector::vectorn::{LengthMismatch, VectorN};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatsError {
+    /// fewer samples than the statistic needs
+    NotEnoughSamples { needed: usize, found: usize },
+    /// samples of different dimensions
+    LengthMismatch(LengthMismatch),
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatsError::NotEnoughSamples { needed, found } => write!(f, "needs at least {} samples, found {}", needed, found),
+            StatsError::LengthMismatch(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for StatsError {}
+
+impl From<LengthMismatch> for StatsError {
+    fn from(e: LengthMismatch) -> Self {
+        StatsError::LengthMismatch(e)
+    }
+}
+
+/// mean of the samples as a vector of the matrix type, checking the sample count
+pub(crate) fn mean_of<M: SquareMatrix>(zero: &M, samples: &[M::Vector]) -> Result<M::Vector, StatsError> {
+    if samples.len() < 2 {
+        return Err(StatsError::NotEnoughSamples { needed: 2, found: samples.len() });
+    }
+    let d = zero.dim();
+    let mut mean = zero.zero_vector();
+    for s in samples {
+        let len = M::vector_dim(s);
+        if len != d {
+            return Err(LengthMismatch { left: d, right: len }.into());
+        }
+        for i in 0..d {
+            let m = M::vector_get(&mean, i) + M::vector_get(s, i);
+            M::vector_set(&mut mean, i, m);
+        }
+    }
+    let n: M::Scalar = num::cast(samples.len()).unwrap_or_else(M::Scalar::nan);
+    for i in 0..d {
+        let m = M::vector_get(&mean, i) / n;
+        M::vector_set(&mut mean, i, m);
+    }
+    Ok(mean)
+}
+
+/// sample covariance around a known mean
+pub(crate) fn covariance_of<M: SquareMatrix>(zero: &M, samples: &[M::Vector], mean: &M::Vector) -> M {
+    let d = zero.dim();
+    let mut cov = zero.zeros_like();
+    for s in samples {
+        for i in 0..d {
+            let di = M::vector_get(s, i) - M::vector_get(mean, i);
+            for j in 0..=i {
+                let dj = M::vector_get(s, j) - M::vector_get(mean, j);
+                cov.set(i, j, cov.get(i, j) + di * dj);
+            }
+        }
+    }
+    let n: M::Scalar = num::cast(samples.len() - 1).unwrap_or_else(M::Scalar::nan);
+    for i in 0..d {
+        for j in 0..=i {
+            let c = cov.get(i, j) / n;
+            cov.set(i, j, c);
+            cov.set(j, i, c);
+        }
+    }
+    cov
+}
+
+/// normalize a covariance matrix to correlations, components without variance give NaN
+fn correlation_of<M: SquareMatrix>(mut cov: M) -> M {
+    let d = cov.dim();
+    let sd: Vec<M::Scalar> = (0..d).map(|i| cov.get(i, i).sqrt()).collect();
+    for i in 0..d {
+        for j in 0..d {
+            cov.set(i, j, cov.get(i, j) / (sd[i] * sd[j]));
+        }
+    }
+    cov
+}
+
+/// component-wise mean of `VectorN` samples of equal length
+pub fn mean_vector<T: Float>(samples: &[VectorN<T>]) -> Result<VectorN<T>, StatsError> {
+    let d = samples.first().map_or(0, |s| s.len());
+    mean_of(&Matrix::<T>::zeros(d, d), samples)
+}
+
+/// 3 x 3 sample covariance of `Vector3` samples
+pub fn covariance_matrix3(samples: &[Vector3]) -> Result<Matrix3x3, StatsError> {
+    let zero = Matrix3x3::default().zeros_like();
+    let mean = mean_of(&zero, samples)?;
+    Ok(covariance_of(&zero, samples, &mean))
+}
+
+/// 3 x 3 correlation matrix of `Vector3` samples
+pub fn correlation_matrix3(samples: &[Vector3]) -> Result<Matrix3x3, StatsError> {
+    covariance_matrix3(samples).map(correlation_of)
+}
+
+/// d x d sample covariance of `VectorN` samples of length d
+pub fn covariance_matrix<T: Float>(samples: &[VectorN<T>]) -> Result<Matrix<T>, StatsError> {
+    let d = samples.first().map_or(0, |s| s.len());
+    let zero = Matrix::zeros(d, d);
+    let mean = mean_of(&zero, samples)?;
+    Ok(covariance_of(&zero, samples, &mean))
+}
+
+/// d x d correlation matrix of `VectorN` samples of length d
+pub fn correlation_matrix<T: Float>(samples: &[VectorN<T>]) -> Result<Matrix<T>, StatsError> {
+    covariance_matrix(samples).map(correlation_of)
+}
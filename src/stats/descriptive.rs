@@ -0,0 +1,176 @@
+//! descriptive statistics of a slice of samples
+//!
+//! an empty slice (or a single sample for the sample variance) has no
+//! statistic and gives `None`.
+use std::cmp::Ordering;
+use num::Float;
+
+/// arithmetic mean
+pub fn mean<T: Float>(data: &[T]) -> Option<T> {
+    let mut w = Welford::new();
+    w.extend(data.iter().copied());
+    w.mean()
+}
+
+/// variance dividing by n
+pub fn population_variance<T: Float>(data: &[T]) -> Option<T> {
+    let mut w = Welford::new();
+    w.extend(data.iter().copied());
+    w.population_variance()
+}
+
+/// unbiased variance dividing by n - 1
+pub fn sample_variance<T: Float>(data: &[T]) -> Option<T> {
+    let mut w = Welford::new();
+    w.extend(data.iter().copied());
+    w.sample_variance()
+}
+
+/// square root of the population variance
+pub fn population_std_dev<T: Float>(data: &[T]) -> Option<T> {
+    population_variance(data).map(T::sqrt)
+}
+
+/// square root of the sample variance
+pub fn sample_std_dev<T: Float>(data: &[T]) -> Option<T> {
+    sample_variance(data).map(T::sqrt)
+}
+
+/// smallest sample, NaN samples are skipped
+pub fn min<T: Float>(data: &[T]) -> Option<T> {
+    min_max(data).map(|(lo, _)| lo)
+}
+
+/// largest sample, NaN samples are skipped
+pub fn max<T: Float>(data: &[T]) -> Option<T> {
+    min_max(data).map(|(_, hi)| hi)
+}
+
+/// smallest and largest sample in one pass, NaN samples are skipped
+pub fn min_max<T: Float>(data: &[T]) -> Option<(T, T)> {
+    data.iter()
+        .filter(|x| !x.is_nan())
+        .fold(None, |acc, &x| match acc {
+            None => Some((x, x)),
+            Some((lo, hi)) => Some((lo.min(x), hi.max(x))),
+        })
+}
+
+fn sorted<T: Float>(data: &[T]) -> Vec<T> {
+    let mut s: Vec<T> = data.iter().copied().filter(|x| !x.is_nan()).collect();
+    s.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    s
+}
+
+/// quantile of already sorted data by linear interpolation between closest ranks
+fn sorted_quantile<T: Float>(s: &[T], q: T) -> Option<T> {
+    if s.is_empty() || !(q >= T::zero() && q <= T::one()) {
+        return None;
+    }
+    let h = q * T::from(s.len() - 1)?;
+    let lo = h.floor();
+    let i = lo.to_usize()?;
+    if i + 1 >= s.len() {
+        return Some(s[s.len() - 1]);
+    }
+    Some(s[i] + (h - lo) * (s[i + 1] - s[i]))
+}
+
+/// middle value, the mean of the two middle values for an even count
+pub fn median<T: Float>(data: &[T]) -> Option<T> {
+    quantile(data, T::from(0.5)?)
+}
+
+/// q-th quantile for q in [0, 1], interpolating linearly between samples
+///
+/// NaN samples are ignored, q outside [0, 1] gives `None`.
+pub fn quantile<T: Float>(data: &[T], q: T) -> Option<T> {
+    sorted_quantile(&sorted(data), q)
+}
+
+/// several quantiles sorting the data only once
+pub fn quantiles<T: Float>(data: &[T], qs: &[T]) -> Option<Vec<T>> {
+    let s = sorted(data);
+    qs.iter().map(|&q| sorted_quantile(&s, q)).collect()
+}
+
+/// streaming mean and variance by Welford's algorithm
+///
+/// numerically stable in one pass, and two accumulators over separate
+/// chunks can be merged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Welford<T> {
+    count: usize,
+    mean: T,
+    m2: T,
+}
+
+impl<T: Float> Welford<T> {
+    pub fn new() -> Self {
+        Welford { count: 0, mean: T::zero(), m2: T::zero() }
+    }
+
+    pub fn push(&mut self, x: T) {
+        self.count += 1;
+        let n = T::from(self.count).unwrap_or_else(T::nan);
+        let delta = x - self.mean;
+        self.mean = self.mean + delta / n;
+        self.m2 = self.m2 + delta * (x - self.mean);
+    }
+
+    /// combine with an accumulator over other samples
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let na = T::from(self.count).unwrap_or_else(T::nan);
+        let nb = T::from(other.count).unwrap_or_else(T::nan);
+        let n = na + nb;
+        let delta = other.mean - self.mean;
+        self.mean = self.mean + delta * nb / n;
+        self.m2 = self.m2 + other.m2 + delta * delta * na * nb / n;
+        self.count += other.count;
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<T> {
+        if self.count == 0 { None } else { Some(self.mean) }
+    }
+
+    pub fn population_variance(&self) -> Option<T> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.m2 / T::from(self.count)?)
+        }
+    }
+
+    pub fn sample_variance(&self) -> Option<T> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / T::from(self.count - 1)?)
+        }
+    }
+}
+
+impl<T: Float> Default for Welford<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float> Extend<T> for Welford<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! histograms with configurable binning
+use std::cmp::Ordering;
+use num::Float;
+use super::descriptive::min_max;
+
+/// most bins `Binning::Width` may ask for, a too small width fails instead of exhausting memory
+pub const MAX_WIDTH_BINS: usize = 1 << 24;
+
+/// how the bin edges of a histogram are chosen
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binning<T> {
+    /// this many equally wide bins over the data range
+    Count(usize),
+    /// bins of this width starting at the smallest sample, at most `MAX_WIDTH_BINS` of them
+    Width(T),
+    /// explicit increasing edges, n + 1 edges make n bins
+    Edges(Vec<T>),
+    /// ⌈log₂ n⌉ + 1 equally wide bins
+    Sturges,
+    /// ⌈√n⌉ equally wide bins
+    SquareRoot,
+}
+
+/// counts of samples falling in consecutive bins
+///
+/// bin i is the half open range `[edges[i], edges[i + 1])`, except the last
+/// bin which also holds its right edge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram<T> {
+    edges: Vec<T>,
+    counts: Vec<usize>,
+    outside: usize,
+}
+
+impl<T: Float> Histogram<T> {
+    /// empty histogram over the given edges, None if they are fewer than two or not increasing
+    pub fn with_edges(edges: Vec<T>) -> Option<Self> {
+        if edges.len() < 2 || edges.windows(2).any(|w| w[0].partial_cmp(&w[1]) != Some(Ordering::Less)) {
+            return None;
+        }
+        let counts = vec![0; edges.len() - 1];
+        Some(Histogram { edges, counts, outside: 0 })
+    }
+
+    /// histogram of data, None if there is no bin to put it in
+    pub fn new(data: &[T], binning: Binning<T>) -> Option<Self> {
+        let edges = match binning {
+            Binning::Edges(edges) => edges,
+            Binning::Width(w) => {
+                let (lo, hi) = min_max(data)?;
+                if w.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+                    return None;
+                }
+                let n = ((hi - lo) / w).floor().to_usize()? + 1;
+                if n > MAX_WIDTH_BINS {
+                    return None;
+                }
+                (0..=n).map(|i| lo + w * T::from(i).unwrap_or_else(T::nan)).collect()
+            }
+            Binning::Count(n) => equal_edges(data, n)?,
+            Binning::Sturges => {
+                let n = (data.len() as f64).log2().ceil() as usize + 1;
+                equal_edges(data, n)?
+            }
+            Binning::SquareRoot => {
+                let n = (data.len() as f64).sqrt().ceil() as usize;
+                equal_edges(data, n)?
+            }
+        };
+        let mut h = Self::with_edges(edges)?;
+        h.extend(data.iter().copied());
+        Some(h)
+    }
+
+    /// index of the bin x falls in
+    pub fn bin_of(&self, x: T) -> Option<usize> {
+        let last = self.edges.len() - 1;
+        if !(x >= self.edges[0] && x <= self.edges[last]) {
+            return None;
+        }
+        // first edge greater than x, minus one
+        let i = self.edges.partition_point(|&e| e <= x);
+        Some(i.saturating_sub(1).min(last - 1))
+    }
+
+    /// count one sample, returns false if it lies outside every bin
+    pub fn add(&mut self, x: T) -> bool {
+        match self.bin_of(x) {
+            Some(i) => {
+                self.counts[i] += 1;
+                true
+            }
+            None => {
+                self.outside += 1;
+                false
+            }
+        }
+    }
+
+    pub fn edges(&self) -> &[T] {
+        &self.edges
+    }
+
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// number of samples counted in some bin
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// number of samples (including NaN) that fell outside every bin
+    pub fn outside(&self) -> usize {
+        self.outside
+    }
+
+    /// (lower edge, upper edge, count) of every bin
+    pub fn bins(&self) -> impl Iterator<Item = (T, T, usize)> + '_ {
+        self.edges.windows(2).zip(self.counts.iter()).map(|(w, &c)| (w[0], w[1], c))
+    }
+
+    /// count / (total · width) of every bin, integrates to one
+    pub fn density(&self) -> Vec<T> {
+        let total = T::from(self.total()).unwrap_or_else(T::nan);
+        self.bins()
+            .map(|(lo, hi, c)| T::from(c).unwrap_or_else(T::nan) / (total * (hi - lo)))
+            .collect()
+    }
+}
+
+impl<T: Float> Extend<T> for Histogram<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.add(x);
+        }
+    }
+}
+
+/// n equally wide bins over the data range, widened to one around a single value
+fn equal_edges<T: Float>(data: &[T], n: usize) -> Option<Vec<T>> {
+    let (mut lo, mut hi) = min_max(data)?;
+    if n == 0 {
+        return None;
+    }
+    if lo == hi {
+        let half = T::from(0.5)?;
+        lo = lo - half;
+        hi = hi + half;
+    }
+    let w = (hi - lo) / T::from(n)?;
+    let mut edges: Vec<T> = (0..n).map(|i| lo + w * T::from(i).unwrap_or_else(T::nan)).collect();
+    edges.push(hi);
+    Some(edges)
+}
@@ -0,0 +1,12 @@
+//! statistics over slices, `VectorN` and sets of vector samples
+//!
+//! `VectorN` data is passed with `as_slice()`.
+pub mod descriptive;
+pub mod histogram;
+pub mod covariance;
+pub mod pca;
+
+pub use descriptive::*;
+pub use histogram::{Binning, Histogram};
+pub use covariance::*;
+pub use pca::Pca;
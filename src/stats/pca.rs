@@ -0,0 +1,91 @@
+//! principal component analysis from the sample covariance
+use num::{Float, Zero};
+use crate::matrix::Matrix3x3;
+use crate::matrix::eigen::SymmetricEigen;
+use crate::matrix::matrixnxm::Matrix;
+use crate::matrix::square::SquareMatrix;
+use crate::vector::Vector3;
+use crate::vector::vectorn::VectorN;
+use super::covariance::{covariance_of, mean_of, StatsError};
+
+/// principal axes of a set of samples, ordered by decreasing variance
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pca<M: SquareMatrix> {
+    /// mean of the samples
+    pub mean: M::Vector,
+    /// variance along each axis, largest first
+    pub variances: Vec<M::Scalar>,
+    /// unit axes, column k is the k-th principal axis
+    pub axes: M,
+}
+
+impl<M: SquareMatrix> Pca<M> {
+    fn from_samples(zero: &M, samples: &[M::Vector]) -> Result<Self, StatsError> {
+        let mean = mean_of(zero, samples)?;
+        let eigen = SymmetricEigen::new(&covariance_of(zero, samples, &mean));
+        // covariance is positive semidefinite, clamp rounding noise
+        let variances = eigen.values.into_iter().map(|v| v.max(M::Scalar::zero())).collect();
+        Ok(Pca { mean, variances, axes: eigen.vectors })
+    }
+
+    pub fn dim(&self) -> usize {
+        self.axes.dim()
+    }
+
+    /// coordinates of x along the principal axes, relative to the mean
+    pub fn project(&self, x: &M::Vector) -> M::Vector {
+        let d = self.dim();
+        let mut y = self.axes.zero_vector();
+        for k in 0..d {
+            let mut s = M::Scalar::zero();
+            for i in 0..d {
+                s = s + self.axes.get(i, k) * (M::vector_get(x, i) - M::vector_get(&self.mean, i));
+            }
+            M::vector_set(&mut y, k, s);
+        }
+        y
+    }
+
+    /// point with the given coordinates along the principal axes, inverse of `project`
+    pub fn reconstruct(&self, y: &M::Vector) -> M::Vector {
+        let d = self.dim();
+        let mut x = self.mean.clone();
+        for i in 0..d {
+            let mut s = M::vector_get(&self.mean, i);
+            for k in 0..d {
+                s = s + self.axes.get(i, k) * M::vector_get(y, k);
+            }
+            M::vector_set(&mut x, i, s);
+        }
+        x
+    }
+
+    /// share of the total variance along each axis
+    pub fn explained_variance_ratio(&self) -> Vec<M::Scalar> {
+        let total = self.variances.iter().fold(M::Scalar::zero(), |a, &v| a + v);
+        self.variances.iter().map(|&v| v / total).collect()
+    }
+}
+
+impl Pca<Matrix3x3> {
+    pub fn from_vector3(samples: &[Vector3]) -> Result<Self, StatsError> {
+        Self::from_samples(&Matrix3x3::default().zeros_like(), samples)
+    }
+
+    /// the k-th principal axis
+    pub fn axis(&self, k: usize) -> Vector3 {
+        Vector3::new(self.axes.data[0][k], self.axes.data[1][k], self.axes.data[2][k])
+    }
+}
+
+impl<T: Float> Pca<Matrix<T>> {
+    pub fn from_vectorn(samples: &[VectorN<T>]) -> Result<Self, StatsError> {
+        let d = samples.first().map_or(0, |s| s.len());
+        Self::from_samples(&Matrix::zeros(d, d), samples)
+    }
+
+    /// the k-th principal axis
+    pub fn axis(&self, k: usize) -> VectorN<T> {
+        VectorN::new(self.axes.col(k))
+    }
+}
@@ -1,10 +1,13 @@
 //vector math utils
+#[allow(clippy::module_inception)]
 pub mod vector;
 pub mod vector2;
 pub mod vector3;
 pub mod vector4;
 pub mod vectorn;
+pub mod soa;
 
 pub use vector2::*;
 pub use vector3::*;
-pub use vector4::*;
\ No newline at end of file
+pub use vector4::*;
+pub use soa::{Vector3x8, Vector3SoA};
\ No newline at end of file
@@ -0,0 +1,392 @@
+//! structure-of-arrays vector types for batch math
+#![allow(clippy::needless_range_loop)]
+use std::ops::{Add,Sub,Mul,Div,Neg};
+use crate::raw::Vec3f;
+use crate::vector::Vector3;
+
+/// lane count of the wide vector types
+pub const LANES: usize = 8;
+
+/// eight vector3 stored as one array per component
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Vector3x8 {
+    pub x: [f32;LANES],
+    pub y: [f32;LANES],
+    pub z: [f32;LANES],
+}
+
+impl Vector3x8 {
+    /// get a new vector3x8 from component arrays
+    pub fn new(x: [f32;LANES], y: [f32;LANES], z: [f32;LANES]) -> Self {
+        Self {x, y, z}
+    }
+
+    /// get a new vector3x8 with every lane set to v
+    pub fn splat(v: Vector3) -> Self {
+        Self {x: [v.x;LANES], y: [v.y;LANES], z: [v.z;LANES]}
+    }
+
+    /// get a new vector3x8 with every component set to a
+    pub fn broadcast(a: f32) -> Self {
+        Self {x: [a;LANES], y: [a;LANES], z: [a;LANES]}
+    }
+
+    /// get lane i as a vector3
+    pub fn lane(&self, i: usize) -> Vector3 {
+        Vector3::new(self.x[i], self.y[i], self.z[i])
+    }
+
+    /// set lane i to v
+    pub fn set_lane(&mut self, i: usize, v: Vector3) {
+        self.x[i] = v.x;
+        self.y[i] = v.y;
+        self.z[i] = v.z;
+    }
+
+    /// compute length² of every lane
+    pub fn length_square(&self) -> [f32;LANES] {
+        Self::dot(*self, *self)
+    }
+
+    /// compute length of every lane
+    pub fn length(&self) -> [f32;LANES] {
+        let mut ret = self.length_square();
+        for l in ret.iter_mut() {
+            *l = l.sqrt();
+        }
+        ret
+    }
+
+    /// get a vector3x8 with every lane normalized
+    pub fn normalized(&self) -> Self {
+        let l = self.length();
+        let mut ret = *self;
+        for i in 0..LANES {
+            ret.x[i] /= l[i];
+            ret.y[i] /= l[i];
+            ret.z[i] /= l[i];
+        }
+        ret
+    }
+
+    /// lane-wise dot production
+    pub fn dot(a: Self, b: Self) -> [f32;LANES] {
+        let mut ret = [0.0;LANES];
+        for i in 0..LANES {
+            ret[i] = a.x[i] * b.x[i] + a.y[i] * b.y[i] + a.z[i] * b.z[i];
+        }
+        ret
+    }
+
+    /// lane-wise cross production
+    pub fn cross(a: Self, b: Self) -> Self {
+        let mut ret = Self::default();
+        for i in 0..LANES {
+            ret.x[i] = a.y[i] * b.z[i] - a.z[i] * b.y[i];
+            ret.y[i] = a.z[i] * b.x[i] - a.x[i] * b.z[i];
+            ret.z[i] = a.x[i] * b.y[i] - a.y[i] * b.x[i];
+        }
+        ret
+    }
+
+    /// apply f to every component pair of a and b
+    #[inline]
+    fn zip_with(a: Self, b: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        let mut ret = Self::default();
+        for i in 0..LANES {
+            ret.x[i] = f(a.x[i], b.x[i]);
+            ret.y[i] = f(a.y[i], b.y[i]);
+            ret.z[i] = f(a.z[i], b.z[i]);
+        }
+        ret
+    }
+
+    /// apply f to every component
+    #[inline]
+    fn map(self, f: impl Fn(f32) -> f32) -> Self {
+        let mut ret = self;
+        for i in 0..LANES {
+            ret.x[i] = f(self.x[i]);
+            ret.y[i] = f(self.y[i]);
+            ret.z[i] = f(self.z[i]);
+        }
+        ret
+    }
+}
+
+impl Default for Vector3x8 {
+    fn default() -> Self {
+        Self::broadcast(0.0)
+    }
+}
+
+impl Add for Vector3x8 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::zip_with(self, rhs, |a, b| a + b)
+    }
+}
+
+impl Sub for Vector3x8 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::zip_with(self, rhs, |a, b| a - b)
+    }
+}
+
+impl Mul for Vector3x8 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::zip_with(self, rhs, |a, b| a * b)
+    }
+}
+
+impl Mul<f32> for Vector3x8 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        self.map(|a| a * rhs)
+    }
+}
+
+impl Mul<Vector3x8> for f32 {
+    type Output = Vector3x8;
+
+    fn mul(self, rhs: Vector3x8) -> Vector3x8 {
+        rhs.map(|a| self * a)
+    }
+}
+
+impl Div for Vector3x8 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::zip_with(self, rhs, |a, b| a / b)
+    }
+}
+
+impl Div<f32> for Vector3x8 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        self.map(|a| a / rhs)
+    }
+}
+
+impl Neg for Vector3x8 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
+
+impl From<[Vec3f;LANES]> for Vector3x8 {
+    fn from(aos: [Vec3f;LANES]) -> Self {
+        let mut ret = Self::default();
+        for (i, v) in aos.iter().enumerate() {
+            ret.x[i] = v[0];
+            ret.y[i] = v[1];
+            ret.z[i] = v[2];
+        }
+        ret
+    }
+}
+
+impl From<Vector3x8> for [Vec3f;LANES] {
+    fn from(soa: Vector3x8) -> [Vec3f;LANES] {
+        let mut ret = [[0.0;3];LANES];
+        for (i, v) in ret.iter_mut().enumerate() {
+            *v = [soa.x[i], soa.y[i], soa.z[i]];
+        }
+        ret
+    }
+}
+
+/// a growable buffer of vector3 stored as one vec per component
+///
+/// the three components always have the same length, so they are only
+/// reachable through slices.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vector3SoA {
+    x: Vec<f32>,
+    y: Vec<f32>,
+    z: Vec<f32>,
+}
+
+impl Vector3SoA {
+    /// get a new empty buffer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// get a new empty buffer with room for n vectors
+    pub fn with_capacity(n: usize) -> Self {
+        Self {x: Vec::with_capacity(n), y: Vec::with_capacity(n), z: Vec::with_capacity(n)}
+    }
+
+    /// get a buffer from component vecs, panics if their lengths differ
+    pub fn from_components(x: Vec<f32>, y: Vec<f32>, z: Vec<f32>) -> Self {
+        assert!(x.len() == y.len() && y.len() == z.len(), "Vector3SoA components have different lengths");
+        Self {x, y, z}
+    }
+
+    /// convert an array-of-structures slice into a structure-of-arrays buffer
+    pub fn from_aos(aos: &[Vec3f]) -> Self {
+        Self {
+            x: aos.iter().map(|v| v[0]).collect(),
+            y: aos.iter().map(|v| v[1]).collect(),
+            z: aos.iter().map(|v| v[2]).collect(),
+        }
+    }
+
+    /// convert back to an array-of-structures vec
+    pub fn to_aos(&self) -> Vec<Vec3f> {
+        let mut ret = vec![[0.0;3]; self.len()];
+        self.write_aos(&mut ret);
+        ret
+    }
+
+    /// write the buffer into an array-of-structures slice of the same length
+    pub fn write_aos(&self, out: &mut [Vec3f]) {
+        assert_eq!(out.len(), self.len(), "Vector3SoA length mismatch");
+        for (i, v) in out.iter_mut().enumerate() {
+            *v = [self.x[i], self.y[i], self.z[i]];
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    pub fn x(&self) -> &[f32] {
+        &self.x
+    }
+
+    pub fn y(&self) -> &[f32] {
+        &self.y
+    }
+
+    pub fn z(&self) -> &[f32] {
+        &self.z
+    }
+
+    /// the x, y and z components as mutable slices
+    pub fn components_mut(&mut self) -> (&mut [f32], &mut [f32], &mut [f32]) {
+        (&mut self.x, &mut self.y, &mut self.z)
+    }
+
+    pub fn push(&mut self, v: Vector3) {
+        self.x.push(v.x);
+        self.y.push(v.y);
+        self.z.push(v.z);
+    }
+
+    /// get vector i
+    pub fn get(&self, i: usize) -> Option<Vector3> {
+        if i < self.len() {
+            Some(Vector3::new(self.x[i], self.y[i], self.z[i]))
+        } else {
+            None
+        }
+    }
+
+    /// load the 8 vectors starting at i into a wide vector, padding with zero past the end
+    pub fn load8(&self, i: usize) -> Vector3x8 {
+        let mut ret = Vector3x8::default();
+        let i = i.min(self.len());
+        let n = LANES.min(self.len().saturating_sub(i));
+        ret.x[..n].copy_from_slice(&self.x[i..i + n]);
+        ret.y[..n].copy_from_slice(&self.y[i..i + n]);
+        ret.z[..n].copy_from_slice(&self.z[i..i + n]);
+        ret
+    }
+
+    /// store the lanes of v to the 8 vectors starting at i, dropping lanes past the end
+    pub fn store8(&mut self, i: usize, v: &Vector3x8) {
+        let i = i.min(self.len());
+        let n = LANES.min(self.len().saturating_sub(i));
+        self.x[i..i + n].copy_from_slice(&v.x[..n]);
+        self.y[i..i + n].copy_from_slice(&v.y[..n]);
+        self.z[i..i + n].copy_from_slice(&v.z[..n]);
+    }
+}
+
+impl From<&[Vec3f]> for Vector3SoA {
+    fn from(aos: &[Vec3f]) -> Self {
+        Self::from_aos(aos)
+    }
+}
+
+impl From<Vector3SoA> for Vec<Vec3f> {
+    fn from(soa: Vector3SoA) -> Vec<Vec3f> {
+        soa.to_aos()
+    }
+}
+
+#[test]
+fn soa_round_trip() {
+    let aos: Vec<Vec3f> = (0..11).map(|i| [i as f32, 2.0 * i as f32, -(i as f32)]).collect();
+    let soa = Vector3SoA::from_aos(&aos);
+    assert_eq!(soa.len(), 11);
+    assert_eq!(soa.get(3), Some(Vector3::new(3.0, 6.0, -3.0)));
+    assert_eq!(soa.to_aos(), aos);
+
+    let wide = soa.load8(8);
+    assert_eq!(wide.lane(2), Vector3::new(10.0, 20.0, -10.0));
+    assert_eq!(wide.lane(3), Vector3::default());
+}
+
+#[test]
+fn soa_past_the_end() {
+    let mut soa = Vector3SoA::from_aos(&[[1.0, 2.0, 3.0]; 3]);
+    assert_eq!(soa.load8(5), Vector3x8::default());
+    soa.store8(5, &Vector3x8::splat(Vector3::broadcast(9.0)));
+    assert_eq!(soa.to_aos(), vec![[1.0, 2.0, 3.0]; 3]);
+
+    soa.store8(2, &Vector3x8::splat(Vector3::broadcast(9.0)));
+    assert_eq!(soa.get(2), Some(Vector3::broadcast(9.0)));
+    assert_eq!(soa.y(), &[2.0, 2.0, 9.0]);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn soa_components_must_agree() {
+    Vector3SoA::from_components(vec![1.0, 2.0], vec![1.0], vec![1.0, 2.0]);
+}
+
+#[test]
+fn vector3x8_ops() {
+    let a = Vector3x8::splat(Vector3::new(1.0, 0.0, 0.0));
+    let b = Vector3x8::splat(Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(Vector3x8::cross(a, b), Vector3x8::splat(Vector3::new(0.0, 0.0, 1.0)));
+    assert_eq!(Vector3x8::dot(a + b, a + b), [2.0;LANES]);
+    assert_eq!((a * 3.0).length(), [3.0;LANES]);
+}
+
+use std::fmt;
+use crate::display::write_matrix;
+
+/// one lane per line, `{:#}` for a single line
+impl fmt::Display for Vector3x8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = [&self.x, &self.y, &self.z];
+        write_matrix(f, LANES, 3, |i, j| &c[j][i])
+    }
+}
+
+/// one vector per line, `{:#}` for a single line
+impl fmt::Display for Vector3SoA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = [self.x(), self.y(), self.z()];
+        write_matrix(f, self.len(), 3, |i, j| &c[j][i])
+    }
+}
@@ -1,8 +1,13 @@
-//generate a Rⁿ vector type
+//! generate a Rⁿ vector type
+//!
+//! elements only need to be `Clone`, so big integers and rationals work
+//! too. the vector is `Copy` whenever its elements are.
+use std::array;
 use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
 use num::Zero;
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
 pub struct Vector<T, const SIZE: usize> {
     pub data: [T; SIZE],
 }
@@ -11,140 +16,113 @@ pub trait Sqrt {
     fn sqrt(self) -> Self;
 }
 
-impl<T: Zero + Mul<Output = T> + Add<Output = T> + Div<Output = T> + Copy + Sqrt, const SIZE: usize> Vector<T, SIZE> {
+impl<T, const SIZE: usize> Vector<T, SIZE> {
     /// get a new vector from array
     pub fn new(data: [T; SIZE]) -> Self {
         Self { data }
     }
 
+    #[inline]
+    pub fn size() -> usize {
+        SIZE
+    }
+}
+
+impl<T: Clone, const SIZE: usize> Vector<T, SIZE> {
     /// get a new vector form a
     pub fn broadcast(a: T) -> Self {
-        Self { data: [a; SIZE] }
+        Self { data: array::from_fn(|_| a.clone()) }
     }
+}
 
+impl<T: Zero + Mul<Output = T> + Add<Output = T> + Clone, const SIZE: usize> Vector<T, SIZE> {
     /// compute length² of vector
     pub fn length_square(&self) -> T {
-        self.data.iter().fold(T::zero(), |acc, x| acc + (*x * *x))
+        self.data.iter().fold(T::zero(), |acc, x| acc + x.clone() * x.clone())
+    }
+
+    /// dot production for vector
+    pub fn dot(a: Self, b: Self) -> T {
+        (0..SIZE).fold(T::zero(), |acc, i| acc + a.data[i].clone() * b.data[i].clone())
     }
+}
 
+impl<T: Zero + Mul<Output = T> + Add<Output = T> + Div<Output = T> + Clone + Sqrt, const SIZE: usize> Vector<T, SIZE> {
     /// compute length of vector
     pub fn length(&self) -> T {
         T::sqrt(self.length_square())
-        //num::Integer::i(self.length_square())
     }
 
     /// get  a normalized vector
     pub fn normalized(&self) -> Self {
         let l = self.length();
-        let mut  ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = self.data[i] / l;
-        }
-        Self{ data: ret}
-    }
-
-    /// dot production for vector
-    pub fn dot(a: Self, b: Self) -> T {        
-        (0usize..SIZE).fold(T::zero(),|acc,x| acc + a.data[x] * b.data[x])
-    }
-
-    #[inline]
-    pub fn size() -> usize {
-        SIZE
+        Self { data: array::from_fn(|i| self.data[i].clone() / l.clone()) }
     }
 }
 
-impl<T: Default + Copy, const SIZE: usize> Default for Vector<T,SIZE>{
+impl<T: Default, const SIZE: usize> Default for Vector<T,SIZE>{
     fn default() -> Self {
-        Self { data: [T::default();SIZE]}
+        Self { data: array::from_fn(|_| T::default()) }
     }
 }
 
-impl<T: Copy + Zero + Add<Output = T>, const SIZE: usize> Add for Vector<T, SIZE> {
+impl<T: Clone + Add<Output = T>, const SIZE: usize> Add for Vector<T, SIZE> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self {
-        let mut  ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = self.data[i] + rhs.data[i];
-        }
-        Self {data: ret}
+        Self { data: array::from_fn(|i| self.data[i].clone() + rhs.data[i].clone()) }
     }
 }
 
-impl<T: Copy + Zero + Sub<Output = T>,const SIZE: usize> Sub for Vector<T, SIZE> {
+impl<T: Clone + Sub<Output = T>,const SIZE: usize> Sub for Vector<T, SIZE> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self {
-        let mut  ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = self.data[i] - rhs.data[i];
-        }
-        Self {data: ret}
+        Self { data: array::from_fn(|i| self.data[i].clone() - rhs.data[i].clone()) }
     }
-} 
+}
 
-impl<T: Copy + Zero + Mul<Output = T>,const SIZE: usize> Mul for Vector<T, SIZE> {
+impl<T: Clone + Mul<Output = T>,const SIZE: usize> Mul for Vector<T, SIZE> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
-        let mut  ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = self.data[i] * rhs.data[i];
-        }
-        Self {data: ret}
+        Self { data: array::from_fn(|i| self.data[i].clone() * rhs.data[i].clone()) }
     }
 }
 
-impl<T: Copy + Zero + Mul<Output = T>,const SIZE: usize> Mul<T> for Vector<T, SIZE> {
+impl<T: Clone + Mul<Output = T>,const SIZE: usize> Mul<T> for Vector<T, SIZE> {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self{
-        let mut  ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = self.data[i] * rhs;
-        }
-        Self {data: ret}
+        Self { data: array::from_fn(|i| self.data[i].clone() * rhs.clone()) }
     }
 }
 
-impl<T: Copy + Zero + Div<Output = T>,const SIZE: usize> Div for Vector<T,SIZE> {
+impl<T: Clone + Div<Output = T>,const SIZE: usize> Div for Vector<T,SIZE> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self {
-        let mut  ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = self.data[i] / rhs.data[i];
-        }
-        Self {data: ret}
+        Self { data: array::from_fn(|i| self.data[i].clone() / rhs.data[i].clone()) }
     }
 }
 
-impl<T: Copy + Zero + Div<Output = T>,const SIZE: usize> Div<T> for Vector<T,SIZE> {
+impl<T: Clone + Div<Output = T>,const SIZE: usize> Div<T> for Vector<T,SIZE> {
     type Output = Self;
 
     fn div(self, rhs: T) -> Self {
-        let mut  ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = self.data[i] / rhs;
-        }
-        Self {data: ret}
+        Self { data: array::from_fn(|i| self.data[i].clone() / rhs.clone()) }
     }
 }
 
-impl<T: Copy + Zero + Neg<Output = T>,const SIZE: usize> Neg for Vector<T,SIZE> {
+impl<T: Neg<Output = T>,const SIZE: usize> Neg for Vector<T,SIZE> {
     type Output = Self;
 
     fn neg(self) -> Self {
-        let mut ret = [T::zero();SIZE];
-        for i in 0usize..SIZE {
-            ret[i] = -self.data[i]
-        }
-        Self {data: ret}
+        Self { data: self.data.map(|x| -x) }
     }
 }
 
-impl<T: Copy,const SIZE: usize> Index<usize> for Vector<T,SIZE> {
+impl<T,const SIZE: usize> Index<usize> for Vector<T,SIZE> {
     type Output = T;
 
     fn index(&self, i: usize) -> &T {
@@ -156,7 +134,7 @@ impl<T: Copy,const SIZE: usize> Index<usize> for Vector<T,SIZE> {
     }
 }
 
-impl<T: Copy,const SIZE: usize> IndexMut<usize> for Vector<T,SIZE> {
+impl<T,const SIZE: usize> IndexMut<usize> for Vector<T,SIZE> {
     fn index_mut(&mut self, i: usize) -> &mut T {
         if i < SIZE {
             &mut self.data[i]
@@ -166,7 +144,7 @@ impl<T: Copy,const SIZE: usize> IndexMut<usize> for Vector<T,SIZE> {
     }
 }
 
-impl <T: Copy + Eq, const SIZE: usize> Eq for Vector<T,SIZE>{
+impl <T: Eq, const SIZE: usize> Eq for Vector<T,SIZE>{
 }
 
 impl Sqrt for f32 {
@@ -253,6 +231,55 @@ impl Sqrt for i128 {
     }
 }
 
+/// serialized as a plain tuple of SIZE elements
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const SIZE: usize> serde::Serialize for Vector<T, SIZE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(SIZE)?;
+        for e in self.data.iter() {
+            tuple.serialize_element(e)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const SIZE: usize> serde::Deserialize<'de> for Vector<T, SIZE> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::marker::PhantomData;
+        use std::convert::TryFrom;
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct VectorVisitor<T, const SIZE: usize>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const SIZE: usize> Visitor<'de> for VectorVisitor<T, SIZE> {
+            type Value = Vector<T, SIZE>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "an array of {} elements", SIZE)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut data = Vec::with_capacity(SIZE);
+                for i in 0..SIZE {
+                    match seq.next_element()? {
+                        Some(e) => data.push(e),
+                        None => return Err(A::Error::invalid_length(i, &self)),
+                    }
+                }
+                match <[T; SIZE]>::try_from(data) {
+                    Ok(data) => Ok(Vector { data }),
+                    Err(_) => unreachable!(),
+                }
+            }
+        }
+
+        deserializer.deserialize_tuple(SIZE, VectorVisitor::<T, SIZE>(PhantomData))
+    }
+}
+
 #[test]
 fn vector_f32() {
     type Vector3f = Vector<f32, 3>;
@@ -269,6 +296,20 @@ fn vector_f32() {
 fn vector_f64() {
     type Vector3d = Vector<f64,3>;
     let a = Vector3d::new([1.0,1.0,1.0]);
-    let b = a.clone();
+    let b = a;
     assert_eq!(a + b, Vector3d::new([2.0,2.0,2.0]));
 }
+
+impl<T: std::fmt::Display, const SIZE: usize> std::fmt::Display for Vector<T, SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        crate::display::write_vector(f, &self.data)
+    }
+}
+
+impl<T: std::str::FromStr, const SIZE: usize> std::str::FromStr for Vector<T, SIZE> {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(|data| Self { data })
+    }
+}
@@ -4,6 +4,8 @@ use std::ops::{Add,Sub,Mul,Div,Neg,Index,IndexMut};
 
 /// a vector in R² space with 32bit float number
 #[derive(Debug,Copy,Clone,PartialEq,PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "Vec2f", into = "Vec2f"))]
+#[repr(C)]
 pub struct Vector2 {
     pub x: f32,
     pub y: f32,
@@ -92,7 +94,7 @@ impl Div for Vector2 {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self {
-        Self {x: self.x / rhs.x, y: self.y / self.y}
+        Self {x: self.x / rhs.x, y: self.y / rhs.y}
     }
 }
 
@@ -146,4 +148,21 @@ impl From<Vector2> for Vec2f {
     fn from(vector2: Vector2) -> Vec2f {
         [vector2[0],vector2[1]]
     }
-}
\ No newline at end of file
+}
+
+use std::fmt;
+
+impl fmt::Display for Vector2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::write_vector(f, &[self.x, self.y])
+    }
+}
+
+impl std::str::FromStr for Vector2 {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = crate::parse::parse_array(s)?;
+        Ok(Self::new(x, y))
+    }
+}
@@ -4,6 +4,8 @@ use std::ops::{Add,Sub,Mul,Div,Neg,Index,IndexMut};
 
 /// a vector in R³ space with 32bit float number
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "Vec3f", into = "Vec3f"))]
+#[repr(C)]
 pub struct Vector3{
     pub x: f32,
     pub y: f32,
@@ -18,7 +20,7 @@ pub type Point3 = Vector3;
 impl Vector3 {
     /// get a new vector3 from x y z
     pub fn new(x: f32, y: f32,z: f32) -> Self {
-        Self {x: x, y: y, z: z}
+        Self {x, y, z}
     }
 
     /// get a new vector3 form a
@@ -52,7 +54,7 @@ impl Vector3 {
         let x = a.y * b.z - a.z * b.y;
         let y = -(a.x * b.z - a.z * b.x);
         let z = a.x * b.y - a.y * b.x;
-        Vector3{x: x, y: y, z: z}
+        Vector3{x, y, z}
     }
 }
 
@@ -163,4 +165,21 @@ impl From<Vector3> for Vec3f {
     fn from(vector3: Vector3) -> Vec3f {
         [vector3[0],vector3[1],vector3[2]]
     }
-}
\ No newline at end of file
+}
+
+use std::fmt;
+
+impl fmt::Display for Vector3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::write_vector(f, &[self.x, self.y, self.z])
+    }
+}
+
+impl std::str::FromStr for Vector3 {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = crate::parse::parse_array(s)?;
+        Ok(Self::new(x, y, z))
+    }
+}
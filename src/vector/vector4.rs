@@ -4,6 +4,8 @@ use std::ops::{Add,Sub,Mul, Div, Neg, Index, IndexMut};
 
 /// a vector in R⁴ with 32 bit float number
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "Vec4f", into = "Vec4f"))]
+#[repr(C)]
 pub struct Vector4 {
     pub x: f32,
     pub y: f32,
@@ -78,7 +80,7 @@ impl Mul<f32> for Vector4 {
     type Output = Self;
 
     fn mul(self, rhs: f32) -> Self {
-        Self {x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.z * rhs}
+        Self {x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.w * rhs}
     }
 }
 
@@ -86,7 +88,7 @@ impl Mul<Vector4> for f32 {
     type Output = Vector4;
     
     fn mul(self, rhs: Vector4) -> Vector4 {
-        Vector4 {x: self * rhs.z, y: self * rhs.y, z: self * rhs.z, w: self * rhs.w}
+        Vector4 {x: self * rhs.x, y: self * rhs.y, z: self * rhs.z, w: self * rhs.w}
     }
 }
 
@@ -152,4 +154,21 @@ impl From<Vector4> for Vec4f {
     fn from(vector4: Vector4) -> Vec4f {
         [vector4[0],vector4[1],vector4[2],vector4[3]]
     }
-}
\ No newline at end of file
+}
+
+use std::fmt;
+
+impl fmt::Display for Vector4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::write_vector(f, &[self.x, self.y, self.z, self.w])
+    }
+}
+
+impl std::str::FromStr for Vector4 {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z, w] = crate::parse::parse_array(s)?;
+        Ok(Self::new(x, y, z, w))
+    }
+}
@@ -1,173 +1,370 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::slice::SliceIndex;
 use std::ops::{Add,Sub,Mul,Div,Neg,Index,IndexMut};
-
-#[derive(Clone)]
+use num::{Signed, Zero};
+use super::vector::Sqrt;
+
+/// a vector in Rⁿ with the length known at runtime
+///
+/// element-wise operators panic when the lengths differ, the `checked_*`
+/// methods and `try_dot` return a `LengthMismatch` instead.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct VectorN<T> {
     pub data: Vec<T>,
 }
 
-impl<T> VectorN<T> 
-where T: Default + Add<Output=T> + Mul<Output=T> + Copy + Clone + AsRef<T> {
+/// two vectors of different lengths met in an element-wise operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatch {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vector length mismatch: {} and {}", self.left, self.right)
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+impl<T> VectorN<T> {
     pub fn new(data: Vec<T>) -> Self {
         VectorN {
             data,
         }
     }
 
+    /// get a new vector of length n with element i set to f(i)
+    pub fn from_fn<F: FnMut(usize) -> T>(n: usize, f: F) -> Self {
+        VectorN {
+            data: (0..n).map(f).collect(),
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.data.len()
     }
 
-    pub fn dot(a: VectorN<T>, b: VectorN<T>) -> T{
-        let mut result: T = Default::default();
-        
-        let len = if a.len() > b.len()  { a.len() } else { b.len() };
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn push(&mut self, e: T) {
+        self.data.push(e)
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
 
-        for i in 0..len {
-            result = result + a[i] * b[i];
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Ok(len) if both vectors have the same length
+    pub fn check_len(a: &Self, b: &Self) -> Result<usize, LengthMismatch> {
+        if a.len() == b.len() {
+            Ok(a.len())
+        } else {
+            Err(LengthMismatch { left: a.len(), right: b.len() })
         }
-        result
+    }
+
+    /// apply f to every pair of elements, or fail if the lengths differ
+    fn zip_with<F: Fn(&T, &T) -> T>(a: &Self, b: &Self, f: F) -> Result<Self, LengthMismatch> {
+        Self::check_len(a, b)?;
+        Ok(a.data.iter().zip(b.data.iter()).map(|(x, y)| f(x, y)).collect())
     }
 }
 
-impl<T:Default> Default for VectorN<T> {
-    fn default() -> Self {
+impl<T: Clone + Zero> VectorN<T> {
+    /// get a new vector of n zeros
+    pub fn zeros(n: usize) -> Self {
         VectorN {
-            data: Vec::default(),
+            data: vec![T::zero(); n],
         }
     }
 }
 
-impl<T: AsRef<T>> Index<usize> for VectorN<T> {
-    type Output = T;
-    fn index(&self,i: usize) -> &T {
-        &self.data[i]
+impl<T: Clone + Zero + Mul<Output=T>> VectorN<T> {
+    /// dot production, panics if the lengths differ
+    pub fn dot(a: &Self, b: &Self) -> T {
+        match Self::try_dot(a, b) {
+            Ok(d) => d,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// dot production, fails if the lengths differ
+    pub fn try_dot(a: &Self, b: &Self) -> Result<T, LengthMismatch> {
+        Self::check_len(a, b)?;
+        Ok(a.data.iter().zip(b.data.iter()).fold(T::zero(), |acc, (x, y)| acc + x.clone() * y.clone()))
+    }
+
+    /// compute length² of vector
+    pub fn length_square(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, x| acc + x.clone() * x.clone())
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, LengthMismatch> {
+        Self::zip_with(self, rhs, |x, y| x.clone() + y.clone())
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, LengthMismatch> {
+        Self::zip_with(self, rhs, |x, y| x.clone() * y.clone())
     }
 }
 
-impl<T: AsRef<T>> IndexMut<usize> for VectorN<T> {
-    fn index_mut(&mut self, i: usize) -> &mut T {
-        &mut self.data[i]
+impl<T: Clone + Sub<Output=T>> VectorN<T> {
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, LengthMismatch> {
+        Self::zip_with(self, rhs, |x, y| x.clone() - y.clone())
     }
 }
 
-impl<T: Default + Add<Output=T> + Mul<Output=T>  + Copy + Clone + AsRef<T>> Add for VectorN<T> {
-    type Output = Self;
+impl<T: Clone + Div<Output=T>> VectorN<T> {
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, LengthMismatch> {
+        Self::zip_with(self, rhs, |x, y| x.clone() / y.clone())
+    }
+}
 
-    fn add(self, rhs: Self) -> Self {
-        let mut result:Vec<T> = Default::default();
+impl<T: Clone + Zero + Mul<Output=T> + Div<Output=T> + Sqrt> VectorN<T> {
+    /// compute length of vector, the L² norm
+    pub fn length(&self) -> T {
+        T::sqrt(self.length_square())
+    }
 
-        let len = if self.len() > rhs.len() {self.len()} else {rhs.len()};
+    /// L² norm, same as `length`
+    pub fn norm_l2(&self) -> T {
+        self.length()
+    }
 
-        for i in 0..len {
-            result.push(self[i] + rhs[i]);
-        }
+    /// get a normalized vector
+    pub fn normalized(&self) -> Self {
+        let l = self.length();
+        self.data.iter().map(|x| x.clone() / l.clone()).collect()
+    }
+}
+
+impl<T: Clone + Signed + PartialOrd> VectorN<T> {
+    /// L¹ norm, sum of absolute values
+    pub fn norm_l1(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, x| acc + x.abs())
+    }
+
+    /// L∞ norm, largest absolute value, zero for an empty vector
+    pub fn norm_inf(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, x| {
+            let a = x.abs();
+            if a > acc { a } else { acc }
+        })
+    }
+}
 
-        Self {
-            data: result,
+impl<T> Default for VectorN<T> {
+    fn default() -> Self {
+        VectorN {
+            data: Vec::default(),
         }
     }
 }
 
-impl<T: Default + Add<Output=T> + Mul<Output=T> + Sub<Output=T> + Copy + Clone + AsRef<T>> Sub for VectorN<T> {
-    type Output = Self;
+impl<T> From<Vec<T>> for VectorN<T> {
+    fn from(data: Vec<T>) -> Self {
+        VectorN { data }
+    }
+}
 
-    fn sub(self, rhs: Self) -> Self {
-        let mut result:Vec<T> = Default::default();
+impl<T> From<VectorN<T>> for Vec<T> {
+    fn from(v: VectorN<T>) -> Self {
+        v.data
+    }
+}
 
-        let len = if self.len() > rhs.len() {self.len()} else {rhs.len()};
+impl<T: Clone> From<&[T]> for VectorN<T> {
+    fn from(data: &[T]) -> Self {
+        VectorN { data: data.to_vec() }
+    }
+}
 
-        for i in 0..len {
-            result.push(self[i] - rhs[i]);
-        }
+impl<T> FromIterator<T> for VectorN<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        VectorN { data: iter.into_iter().collect() }
+    }
+}
 
-        Self {
-            data: result,
-        }
+impl<T> IntoIterator for VectorN<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
     }
 }
 
-impl<T: Default + Add<Output=T> + Mul<Output=T> + Copy + Clone + AsRef<T>> Mul for VectorN<T> {
-    type Output = Self;
+impl<'a, T> IntoIterator for &'a VectorN<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
 
-    fn mul(self, rhs: Self) -> Self {
-        let mut result:Vec<T> = Default::default();
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
 
-        let len = if self.len() > rhs.len() {self.len()} else {rhs.len()};
+impl<'a, T> IntoIterator for &'a mut VectorN<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
 
-        for i in 0..len {
-            result.push(self[i] * rhs[i]);
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
 
-        Self {
-            data: result,
-        }
+impl<T> AsRef<[T]> for VectorN<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
     }
 }
 
-impl<T: Default + Add<Output=T> + Mul<Output=T> + Copy + Clone + AsRef<T>> Mul<T> for VectorN<T> {
-    type Output = Self;
+impl<T> AsMut<[T]> for VectorN<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
 
-    fn mul(self, rhs: T) -> Self {
-        let mut result:Vec<T> = Default::default();
+/// index by element or by range, `v[1..3]` is a slice
+impl<T, I: SliceIndex<[T]>> Index<I> for VectorN<T> {
+    type Output = I::Output;
+    fn index(&self,i: I) -> &I::Output {
+        &self.data[i]
+    }
+}
 
-        for i in 0..self.len() {
-            result.push(self[i] * rhs);
-        }
+impl<T, I: SliceIndex<[T]>> IndexMut<I> for VectorN<T> {
+    fn index_mut(&mut self, i: I) -> &mut I::Output {
+        &mut self.data[i]
+    }
+}
 
-        Self {
-            data: result,
-        }
+/// unwrap an element-wise result, panicking with the mismatched lengths
+fn expect_len<T>(r: Result<T, LengthMismatch>) -> T {
+    match r {
+        Ok(v) => v,
+        Err(e) => panic!("{}", e),
     }
 }
 
-impl<T: Default + Add<Output=T> + Mul<Output=T> + Div<Output=T> + Copy + Clone + AsRef<T>> Div for VectorN<T> {
+impl<T: Clone + Add<Output=T>> Add for VectorN<T> {
     type Output = Self;
 
-    fn div(self, rhs: Self) -> Self {
-        let mut result:Vec<T> = Default::default();
-
-        let len = if self.len() > rhs.len() {self.len()} else {rhs.len()};
+    fn add(self, rhs: Self) -> Self {
+        expect_len(Self::zip_with(&self, &rhs, |x, y| x.clone() + y.clone()))
+    }
+}
 
-        for i in 0..len {
-            result.push(self[i] / rhs[i]);
-        }
+impl<T: Clone + Sub<Output=T>> Sub for VectorN<T> {
+    type Output = Self;
 
-        Self {
-            data: result,
-        }
+    fn sub(self, rhs: Self) -> Self {
+        expect_len(Self::zip_with(&self, &rhs, |x, y| x.clone() - y.clone()))
     }
 }
 
+impl<T: Clone + Mul<Output=T>> Mul for VectorN<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        expect_len(Self::zip_with(&self, &rhs, |x, y| x.clone() * y.clone()))
+    }
+}
 
-impl<T: Default + Add<Output=T> + Mul<Output=T> + Div<Output=T> + Copy + Clone + AsRef<T>> Div<T> for VectorN<T> {
+impl<T: Clone + Mul<Output=T>> Mul<T> for VectorN<T> {
     type Output = Self;
 
-    fn div(self, rhs: T) -> Self {
-        let mut result:Vec<T> = Default::default();
+    fn mul(self, rhs: T) -> Self {
+        self.data.iter().map(|x| x.clone() * rhs.clone()).collect()
+    }
+}
 
-        for i in 0..self.len() {
-            result.push(self[i] / rhs);
-        }
+impl<T: Clone + Div<Output=T>> Div for VectorN<T> {
+    type Output = Self;
 
-        Self {
-            data: result,
-        }
+    fn div(self, rhs: Self) -> Self {
+        expect_len(Self::zip_with(&self, &rhs, |x, y| x.clone() / y.clone()))
     }
 }
 
+impl<T: Clone + Div<Output=T>> Div<T> for VectorN<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self {
+        self.data.iter().map(|x| x.clone() / rhs.clone()).collect()
+    }
+}
 
-impl<T: Default + Add<Output=T> + Mul<Output=T> + Neg<Output=T> + Copy + Clone + AsRef<T>> Neg for VectorN<T> {
+impl<T: Clone + Neg<Output=T>> Neg for VectorN<T> {
     type Output = Self;
 
     fn neg(self) -> Self {
-        let mut result:Vec<T> = Default::default();
+        self.data.into_iter().map(|x| -x).collect()
+    }
+}
 
-        for e in self.data.iter() {
-            result.push(-*e);
-        }
+#[test]
+fn vectorn_f64() {
+    let a = VectorN::new(vec![3.0, -4.0]);
+    let b = VectorN::from_fn(2, |i| i as f64);
+    assert_eq!(a.clone() + b.clone(), VectorN::new(vec![3.0, -3.0]));
+    assert_eq!(VectorN::dot(&a, &b), -4.0);
+    assert_eq!(a.length(), 5.0);
+    assert_eq!(a.norm_l1(), 7.0);
+    assert_eq!(a.norm_inf(), 4.0);
+    assert_eq!(a.normalized(), VectorN::new(vec![0.6, -0.8]));
+    assert_eq!(VectorN::<f32>::zeros(3).len(), 3);
+}
 
-        Self {
-            data: result,
-        }
+#[test]
+fn vectorn_length_mismatch() {
+    let a = VectorN::new(vec![1.0f32, 2.0, 3.0]);
+    let b = VectorN::new(vec![1.0f32, 2.0]);
+    assert_eq!(VectorN::try_dot(&a, &b), Err(LengthMismatch { left: 3, right: 2 }));
+    assert_eq!(a.checked_add(&b), Err(LengthMismatch { left: 3, right: 2 }));
+    assert!(a.checked_sub(&a).is_ok());
+    assert!(std::panic::catch_unwind(|| a.clone() * b.clone()).is_err());
+}
+
+#[test]
+fn vectorn_iteration() {
+    let mut v: VectorN<i32> = (1..=4).collect();
+    v.push(5);
+    for e in &mut v {
+        *e *= 2;
     }
-}
\ No newline at end of file
+    assert_eq!(&v[1..3], &[4, 6]);
+    assert_eq!(v.iter().sum::<i32>(), 30);
+    assert_eq!(v.into_iter().last(), Some(10));
+}
+
+impl<T: fmt::Display> fmt::Display for VectorN<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::display::write_vector(f, &self.data)
+    }
+}
+
+impl<T: std::str::FromStr> std::str::FromStr for VectorN<T> {
+    type Err = crate::parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_vector(s).map(|data| Self { data })
+    }
+}
@@ -0,0 +1,151 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use rmu::matrix::affine::{Transform2, Transform3};
+use rmu::matrix::rotation::*;
+use rmu::matrix::*;
+use rmu::vector::*;
+
+fn close3(a: Vector3, b: Vector3) -> bool {
+    (a - b).length() < 1e-4
+}
+
+fn close2(a: Vector2, b: Vector2) -> bool {
+    (a - b).length() < 1e-4
+}
+
+fn sample3() -> Transform3 {
+    Transform3::new(
+        Vector3::new(1.0, -2.0, 3.0),
+        quat_from_axis_angle(Vector3::new(1.0, 2.0, -0.5), 0.7),
+        Vector3::broadcast(2.0),
+    )
+}
+
+#[test]
+fn quaternion_matrix_round_trip() {
+    let q = quat_from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    assert!(close3(quat_rotate(q, Vector3::new(1.0, 0.0, 0.0)), Vector3::new(0.0, 1.0, 0.0)));
+
+    let v = Vector3::new(0.3, -1.0, 2.0);
+    for angle in [0.1, 1.0, 2.5, 3.1] {
+        let q = quat_from_axis_angle(Vector3::new(-1.0, 0.5, 2.0), angle);
+        let m = quat_to_matrix3(q);
+        assert!(close3(m * v, quat_rotate(q, v)));
+        let back = quat_from_matrix3(&m);
+        assert!((Vector4::dot(back, q).abs() - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn point_and_vector() {
+    let t = Transform3::new(Vector3::new(1.0, 2.0, 3.0), QUAT_IDENTITY, Vector3::new(2.0, 3.0, 4.0));
+    assert_eq!(t.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(3.0, 5.0, 7.0));
+    assert_eq!(t.transform_vector(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, 4.0));
+    assert_eq!(Transform3::default(), Transform3::IDENTITY);
+}
+
+#[test]
+fn composition_applies_rhs_first() {
+    let parent = sample3();
+    let child = Transform3::new(
+        Vector3::new(0.5, 0.0, -1.0),
+        quat_from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 1.2),
+        Vector3::new(1.0, 2.0, 0.5),
+    );
+    let world = parent * child;
+    let p = Vector3::new(0.2, 0.4, -0.7);
+    assert!(close3(world.transform_point(p), parent.transform_point(child.transform_point(p))));
+
+    let m = parent.to_matrix4() * child.to_matrix4();
+    let h = m * Vector4::new(p.x, p.y, p.z, 1.0);
+    assert!(close3(Vector3::new(h.x, h.y, h.z), world.transform_point(p)));
+
+    let mut acc = parent;
+    acc *= child;
+    assert_eq!(acc, world);
+}
+
+#[test]
+fn inverse_undoes() {
+    let t = sample3();
+    let p = Vector3::new(4.0, -1.0, 0.5);
+    assert!(close3(t.inverse().transform_point(t.transform_point(p)), p));
+    let id = t * t.inverse();
+    assert!(close3(id.translation, Vector3::broadcast(0.0)));
+    assert!(close3(id.scale, Vector3::broadcast(1.0)));
+}
+
+#[test]
+fn matrix4_round_trip() {
+    let t = Transform3::new(
+        Vector3::new(1.0, -2.0, 3.0),
+        quat_from_axis_angle(Vector3::new(1.0, 2.0, -0.5), 2.0),
+        Vector3::new(2.0, 0.5, 3.0),
+    );
+    let m: Matrix4x4 = t.into();
+    let p = Vector3::new(0.1, 0.2, 0.3);
+    let h = m * Vector4::new(p.x, p.y, p.z, 1.0);
+    assert!(close3(Vector3::new(h.x, h.y, h.z), t.transform_point(p)));
+
+    let back = Transform3::from(m);
+    assert!(close3(back.translation, t.translation));
+    assert!(close3(back.scale, t.scale));
+    assert!((Vector4::dot(back.rotation, t.rotation).abs() - 1.0).abs() < 1e-5);
+
+    // a mirror comes back as a negative x scale
+    let mirror = Transform3::from_matrix4(&Matrix4x4::new(-1.0, 1.0, 1.0, 1.0));
+    assert!(close3(mirror.transform_point(p), Vector3::new(-0.1, 0.2, 0.3)));
+}
+
+#[test]
+fn interpolation() {
+    let a = Transform3::from_translation(Vector3::new(0.0, 0.0, 0.0));
+    let b = Transform3::new(
+        Vector3::new(2.0, 4.0, 6.0),
+        quat_from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2),
+        Vector3::broadcast(3.0),
+    );
+    let mid = a.lerp(&b, 0.5);
+    assert!(close3(mid.translation, Vector3::new(1.0, 2.0, 3.0)));
+    assert!(close3(mid.scale, Vector3::broadcast(2.0)));
+    let half = quat_from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2 / 2.0);
+    assert!((Vector4::dot(mid.rotation, half) - 1.0).abs() < 1e-5);
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert!(close3(a.lerp(&b, 1.0).translation, b.translation));
+
+    // q and -q describe the same rotation, slerp must not take the long way
+    let q = quat_from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.4);
+    let s = quat_slerp(q, -q, 0.5);
+    assert!((Vector4::dot(s, q).abs() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn transform2() {
+    let t = Transform2::new(Vector2::new(1.0, 2.0), FRAC_PI_2, Vector2::new(2.0, 2.0));
+    assert!(close2(t.transform_point(Vector2::new(1.0, 0.0)), Vector2::new(1.0, 4.0)));
+    assert!(close2(t.transform_vector(Vector2::new(1.0, 0.0)), Vector2::new(0.0, 2.0)));
+
+    let p = Vector2::new(0.3, -0.8);
+    assert!(close2(t.inverse().transform_point(t.transform_point(p)), p));
+
+    let child = Transform2::new(Vector2::new(-1.0, 0.5), 0.3, Vector2::new(1.0, 3.0));
+    let world = t * child;
+    assert!(close2(world.transform_point(p), t.transform_point(child.transform_point(p))));
+
+    let m = Matrix3x3::from(world);
+    let h = m * Vector3::new(p.x, p.y, 1.0);
+    assert!(close2(Vector2::new(h.x, h.y), world.transform_point(p)));
+
+    let back = Transform2::from(m);
+    assert!(close2(back.translation, world.translation));
+    assert!(close2(back.scale, world.scale));
+    assert!((back.rotation - world.rotation).abs() < 1e-5);
+}
+
+#[test]
+fn transform2_lerp_takes_short_way() {
+    let a = Transform2::from_rotation(PI - 0.1);
+    let b = Transform2::from_rotation(-PI + 0.1);
+    let mid = a.lerp(&b, 0.5);
+    assert!((mid.rotation.sin_cos().1 + 1.0).abs() < 1e-5);
+}
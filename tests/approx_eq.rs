@@ -0,0 +1,77 @@
+use rmu::approx_eq::ApproxEq;
+use rmu::complex::Complex64;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::matrix::*;
+use rmu::vector::vectorn::VectorN;
+use rmu::vector::*;
+use rmu::{approx_eq, assert_approx_eq};
+
+#[test]
+fn floats() {
+    assert!(approx_eq!(0.1f64 + 0.2, 0.3));
+
+    assert!(1.0f32.abs_diff_eq(&1.05, 0.1));
+    assert!(!1.0f32.abs_diff_eq(&1.2, 0.1));
+
+    // relative tolerance scales with magnitude, absolute does not
+    assert!(1e6f64.relative_eq(&1.000_001e6, 0.0, 1e-5));
+    assert!(!1e6f64.abs_diff_eq(&1.000_001e6, 1e-5));
+
+    let next = f32::from_bits(1.0f32.to_bits() + 3);
+    assert!(1.0f32.ulps_eq(&next, 0.0, 3));
+    assert!(!1.0f32.ulps_eq(&next, 0.0, 2));
+    assert!(approx_eq!(1.0f32, next, ulps <= 4));
+
+    // opposite signs are only close through the absolute tolerance
+    assert!(!1e-40f32.ulps_eq(&-1e-40, 0.0, 1000));
+    assert!(1e-40f32.ulps_eq(&-1e-40, f32::EPSILON, 0));
+    assert!(0.0f64.ulps_eq(&-0.0, 0.0, 0));
+
+    assert!(!f64::NAN.approx_eq(&f64::NAN));
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY));
+    assert!(!f64::INFINITY.relative_eq(&f64::MAX, 1.0, 1.0));
+}
+
+#[test]
+fn vectors_and_matrices() {
+    let m = Matrix3x3::from_axis_angle(Vector3::new(1.0, 1.0, 0.0), 0.9);
+    assert_approx_eq!(m * m.transpose(), Matrix3x3::default(), abs <= 1e-6);
+    assert!(!approx_eq!(m, Matrix3x3::default(), abs <= 1e-6));
+
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_approx_eq!(v, v + Vector3::broadcast(1e-7), rel <= 1e-6);
+    assert_approx_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.0000002), ulps <= 2);
+    assert_approx_eq!(Vector4::broadcast(1.0), Vector4::broadcast(1.0));
+    assert_approx_eq!(Matrix4x4::default(), Matrix4x4::default() * 1.0000001);
+    assert!(approx_eq!(Matrix2x2::rotate2(0.1) * Matrix2x2::rotate2(-0.1), Matrix2x2::default(), abs <= 1e-6));
+
+    let a = VectorN::new(vec![1.0f64, 2.0, 3.0]);
+    assert_approx_eq!(a, VectorN::new(vec![1.0, 2.0, 3.0 + 1e-12]), abs <= 1e-9);
+    assert!(!approx_eq!(a, VectorN::new(vec![1.0, 2.0]), abs <= 1e9));
+
+    let b = Matrix::from_rows(&[[1.0f64, 2.0, 3.0]]);
+    assert_approx_eq!(b, Matrix::from_rows(&[[1.0, 2.0, f64::from_bits(3.0f64.to_bits() + 1)]]));
+    // same elements, different shape
+    assert!(!approx_eq!(b, Matrix::from_rows(&[[1.0], [2.0], [3.0]]), abs <= 1.0));
+
+    let c = [Complex64::new(1.0, -1.0), Complex64::new(0.5, 0.0)];
+    assert_approx_eq!(c, [Complex64::new(1.0, -1.0 + 1e-12), Complex64::new(0.5, 1e-12)], abs <= 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "(abs <= 0.01)")]
+fn assert_reports_tolerance() {
+    assert_approx_eq!(Vector3::broadcast(1.0), Vector3::broadcast(1.1), abs <= 0.01);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_crate() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+
+    let m = Matrix3x3::from_axis_angle(Vector3::new(0.0, 1.0, 1.0), 2.0);
+    assert_abs_diff_eq!(m * m.transpose(), Matrix3x3::default(), epsilon = 1e-6);
+    assert_relative_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.0000002), max_relative = 1e-6);
+    assert_ulps_eq!(Vector2::new(1.0, 1.0), Vector2::new(1.0, 1.0000001));
+    assert_relative_eq!(VectorN::new(vec![1.0f64, 2.0]), VectorN::new(vec![1.0, f64::from_bits(2.0f64.to_bits() + 1)]));
+}
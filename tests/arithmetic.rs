@@ -0,0 +1,47 @@
+use rmu::matrix::*;
+use rmu::vector::*;
+
+#[test]
+fn vector2_div() {
+    let a = Vector2::new(6.0, 8.0);
+    assert_eq!(a / Vector2::new(2.0, 4.0), Vector2::new(3.0, 2.0));
+}
+
+#[test]
+fn vector4_scalar_product() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v * 2.0, Vector4::new(2.0, 4.0, 6.0, 8.0));
+    assert_eq!(2.0 * v, Vector4::new(2.0, 4.0, 6.0, 8.0));
+}
+
+#[test]
+fn matrix2x2_product() {
+    let a = Matrix2x2::from([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Matrix2x2::from([[5.0, 6.0], [7.0, 8.0]]);
+    assert_eq!(a * b, Matrix2x2::from([[19.0, 22.0], [43.0, 50.0]]));
+}
+
+#[test]
+fn matrix2x2_determinate() {
+    assert_eq!(Matrix2x2::from([[1.0, 2.0], [3.0, 4.0]]).determinate(), -2.0);
+}
+
+#[test]
+fn matrix3x3_sum() {
+    let a = Matrix3x3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let b = Matrix3x3::from([[9.0, 8.0, 7.0], [6.0, 5.0, 4.0], [3.0, 2.0, 1.0]]);
+    assert_eq!(a + b, Matrix3x3::from([[10.0; 3]; 3]));
+}
+
+#[test]
+fn matrix3x3_product() {
+    let a = Matrix3x3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let b = Matrix3x3::from([[9.0, 8.0, 7.0], [6.0, 5.0, 4.0], [3.0, 2.0, 1.0]]);
+    assert_eq!(a * b, Matrix3x3::from([[30.0, 24.0, 18.0], [84.0, 69.0, 54.0], [138.0, 114.0, 90.0]]));
+}
+
+#[test]
+fn matrix4x4_scalar_product() {
+    let m = Matrix4x4::from([[1.0, 2.0, 3.0, 4.0]; 4]);
+    assert_eq!(m * 2.0, Matrix4x4::from([[2.0, 4.0, 6.0, 8.0]; 4]));
+}
@@ -0,0 +1,105 @@
+use num::Float;
+use rmu::autodiff::*;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::numeric::{levenberg_marquardt, Criteria, WithJacobian};
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * a.abs().max(1.0)
+}
+
+/// central difference of a scalar function
+fn fd(f: impl Fn(f64) -> f64, x: f64) -> f64 {
+    let h = 1e-6;
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+#[test]
+fn elementary_functions() {
+    let (v, d) = derivative(|x| x.sin() * x.exp(), 0.7);
+    assert_eq!(v, 0.7f64.sin() * 0.7f64.exp());
+    assert!(close(d, 0.7f64.exp() * (0.7f64.sin() + 0.7f64.cos())));
+
+    let fs: Vec<fn(Dual<f64>) -> Dual<f64>> = vec![
+        |x| x.sqrt(), |x| x.cbrt(), |x| x.ln(), |x| x.log10(), |x| x.log2(), |x| x.exp2(), |x| x.exp_m1(),
+        |x| x.ln_1p(), |x| x.tan(), |x| x.asin(), |x| x.acos(), |x| x.atan(), |x| x.sinh(), |x| x.cosh(),
+        |x| x.tanh(), |x| x.asinh(), |x| x.atanh(), |x| x.recip(), |x| x.powi(3), |x| x.powf(Dual::constant(2.5)),
+        |x| Dual::constant(2.0).powf(x), |x| x.atan2(Dual::constant(0.3)), |x| Dual::constant(0.3).atan2(x),
+        |x| x.hypot(Dual::constant(2.0)), |x| x.abs(), |x| 3.0 / (1.0 - x) * 2.0 + x % Dual::constant(0.25),
+    ];
+    for (i, f) in fs.iter().enumerate() {
+        let (_, d) = derivative(f, 0.4);
+        let num = fd(|x| f(Dual::constant(x)).re, 0.4);
+        assert!((d - num).abs() < 1e-6, "function {}: {} vs {}", i, d, num);
+    }
+    assert_eq!(derivative(|x| x.acosh(), 2.0).1, 1.0 / 3f64.sqrt());
+    assert_eq!(derivative(|x| x.floor() + x.max(Dual::constant(1.0)), 1.5), (2.5, 1.0));
+}
+
+#[test]
+fn ordering_and_zero() {
+    use num::Zero;
+    use std::cmp::Ordering;
+    let (x, c) = (Dual::variable(2.0), Dual::constant(2.0));
+    assert_ne!(x, c);
+    assert_eq!(x.partial_cmp(&c), None);
+    assert_eq!((x <= c, x >= c), (false, false));
+    assert_eq!(x.partial_cmp(&x), Some(Ordering::Equal));
+    assert_eq!(Dual::constant(1.0).partial_cmp(&x), Some(Ordering::Less));
+    assert!(Dual::variable(3.0) > c);
+
+    let g = Gradient::<f64, 2>::variables(&Vector { data: [1.0, 1.0] });
+    assert_eq!(g[0].partial_cmp(&g[1]), None);
+    assert!(g[0] - g[0] == Gradient::zero());
+
+    assert!(Dual::constant(0.0).is_zero());
+    assert!(!Dual::variable(0.0).is_zero());
+}
+
+#[test]
+fn powi_extremes() {
+    assert_eq!(derivative(|x| x.powi(i32::MIN), 1.0), (1.0, i32::MIN as f64));
+    assert_eq!(derivative(|x| x.powi(i32::MAX), 1.0), (1.0, i32::MAX as f64));
+    assert_eq!(derivative(|x| x.powi(-2), 2.0), (0.25, -0.25));
+}
+
+#[test]
+fn through_vectors_and_matrices() {
+    // d/dt |p + t·v| at t = 0 is the component of v along p
+    let t = Dual::variable(0.0);
+    let p = Vector::new([Dual::constant(3.0), Dual::constant(0.0), Dual::constant(4.0)]);
+    let v = Vector::new([Dual::constant(1.0), Dual::constant(2.0), Dual::constant(0.0)]);
+    let l = (p + v * t).length();
+    assert!(l.re == 5.0 && close(l.eps, 0.6));
+    assert_eq!(Vector::dot(p, v * t).eps, 3.0);
+
+    // rotating by an angle θ, the derivative is the rotation by θ + π/2
+    let th = Dual::variable(0.5f64);
+    let m = Matrix::from_rows(&[[th.cos(), -th.sin()], [th.sin(), th.cos()]]);
+    let r = m.mul_vec(&[Dual::constant(1.0), Dual::constant(0.0)]);
+    assert!(close(r[0].eps, -(0.5f64.sin())) && close(r[1].eps, 0.5f64.cos()));
+
+    let n = VectorN::new(vec![Dual::variable(1.0f32), Dual::constant(1.0)]).normalized();
+    assert!((n[0].eps - 0.5 / 2f32.sqrt()).abs() < 1e-6);
+}
+
+#[test]
+fn gradients_and_jacobians() {
+    let (v, g) = gradient(|x| x.data[0] * x.data[1].sin() + x.data[2] * x.data[2], &Vector::new([2.0, 0.5, -1.0]));
+    assert_eq!(v, 2.0 * 0.5f64.sin() + 1.0);
+    assert_eq!(g.data, [0.5f64.sin(), 2.0 * 0.5f64.cos(), -2.0]);
+
+    let (r, j) = jacobian(|x| VectorN::new(vec![x.data[0] * x.data[1], x.data[0] + 2.0 * x.data[1]]), &Vector::new([3.0, 4.0]));
+    assert_eq!(r.as_slice(), &[12.0, 11.0]);
+    assert_eq!(j, Matrix::from_rows(&[[4.0, 3.0], [1.0, 2.0]]));
+
+    // exact jacobians for a least squares fit of a circle centre and radius
+    let pts = [(4.0, 1.0), (1.0, 4.0), (-2.0, 1.0), (1.0, -2.0)];
+    let residual = |p: &Vector<Gradient<f64, 3>, 3>| -> VectorN<Gradient<f64, 3>> {
+        pts.iter().map(|&(x, y)| ((p.data[0] - x).powi(2) + (p.data[1] - y).powi(2)).sqrt() - p.data[2]).collect()
+    };
+    let problem = WithJacobian(|p: &Vector<f64, 3>| jacobian(residual, p).0, |p: &Vector<f64, 3>| jacobian(residual, p).1);
+    let m = levenberg_marquardt(&problem, Vector::new([0.0, 0.0, 1.0]), &Criteria::default());
+    assert!((m.x.data[0] - 1.0).abs() < 1e-8 && (m.x.data[1] - 1.0).abs() < 1e-8 && (m.x.data[2] - 3.0).abs() < 1e-8, "{:?}", m);
+}
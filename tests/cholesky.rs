@@ -0,0 +1,106 @@
+use rmu::matrix::*;
+use rmu::matrix::cholesky::*;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::vector::*;
+use rmu::vector::vectorn::VectorN;
+
+fn spd() -> Matrix<f64> {
+    Matrix::from_rows(&[[4.0, 12.0, -16.0, 2.0]
+                       ,[12.0, 37.0, -43.0, 3.0]
+                       ,[-16.0, -43.0, 98.0, 1.0]
+                       ,[2.0, 3.0, 1.0, 100.0]])
+}
+
+fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>, tol: f64) {
+    for (x, y) in a.data.iter().zip(b.data.iter()) {
+        assert!((x - y).abs() <= tol, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+fn runtime_cholesky() {
+    let a = spd();
+    let c = a.cholesky().unwrap();
+    let l = c.l();
+    assert_eq!((l[(0, 0)], l[(1, 0)], l[(1, 1)], l[(2, 2)]), (2.0, 6.0, 1.0, 3.0));
+    assert_eq!(l[(0, 1)], 0.0);
+    assert_close(&(l * &l.transpose()), &a, 1e-12);
+
+    let b = VectorN { data: vec![1.0, 2.0, 3.0, 4.0] };
+    let x = c.solve(&b);
+    let ax = a.mul_vec(&x.data);
+    assert!(ax.iter().zip(&b.data).all(|(p, q)| (p - q).abs() < 1e-10));
+
+    let inv = c.inverse();
+    assert_close(&(&inv * &a), &Matrix::identity(4), 1e-10);
+    // ∏ lᵢᵢ² and ∏ dᵢ are the same determinant
+    let ldlt = a.ldlt().unwrap();
+    assert!((c.determinant() - ldlt.determinant()).abs() < 1e-8);
+}
+
+#[test]
+fn runtime_ldlt() {
+    let a = spd();
+    let f = a.ldlt().unwrap();
+    assert_eq!(&f.d().data[..3], &[4.0, 1.0, 9.0]);
+    let mut d = Matrix::zeros(4, 4);
+    for i in 0..4 {
+        assert_eq!(f.l()[(i, i)], 1.0);
+        d[(i, i)] = f.d().data[i];
+    }
+    assert_close(&(&(f.l() * &d) * &f.l().transpose()), &a, 1e-12);
+
+    let b = VectorN { data: vec![-1.0, 0.5, 2.0, 0.0] };
+    let x = f.solve(&b);
+    let y = a.cholesky().unwrap().solve(&b);
+    assert!(x.data.iter().zip(&y.data).all(|(p, q)| (p - q).abs() < 1e-10));
+    assert_close(&f.inverse(), &a.cholesky().unwrap().inverse(), 1e-10);
+}
+
+#[test]
+fn fixed_size() {
+    let a = Matrix3x3::from([[4.0, 2.0, 0.4], [2.0, 5.0, 1.0], [0.4, 1.0, 3.0]]);
+    let c = a.cholesky().unwrap();
+    let l = *c.l();
+    let back = l * l.transpose();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((back[i][j] - a[i][j]).abs() < 1e-5);
+        }
+    }
+    assert!((c.determinant() - a.determinate()).abs() < 1e-3);
+    assert!((a.ldlt().unwrap().determinant() - a.determinate()).abs() < 1e-3);
+
+    let b = Vector3::new(1.0, -2.0, 0.5);
+    let x = c.solve(&b);
+    assert!((a * x - b).length() < 1e-5);
+    assert!((a * a.ldlt().unwrap().solve(&b) - b).length() < 1e-5);
+
+    let inv = c.inverse();
+    let id = inv * a;
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((id[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-5);
+        }
+    }
+
+    let m2 = Matrix2x2::from([[2.0, 1.0], [1.0, 2.0]]);
+    assert!((m2.cholesky().unwrap().determinant() - 3.0).abs() < 1e-6);
+    let m4 = Matrix4x4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(m4.ldlt().unwrap().d(), &Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn not_positive_definite() {
+    let indefinite = Matrix::from_rows(&[[1.0, 2.0], [2.0, 1.0]]);
+    assert_eq!(indefinite.cholesky().unwrap_err(), FactorError::NotPositiveDefinite);
+    assert_eq!(indefinite.ldlt().unwrap_err(), FactorError::NotPositiveDefinite);
+
+    let wide = Matrix::from_rows(&[[4.0, 1.0, 0.0], [1.0, 3.0, 0.0]]);
+    assert_eq!(wide.cholesky().unwrap_err(), FactorError::NotSquare { rows: 2, cols: 3 });
+    assert_eq!(wide.ldlt().unwrap_err(), FactorError::NotSquare { rows: 2, cols: 3 });
+
+    let singular = Matrix3x3::from([[1.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert!(singular.cholesky().is_err());
+    assert!(Matrix2x2::from([[f32::NAN, 0.0], [0.0, 1.0]]).cholesky().is_err());
+}
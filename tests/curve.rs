@@ -0,0 +1,174 @@
+use rmu::curve::*;
+use rmu::vector::{Vector2, Vector3};
+
+fn v2(x: f32, y: f32) -> Vector2 {
+    Vector2::new(x, y)
+}
+
+fn near2(a: Vector2, b: Vector2, tol: f32) -> bool {
+    (a - b).length() <= tol
+}
+
+/// central difference against the analytic derivative
+fn check_derivative<C: Curve<Vector2>>(c: &C) {
+    let (a, b) = c.domain();
+    for i in 1..10 {
+        let t = a + (b - a) * i as f32 / 10.0 + 0.013;
+        let h = 1e-3;
+        let fd = (c.eval(t + h) - c.eval(t - h)) / (2.0 * h);
+        assert!(near2(fd, c.derivative(t), 2e-2), "t = {}: {:?} vs {:?}", t, fd, c.derivative(t));
+    }
+}
+
+fn quarter_circle() -> Nurbs<Vector2> {
+    let w = std::f32::consts::FRAC_1_SQRT_2;
+    Nurbs::new(2, vec![v2(1.0, 0.0), v2(1.0, 1.0), v2(0.0, 1.0)], vec![1.0, w, 1.0], vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]).unwrap()
+}
+
+#[test]
+fn bezier() {
+    let c = CubicBezier::new(v2(0.0, 0.0), v2(0.0, 1.0), v2(1.0, 1.0), v2(1.0, 0.0));
+    assert_eq!(c.eval(0.0), v2(0.0, 0.0));
+    assert_eq!(c.eval(1.0), v2(1.0, 0.0));
+    assert_eq!(c.eval(0.5), v2(0.5, 0.75));
+    check_derivative(&c);
+
+    let (left, right) = c.split(0.3);
+    for i in 0..=10 {
+        let u = i as f32 / 10.0;
+        assert!(near2(left.eval(u), c.eval(0.3 * u), 1e-5));
+        assert!(near2(right.eval(u), c.eval(0.3 + 0.7 * u), 1e-5));
+    }
+    let (min, max) = c.bounding_box();
+    assert!(near2(min, v2(0.0, 0.0), 1e-6) && near2(max, v2(1.0, 0.75), 1e-6));
+
+    let q = QuadraticBezier::new(v2(0.0, 0.0), v2(1.0, 2.0), v2(2.0, 0.0));
+    assert_eq!(q.bounding_box(), (v2(0.0, 0.0), v2(2.0, 1.0)));
+    let (a, b) = q.split(0.5);
+    assert_eq!(a.p2, b.p0);
+    assert_eq!(a.p2, q.eval(0.5));
+    check_derivative(&q);
+
+    let line = CubicBezier::new(v2(0.0, 0.0), v2(1.0, 0.0), v2(2.0, 0.0), v2(3.0, 0.0));
+    assert!((line.length() - 3.0).abs() < 1e-5);
+}
+
+#[test]
+fn bspline() {
+    let pts = vec![v2(0.0, 0.0), v2(1.0, 2.0), v2(3.0, 2.0), v2(4.0, 0.0)];
+    // a clamped cubic with four control points is the bézier curve
+    let s = BSpline::uniform(3, pts.clone()).unwrap();
+    let c = CubicBezier::new(pts[0], pts[1], pts[2], pts[3]);
+    for i in 0..=10 {
+        let t = i as f32 / 10.0;
+        assert!(near2(s.eval(t), c.eval(t), 1e-5));
+        assert!(near2(s.derivative(t), c.derivative(t), 1e-4));
+    }
+
+    // degree one is the polyline
+    let poly = BSpline::uniform(1, pts.clone()).unwrap();
+    assert_eq!(poly.knots(), &[0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0]);
+    assert!(near2(poly.eval(1.0 / 6.0), v2(0.5, 1.0), 1e-5));
+
+    let more: Vec<Vector2> = (0..8).map(|i| v2(i as f32, ((i * 7) % 5) as f32)).collect();
+    let s = BSpline::new(3, more.clone(), vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.5, 2.0, 4.0, 5.0, 5.0, 5.0, 5.0]).unwrap();
+    assert_eq!(s.domain(), (0.0, 5.0));
+    assert!(near2(s.eval(0.0), more[0], 1e-6));
+    assert!(near2(s.eval(5.0), more[7], 1e-5));
+    check_derivative(&s);
+
+    assert_eq!(BSpline::new(3, pts.clone(), vec![0.0; 5]), Err(CurveError::CountMismatch { expected: 8, found: 5 }));
+    assert_eq!(BSpline::new(1, pts.clone(), vec![0.0, 0.0, 2.0, 1.0, 3.0, 3.0]), Err(CurveError::DecreasingKnots));
+    assert_eq!(BSpline::uniform(4, pts.clone()), Err(CurveError::NotEnoughPoints { needed: 5, found: 4 }));
+    assert!(matches!(BSpline::new(1, pts.clone(), vec![0.0, 0.0, f32::NAN, 1.0, 1.0, 1.0]), Err(CurveError::InvalidKnot { index: 2, .. })));
+    assert_eq!(BSpline::new(1, pts.clone(), vec![0.0, 0.0, 1.0, 2.0, 3.0, f32::INFINITY]), Err(CurveError::InvalidKnot { index: 5, knot: f32::INFINITY }));
+    let w = vec![1.0; 4];
+    assert!(matches!(Nurbs::new(1, pts, w, vec![f32::NAN, 0.0, 1.0, 2.0, 3.0, 3.0]), Err(CurveError::InvalidKnot { index: 0, .. })));
+}
+
+#[test]
+fn nurbs_weights() {
+    let pts = vec![v2(0.0, 0.0), v2(1.0, 1.0), v2(2.0, 0.0)];
+    assert_eq!(Nurbs::uniform(2, pts.clone(), vec![1.0, 0.0, 1.0]).unwrap_err(), CurveError::InvalidWeight { index: 1, weight: 0.0 });
+    assert_eq!(Nurbs::uniform(2, pts.clone(), vec![1.0, 1.0, -2.0]).unwrap_err(), CurveError::InvalidWeight { index: 2, weight: -2.0 });
+    assert!(matches!(Nurbs::uniform(2, pts.clone(), vec![f32::NAN, 1.0, 1.0]), Err(CurveError::InvalidWeight { index: 0, .. })));
+    assert!(matches!(Nurbs::uniform(2, pts.clone(), vec![1.0, f32::INFINITY, 1.0]), Err(CurveError::InvalidWeight { index: 1, .. })));
+    assert!(Nurbs::uniform(2, pts, vec![0.5, 2.0, 1.0]).is_ok());
+}
+
+#[test]
+fn nurbs_circle() {
+    let c = quarter_circle();
+    for i in 0..=20 {
+        assert!((c.eval(i as f32 / 20.0).length() - 1.0).abs() < 1e-6);
+    }
+    check_derivative(&c);
+    assert!((c.length() - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+    assert_eq!(c.control_point(1).1, std::f32::consts::FRAC_1_SQRT_2);
+
+    let (_, p) = c.closest_point(v2(2.0, 2.0));
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    assert!(near2(p, v2(h, h), 1e-4));
+
+    // equal weights give back the plain b-spline
+    let pts = vec![v2(0.0, 0.0), v2(1.0, 3.0), v2(2.0, -1.0), v2(4.0, 0.0), v2(5.0, 2.0)];
+    let n = Nurbs::uniform(3, pts.clone(), vec![2.0; 5]).unwrap();
+    let s = BSpline::uniform(3, pts).unwrap();
+    assert!(near2(n.eval(0.4), s.eval(0.4), 1e-5));
+}
+
+#[test]
+fn hermite_and_catmull_rom() {
+    let pts = vec![v2(0.0, 0.0), v2(1.0, 1.0), v2(3.0, 1.0), v2(4.0, 3.0)];
+    let tangents = vec![v2(1.0, 0.0), v2(1.0, 1.0), v2(2.0, 0.0), v2(0.0, 1.0)];
+    let h = Hermite::new(pts.clone(), tangents.clone()).unwrap();
+    for i in 0..4 {
+        assert!(near2(h.eval(i as f32), pts[i], 1e-6));
+        assert!(near2(h.derivative(i as f32), tangents[i], 1e-5));
+    }
+    check_derivative(&h);
+    assert_eq!(Hermite::new(pts.clone(), vec![]), Err(CurveError::CountMismatch { expected: 4, found: 0 }));
+
+    let c = CatmullRom::new(pts.clone()).unwrap();
+    for (i, p) in pts.iter().enumerate() {
+        assert!(near2(c.eval(i as f32), *p, 1e-6));
+    }
+    // uniform tangents are half the chord over the neighbours, continuous at the joints
+    assert!(near2(c.derivative(1.0), (pts[2] - pts[0]) * 0.5, 1e-5));
+    assert!(near2(c.derivative(0.999_9), c.derivative(1.0), 1e-2));
+    check_derivative(&c);
+
+    let cc = CatmullRom::centripetal(pts.clone()).unwrap();
+    for (i, p) in pts.iter().enumerate() {
+        assert!(near2(cc.eval(i as f32), *p, 1e-6));
+    }
+    check_derivative(&cc);
+}
+
+#[test]
+fn arc_length_parameterization() {
+    // a straight line whose parameter speeds up and slows down
+    let c = CubicBezier::new(v2(0.0, 0.0), v2(2.9, 0.0), v2(2.95, 0.0), v2(3.0, 0.0));
+    let table = ArcLengthTable::new(&c, 256);
+    assert!((table.length() - 3.0).abs() < 1e-4);
+    for s in [0.0f32, 0.5, 1.5, 2.25, 3.0] {
+        let p = c.eval(table.param_at(s));
+        assert!((p.x - s).abs() < 1e-2, "s = {}: {:?}", s, p);
+    }
+    assert_eq!(table.param_at(-1.0), 0.0);
+    assert_eq!(table.param_at_fraction(1.0), 1.0);
+}
+
+#[test]
+fn curves_in_3d() {
+    let c = CubicBezier::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0), Vector3::new(1.0, 1.0, 1.0));
+    let (min, max) = c.bounding_box();
+    assert_eq!((min, max), (Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)));
+    let (t, p) = c.closest_point(Vector3::new(1.0, 1.0, 2.0));
+    assert!((t - 1.0).abs() < 1e-3);
+    assert!((p - Vector3::new(1.0, 1.0, 1.0)).length() < 1e-3);
+
+    let s = CatmullRom::centripetal(vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)]).unwrap();
+    assert_eq!(s.eval(2.0), Vector3::new(1.0, 1.0, 1.0));
+    assert!(s.length() > 2.0);
+}
@@ -0,0 +1,78 @@
+use rmu::combinatorics::graph::*;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::matrix::sparse::CooMatrix;
+use rmu::matrix::*;
+use rmu::vector::soa::{Vector3SoA, Vector3x8};
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+use rmu::vector::*;
+
+#[test]
+fn vectors() {
+    let v = Vector3::new(1.0, -2.5, 3.0);
+    assert_eq!(v.to_string(), "(1, -2.5, 3)");
+    assert_eq!(format!("{:.2}", v), "(1.00, -2.50, 3.00)");
+    assert_eq!(format!("{:6.1}", v), "(   1.0,   -2.5,    3.0)");
+    assert_eq!(format!("{:<5}", Vector2::new(1.0, 2.0)), "(1    , 2    )");
+    assert_eq!(format!("{:+}", Vector4::new(1.0, -1.0, 0.0, 2.0)), "(+1, -1, +0, +2)");
+
+    assert_eq!(Vector::new([1, 2, 3]).to_string(), "(1, 2, 3)");
+    assert_eq!(format!("{:.1}", VectorN::new(vec![0.25f64, 1.0])), "(0.2, 1.0)");
+    assert_eq!(VectorN::<f64>::new(vec![]).to_string(), "()");
+}
+
+#[test]
+fn matrices() {
+    let m = Matrix2x2::from([[1.0, -20.5], [300.0, 4.0]]);
+    assert_eq!(m.to_string(), "[  1, -20.5]\n[300,     4]");
+    assert_eq!(format!("{:.1}", m), "[  1.0, -20.5]\n[300.0,   4.0]");
+    assert_eq!(format!("{:#}", m), "[[1, -20.5], [300, 4]]");
+    assert_eq!(format!("{:#6.1}", m), "[[   1.0,  -20.5], [ 300.0,    4.0]]");
+
+    let id = Matrix3x3::default();
+    assert_eq!(id.to_string(), "[1, 0, 0]\n[0, 1, 0]\n[0, 0, 1]");
+    assert_eq!(format!("{:.0}", Matrix4x4::default()).lines().count(), 4);
+
+    let a = Matrix::from_rows(&[[1, 22, 3], [-4, 5, 666]]);
+    assert_eq!(a.to_string(), "[ 1, 22,   3]\n[-4,  5, 666]");
+    assert_eq!(Matrix::<i32>::from_vec(0, 3, vec![]).to_string(), "[]");
+}
+
+#[test]
+fn sparse_and_soa() {
+    let mut coo = CooMatrix::new(3, 4);
+    coo.push(2, 3, -1.5);
+    coo.push(0, 1, 2.0);
+    let csr = coo.to_csr();
+    assert_eq!(csr.to_string(), "3 x 4 csr, 2 nonzeros\n(0, 1)    2\n(2, 3) -1.5");
+    assert_eq!(format!("{:#.1}", csr), "{(0, 1): 2.0, (2, 3): -1.5}");
+    assert_eq!(format!("{:#}", coo.to_csc()), "{(0, 1): 2, (2, 3): -1.5}");
+
+    let s = Vector3x8::splat(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(format!("{}", s).lines().next(), Some("[1, 2, 3]"));
+    assert_eq!(format!("{}", s).lines().count(), 8);
+
+    let soa = Vector3SoA::from_components(vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]);
+    assert_eq!(format!("{:#}", soa), "[[1, 2, 3], [4, 5, 6]]");
+    assert_eq!(Vector3SoA::new().to_string(), "[]");
+}
+
+#[test]
+fn graphs() {
+    let mut g = Graph::<()>::new();
+    for n in ["c", "a", "b", "d"] {
+        g.add_node(n.into(), ());
+    }
+    g.connect("j".into(), "a".into(), "c".into()).unwrap();
+    g.connect("i".into(), "a".into(), "b".into()).unwrap();
+    assert_eq!(g.to_string(), "a -- b (i), c (j)\nb -- a (i)\nc -- a (j)\nd");
+
+    let mut dg = DGraph::<()>::new();
+    for n in ["a", "b", "c"] {
+        dg.add_node(n.into(), ());
+    }
+    dg.connect("a".into(), "c".into()).unwrap();
+    dg.connect("a".into(), "b".into()).unwrap();
+    dg.connect("b".into(), "c".into()).unwrap();
+    assert_eq!(dg.to_string(), "a -> b, c\nb -> c\nc");
+}
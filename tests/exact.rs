@@ -0,0 +1,96 @@
+use num::rational::{BigRational, Ratio};
+use num::{BigInt, One, Zero};
+use rmu::matrix::exact::ExactError;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+
+type Q = Ratio<i64>;
+
+fn q(n: i64, d: i64) -> Q {
+    Ratio::new(n, d)
+}
+
+fn big(n: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(n))
+}
+
+#[test]
+fn determinants() {
+    let a = Matrix::from_rows(&[[2i64, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+    assert_eq!(a.bareiss_determinant(), Ok(4));
+    // needs a row swap
+    let b = Matrix::from_rows(&[[0i64, 1, 2], [1, 0, 3], [4, -3, 8]]);
+    assert_eq!(b.bareiss_determinant(), Ok(-2));
+    assert_eq!(Matrix::from_rows(&[[1i64, 2], [2, 4]]).bareiss_determinant(), Ok(0));
+    assert_eq!(Matrix::<i64>::zeros(2, 3).bareiss_determinant(), Err(ExactError::NotSquare { rows: 2, cols: 3 }));
+
+    // hilbert matrices are famously ill conditioned, exact arithmetic doesn't care
+    let n = 5;
+    let h = Matrix::from_vec(n, n, (0..n * n).map(|k| q(1, (k / n + k % n + 1) as i64)).collect());
+    assert_eq!(h.bareiss_determinant(), Ok(q(1, 266_716_800_000)));
+
+    // bigger than i64 holds
+    let v = Matrix::from_vec(8, 8, (0..64).map(|k| big(((k / 8 + 2) as i64).pow((k % 8) as u32))).collect());
+    let det = v.bareiss_determinant().unwrap();
+    // vandermonde: Π (xⱼ - xᵢ) over i < j with xᵢ = i + 2
+    let mut expected = big(1);
+    for i in 0..8 {
+        for j in i + 1..8 {
+            expected *= big(j - i);
+        }
+    }
+    assert_eq!(det, expected);
+}
+
+#[test]
+fn rank_nullspace_and_solve() {
+    let a = Matrix::from_rows(&[[1i64, 2, 3, 4], [2, 4, 6, 8], [1, 0, 1, 0]]);
+    let r = a.rref_exact();
+    assert_eq!(r.rank(), 2);
+    assert_eq!(r.pivots, vec![0, 1]);
+    let null = a.nullspace_exact();
+    assert_eq!(null.len(), 2);
+    for x in &null {
+        assert!(a.mul_vec(x.as_slice()).iter().all(|v| *v == 0), "{:?}", x);
+    }
+    assert_eq!(Matrix::<i64>::zeros(3, 2).rank_exact(), 0);
+    assert_eq!(Matrix::<i64>::identity(3).nullspace_exact().len(), 0);
+
+    let m = Matrix::from_rows(&[[q(1, 2), q(1, 3)], [q(1, 4), q(-1, 5)]]);
+    let b = VectorN::new(vec![q(1, 1), q(0, 1)]);
+    let x = m.solve_exact(&b).unwrap();
+    assert_eq!(m.mul_vec(x.as_slice()), b.data);
+    assert_eq!(x.data, vec![q(24, 22), q(30, 22)]);
+
+    let singular = Matrix::from_rows(&[[q(1, 1), q(2, 1)], [q(1, 2), q(1, 1)]]);
+    assert_eq!(singular.solve_exact(&b), Err(ExactError::Singular));
+    assert_eq!(m.solve_exact(&VectorN::new(vec![q(1, 1)])), Err(ExactError::DimensionMismatch { expected: 2, found: 1 }));
+
+    let bm = Matrix::from_rows(&[[big(3), big(1)], [big(1), big(2)]]);
+    let bx = bm.solve_exact(&VectorN::new(vec![big(9), big(8)])).unwrap();
+    assert_eq!(bx.data, vec![big(2), big(3)]);
+}
+
+#[test]
+fn rational_vectors() {
+    let a = Vector::new([q(1, 2), q(1, 3), q(1, 6)]);
+    let b = Vector::broadcast(q(1, 1));
+    assert_eq!(Vector::dot(a, b), Q::one());
+    assert_eq!(a.length_square(), q(7, 18));
+    assert_eq!((a - a).data, [Q::zero(); 3]);
+
+    let a = Vector::new([big(1), big(2), big(-3)]);
+    let b = Vector::broadcast(big(1) / big(2));
+    assert_eq!(Vector::dot(a.clone(), b.clone()), Zero::zero());
+    assert_eq!(a.length_square(), big(14));
+    assert_eq!((a.clone() - b.clone() * big(2)).data, [big(0), big(1), big(-4)]);
+    assert_eq!((-(a.clone() / big(2)))[2], big(3) / big(2));
+    assert_eq!(Vector::<BigRational, 2>::default().data, [big(0), big(0)]);
+
+    let u = VectorN::new(vec![big(1), big(2)]);
+    let v = VectorN::new(vec![big(3), big(-1)]);
+    assert!(VectorN::dot(&u, &v).is_one());
+    assert_eq!((u.clone() + v.clone()) * big(2), VectorN::new(vec![big(8), big(2)]));
+    assert_eq!(&Matrix::from_rows(&[[big(1), big(2)]]) * &Matrix::from_cols(&[[big(3), big(-1)]]), Matrix::from_rows(&[[big(1)]]));
+}
@@ -0,0 +1,97 @@
+use rmu::complex::{Complex, Complex64};
+use rmu::fft::*;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+
+fn dft(x: &[Complex64]) -> Vec<Complex64> {
+    let n = x.len();
+    (0..n).map(|k| {
+        x.iter().enumerate().fold(Complex::new(0.0, 0.0), |acc, (j, v)| {
+            let a = -2.0 * std::f64::consts::PI * ((j * k) % n) as f64 / n as f64;
+            acc + v * Complex::new(a.cos(), a.sin())
+        })
+    }).collect()
+}
+
+fn signal(n: usize) -> Vec<Complex64> {
+    (0..n).map(|i| Complex::new((i as f64 * 0.37).sin() + 0.1 * i as f64, (i as f64 * 1.3).cos())).collect()
+}
+
+fn max_error(a: &[Complex64], b: &[Complex64]) -> f64 {
+    assert_eq!(a.len(), b.len());
+    a.iter().zip(b).map(|(x, y)| (x - y).norm()).fold(0.0, f64::max)
+}
+
+#[test]
+fn matches_the_dft() {
+    // powers of two, mixed radix and bluestein sizes
+    for n in (0..=40).chain([64, 97, 128, 134, 210, 1000]) {
+        let x = signal(n);
+        let y = fft(&x);
+        assert!(max_error(y.as_slice(), &dft(&x)) < 1e-9 * (n.max(1) as f64), "n = {}", n);
+        assert!(max_error(ifft(y.as_slice()).as_slice(), &x) < 1e-12 * (n.max(1) as f64), "n = {}", n);
+    }
+
+    let plan = Fft::<f32>::new(12);
+    let mut data = vec![Complex::new(1.0f32, 0.0); 12];
+    plan.forward(&mut data);
+    assert!((data[0].re - 12.0).abs() < 1e-5 && data[1..].iter().all(|z| z.norm() < 1e-5));
+}
+
+#[test]
+fn real_signals() {
+    for n in [1, 2, 7, 8, 15, 16, 30, 101] {
+        let x: Vec<f64> = (0..n).map(|i| (i as f64 * 0.7).sin() + i as f64 * 0.01).collect();
+        let spectrum = rfft(&x);
+        assert_eq!(spectrum.len(), n / 2 + 1);
+        let full = dft(&x.iter().map(|&v| Complex::new(v, 0.0)).collect::<Vec<_>>());
+        assert!(max_error(spectrum.as_slice(), &full[..n / 2 + 1]) < 1e-10, "n = {}", n);
+        let back = irfft(spectrum.as_slice(), n);
+        assert!(back.iter().zip(&x).all(|(a, b)| (a - b).abs() < 1e-12), "n = {}", n);
+    }
+
+    // the empty signal round trips through its single zero bin
+    let empty = rfft::<f64>(&[]);
+    assert_eq!(empty.as_slice(), &[Complex::new(0.0, 0.0)]);
+    assert!(irfft(empty.as_slice(), 0).is_empty());
+
+    // a pure tone lands in its bin
+    let tone: Vec<f64> = (0..64).map(|i| (2.0 * std::f64::consts::PI * 5.0 * i as f64 / 64.0).cos()).collect();
+    let mags = rfft(&tone).magnitudes();
+    assert!((mags[5] - 32.0).abs() < 1e-9);
+    assert!(mags.iter().enumerate().all(|(k, m)| k == 5 || *m < 1e-9));
+}
+
+#[test]
+fn convolution() {
+    let a = [1.0f64, 2.0, 3.0];
+    let b = [0.0, 1.0, 0.5, -1.0];
+    let c = convolve(&a, &b);
+    let expected = [0.0, 1.0, 2.5, 3.0, -0.5, -3.0];
+    assert!(c.iter().zip(&expected).all(|(x, y)| (x - y).abs() < 1e-12), "{:?}", c);
+    assert!(convolve::<f64>(&[], &b).is_empty());
+
+    let za: Vec<Complex64> = signal(9);
+    let zb: Vec<Complex64> = signal(5);
+    let direct: Vec<Complex64> = (0..13).map(|k| {
+        (0..9).filter(|i| k >= *i && k - i < 5).fold(Complex::new(0.0, 0.0), |acc, i| acc + za[i] * zb[k - i])
+    }).collect();
+    assert!(max_error(convolve_complex(&za, &zb).as_slice(), &direct) < 1e-10);
+}
+
+#[test]
+fn complex_scalars() {
+    let i = Complex::new(0.0, 1.0);
+    let v = Vector::new([i, Complex::new(1.0, 0.0)]);
+    assert_eq!(Vector::dot(v, v), Complex::new(0.0, 0.0));
+    assert_eq!((v * i).data, [Complex::new(-1.0, 0.0), i]);
+
+    let n = VectorN::new(vec![i, Complex::new(3.0, 4.0)]);
+    assert_eq!(n.norm(), 26f64.sqrt());
+    assert_eq!(VectorN::hermitian_dot(&n, &n), Complex::new(26.0, 0.0));
+    assert_eq!(n.conj()[0], -i);
+
+    let m = Matrix::from_rows(&[[i, Complex::new(0.0, 0.0)], [Complex::new(0.0, 0.0), i]]);
+    assert_eq!(m.mul_vec(&[i, i]), vec![Complex::new(-1.0, 0.0); 2]);
+}
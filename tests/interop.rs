@@ -0,0 +1,84 @@
+#![cfg(any(feature = "mint", feature = "glam", feature = "nalgebra", feature = "cgmath"))]
+
+use rmu::matrix::*;
+use rmu::vector::*;
+
+fn sample() -> Matrix4x4 {
+    Matrix4x4::translate3(1.0, 2.0, 3.0) * Matrix4x4::from([[1.0, 2.0, 0.0, 0.0]
+                                                          ,[0.0, 1.0, 0.0, 0.0]
+                                                          ,[0.0, 0.0, 3.0, 0.0]
+                                                          ,[0.0, 0.0, 0.0, 1.0]])
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_round_trip() {
+    let v: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!((v.x, v.y, v.z), (1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+
+    let m = sample();
+    let rows: mint::RowMatrix4<f32> = m.into();
+    let cols: mint::ColumnMatrix4<f32> = m.into();
+    assert_eq!(rows.x, mint::Vector4 { x: 1.0, y: 2.0, z: 0.0, w: 1.0 });
+    // translation is the last column
+    assert_eq!(cols.w, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+    let back: Matrix4x4 = rows.into();
+    assert_eq!(back, m);
+    let back: Matrix4x4 = cols.into();
+    assert_eq!(back, m);
+
+    let m2 = Matrix2x2::rotate2(0.3);
+    let cols: mint::ColumnMatrix2<f32> = m2.into();
+    assert_eq!(cols.x, mint::Vector2 { x: m2[0][0], y: m2[1][0] });
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_same_mapping() {
+    let m = sample();
+    let g: glam::Mat4 = m.into();
+    assert_eq!(g.w_axis, glam::Vec4::new(1.0, 2.0, 3.0, 1.0));
+
+    let v = Vector4::new(0.5, -1.0, 2.0, 1.0);
+    let expect = m * v;
+    let got = g * glam::Vec4::from(v);
+    assert_eq!(Vector4::from(got), expect);
+    let back: Matrix4x4 = g.into();
+    assert_eq!(back, m);
+
+    let m3 = Matrix3x3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+    let g3: glam::Mat3 = m3.into();
+    assert_eq!(g3.row(0), glam::Vec3::new(1.0, 2.0, 3.0));
+    let back: Matrix3x3 = g3.into();
+    assert_eq!(back, m3);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_same_mapping() {
+    let m = sample();
+    let n: nalgebra::Matrix4<f32> = m.into();
+    assert_eq!(n[(0, 3)], 1.0);
+    assert_eq!(n[(0, 1)], 2.0);
+
+    let v = Vector4::new(0.5, -1.0, 2.0, 1.0);
+    let got = n * nalgebra::Vector4::from(v);
+    assert_eq!(Vector4::from(got), m * v);
+    let back: Matrix4x4 = n.into();
+    assert_eq!(back, m);
+}
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn cgmath_same_mapping() {
+    let m = sample();
+    let c: cgmath::Matrix4<f32> = m.into();
+    assert_eq!(c.w, cgmath::Vector4::new(1.0, 2.0, 3.0, 1.0));
+
+    let v = Vector4::new(0.5, -1.0, 2.0, 1.0);
+    let got = c * cgmath::Vector4::from(v);
+    assert_eq!(Vector4::from(got), m * v);
+    let back: Matrix4x4 = c.into();
+    assert_eq!(back, m);
+}
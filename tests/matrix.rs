@@ -0,0 +1,62 @@
+use rmu::matrix::*;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::vector::*;
+
+#[test]
+fn column_vector_convention() {
+    let t = Matrix4x4::translate3(1.0, 2.0, 3.0);
+    assert_eq!(t * Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(t.col(3), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(t.row(0), Vector4::new(1.0, 0.0, 0.0, 1.0));
+
+    // column-major export puts the translation in the last four elements
+    let cols = t.to_cols_array();
+    assert_eq!(&cols[12..], &[1.0, 2.0, 3.0, 1.0]);
+    assert_eq!(Matrix4x4::from_cols_array(&cols), t);
+    assert_eq!(Matrix4x4::from_rows_array(&t.to_rows_array()), t);
+}
+
+#[test]
+fn rows_and_cols() {
+    let m = Matrix3x3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(Matrix3x3::from_rows(m.row(0), m.row(1), m.row(2)), m);
+    assert_eq!(Matrix3x3::from_cols(m.col(0), m.col(1), m.col(2)), m);
+    assert_eq!(m.transpose().row(0), m.col(0));
+    assert_eq!(m.transpose().transpose(), m);
+    assert_eq!(m.to_cols_array(), [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+
+    let m2 = Matrix2x2::from_cols(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0));
+    assert_eq!(m2.data, [[1.0, 3.0], [2.0, 4.0]]);
+    assert_eq!(m2.to_rows_array(), [1.0, 3.0, 2.0, 4.0]);
+}
+
+#[test]
+fn product_transpose() {
+    let a = Matrix2x2::from([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Matrix2x2::from([[0.0, 1.0], [5.0, -1.0]]);
+    assert_eq!((a * b).transpose(), b.transpose() * a.transpose());
+    assert_eq!(a * b, Matrix2x2::from([[10.0, -1.0], [20.0, -1.0]]));
+
+    let a = Matrix3x3::from([[1.0, 2.0, 0.0], [0.0, 1.0, 4.0], [2.0, 0.0, 1.0]]);
+    let b = Matrix3x3::from([[3.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 2.0, 1.0]]);
+    assert_eq!((a * b).transpose(), b.transpose() * a.transpose());
+
+    let a = Matrix4x4::translate3(1.0, 2.0, 3.0);
+    let b = Matrix4x4::new(2.0, 3.0, 4.0, 1.0);
+    assert_eq!((a * b).transpose(), b.transpose() * a.transpose());
+}
+
+#[test]
+fn runtime_sized() {
+    let m = Matrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!((m.n, m.m), (2, 3));
+    assert_eq!(m.row(1), &[4, 5, 6]);
+    assert_eq!(m.col(2), vec![3, 6]);
+    assert_eq!(m[(1, 0)], 4);
+
+    let t = m.transpose();
+    assert_eq!((t.n, t.m), (3, 2));
+    assert_eq!(t.row(2), &[3, 6]);
+    assert_eq!(m.to_cols_array(), vec![1, 4, 2, 5, 3, 6]);
+    assert_eq!(Matrix::from_cols(&[vec![1, 4], vec![2, 5], vec![3, 6]]).to_rows_array(), m.to_rows_array());
+}
@@ -0,0 +1,110 @@
+use num::BigInt;
+use rmu::matrix::exact::ExactError;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::number_theory::*;
+use rmu::vector::vectorn::VectorN;
+
+#[test]
+fn gcd_and_modular_arithmetic() {
+    assert_eq!(gcd(84i64, -36), 12);
+    assert_eq!(lcm(4u32, 6), 12);
+    let (g, x, y) = extended_gcd(240i64, 46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+    assert_eq!(extended_gcd(-4i64, 0).0, 4);
+
+    assert_eq!(mod_pow(4, 13, 497), 445);
+    assert_eq!(mod_pow(u64::MAX - 1, u64::MAX, u64::MAX), u64::MAX - 1);
+    assert_eq!(mod_pow(7, 0, 1), 0);
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(6, 9), None);
+
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    // moduli that share factors
+    assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(crt(&[]), Some((0, 1)));
+    let big = [(123_456_789, 4_294_967_291), (987_654_321, 4_294_967_279)];
+    let (x, m) = crt(&big).unwrap();
+    assert_eq!(m, 4_294_967_291 * 4_294_967_279);
+    assert!(big.iter().all(|&(r, mi)| x % mi == r));
+}
+
+#[test]
+fn primes() {
+    let small = sieve(100);
+    assert_eq!(small.len(), 25);
+    assert_eq!(&small[..5], &[2, 3, 5, 7, 11]);
+    assert!(sieve(1).is_empty());
+    for n in 0..1000u64 {
+        assert_eq!(is_prime(n), small.contains(&(n as usize)) || (n > 100 && sieve(1000).contains(&(n as usize))), "{}", n);
+    }
+    // strong pseudoprime to bases 2..=23 and a carmichael number
+    assert!(!is_prime(3_825_123_056_546_413_051));
+    assert!(!is_prime(561));
+    assert!(is_prime(18_446_744_073_709_551_557));
+
+    assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(factorize(1), vec![]);
+    assert_eq!(factorize(4_294_967_291 * 4_294_967_279), vec![(4_294_967_279, 1), (4_294_967_291, 1)]);
+    assert_eq!(factorize(1_000_000_007u64.pow(2)), vec![(1_000_000_007, 2)]);
+    let n = 600_851_475_143;
+    assert_eq!(factorize(n), vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+}
+
+type F7 = Zp<7>;
+
+#[test]
+fn matrices_mod_p() {
+    let z = |n: i64| F7::from(n);
+    assert_eq!(z(3) * z(5), z(1));
+    assert_eq!(z(3) / z(5), z(2));
+    assert_eq!(-z(3), z(4));
+    assert_eq!(z(-1).value(), 6);
+    assert_eq!(z(3).pow(6), z(1));
+    assert_eq!(z(0).inverse(), None);
+    assert_eq!(format!("{}", z(10)), "3");
+
+    let a = Matrix::from_rows(&[[z(1), z(2), z(3)], [z(0), z(1), z(4)], [z(5), z(6), z(0)]]);
+    assert_eq!(a.bareiss_determinant(), Ok(z(1)));
+    let inv = a.inverse_exact().unwrap();
+    assert_eq!(&a * &inv, Matrix::identity(3));
+
+    // singular mod 7 though not over the integers
+    let s = Matrix::from_rows(&[[z(1), z(2)], [z(3), z(13)]]);
+    assert_eq!(s.bareiss_determinant(), Ok(z(0)));
+    assert_eq!(s.inverse_exact(), Err(ExactError::Singular));
+    assert_eq!(s.rank_exact(), 1);
+    let x = s.solve_exact(&VectorN::new(vec![z(1), z(0)]));
+    assert_eq!(x, Err(ExactError::Singular));
+}
+
+fn is_unimodular(m: &Matrix<i64>) -> bool {
+    m.bareiss_determinant().map(|d| d.abs() == 1).unwrap_or(false)
+}
+
+#[test]
+fn normal_forms() {
+    let a = Matrix::from_rows(&[[2i64, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+    let hnf = a.hermite_normal_form();
+    assert_eq!(&hnf.u * &a, hnf.h);
+    assert!(is_unimodular(&hnf.u));
+    assert_eq!(hnf.h, Matrix::from_rows(&[[1, 0, 50, -11], [0, 3, 28, -2], [0, 0, 61, -13]]));
+
+    let b = Matrix::from_rows(&[[2i64, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+    let snf = b.smith_normal_form();
+    assert_eq!(&(&snf.u * &b) * &snf.v, snf.d);
+    assert!(is_unimodular(&snf.u) && is_unimodular(&snf.v));
+    assert_eq!(snf.invariant_factors(), vec![2, 6, 12]);
+
+    // rank deficient and non square
+    let c = Matrix::from_rows(&[[6i64, 4], [9, 6], [3, 2]]);
+    let snf = c.smith_normal_form();
+    assert_eq!(snf.invariant_factors(), vec![1]);
+    assert_eq!(&(&snf.u * &c) * &snf.v, snf.d);
+    let hnf = c.hermite_normal_form();
+    assert_eq!(hnf.h, Matrix::from_rows(&[[3, 2], [0, 0], [0, 0]]));
+
+    let big = Matrix::from_rows(&[[BigInt::from(4), BigInt::from(6)], [BigInt::from(10), BigInt::from(4)]]);
+    assert_eq!(big.smith_normal_form().invariant_factors(), vec![BigInt::from(2), BigInt::from(22)]);
+}
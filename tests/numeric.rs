@@ -0,0 +1,78 @@
+use rmu::numeric::*;
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+use std::f64::consts::PI;
+
+#[test]
+fn quadrature() {
+    let exact = 2.0;
+    assert!((adaptive_simpson(f64::sin, 0.0, PI, 1e-10, 50) - exact).abs() < 1e-9);
+    assert!((romberg(f64::sin, 0.0, PI, 1e-10, 20) - exact).abs() < 1e-9);
+    assert!((gauss_legendre(f64::sin, 0.0, PI, 12) - exact).abs() < 1e-12);
+
+    // exact for polynomials up to degree 2n - 1
+    let rule = GaussLegendre::<f64>::new(3);
+    assert!((rule.integrate(|x| x.powi(5) - 2.0 * x * x, 0.0, 2.0) - (64.0 / 6.0 - 16.0 / 3.0)).abs() < 1e-12);
+    let w: f64 = rule.weights().iter().sum();
+    assert!((w - 2.0).abs() < 1e-14);
+    assert_eq!(rule.nodes()[1], 0.0);
+    assert!((rule.nodes()[2] - 0.6f64.sqrt()).abs() < 1e-14);
+
+    // f32 works too
+    assert!((adaptive_simpson(|x: f32| x.exp(), 0.0, 1.0, 1e-6, 30) - (1f32.exp() - 1.0)).abs() < 1e-5);
+    // a kink needs the adaptivity
+    assert!((adaptive_simpson(|x: f64| x.abs().sqrt(), -1.0, 1.0, 1e-9, 60) - 4.0 / 3.0).abs() < 1e-7);
+}
+
+/// harmonic oscillator x'' = -x as a first order system
+fn oscillator(_: f64, y: &Vector<f64, 2>) -> Vector<f64, 2> {
+    Vector::new([y.data[1], -y.data[0]])
+}
+
+#[test]
+fn fixed_step() {
+    let y0 = Vector::new([1.0, 0.0]);
+    let y = rk4(oscillator, 0.0, y0, 2.0 * PI, 200);
+    assert!((y.data[0] - 1.0).abs() < 1e-7 && y.data[1].abs() < 1e-7);
+
+    // euler is first order: halving the step halves the error
+    let decay = |_: f64, y: &VectorN<f64>| VectorN::new(vec![-y[0]]);
+    let e1 = (euler(decay, 0.0, VectorN::new(vec![1.0]), 1.0, 100)[0] - (-1f64).exp()).abs();
+    let e2 = (euler(decay, 0.0, VectorN::new(vec![1.0]), 1.0, 200)[0] - (-1f64).exp()).abs();
+    assert!((e1 / e2 - 2.0).abs() < 0.05);
+    assert_eq!(euler_step(decay, 0.0, &VectorN::new(vec![2.0]), 0.5)[0], 1.0);
+}
+
+#[test]
+fn adaptive() {
+    let solution = rk45(oscillator, 0.0, Vector::new([1.0, 0.0]), 10.0, &Rk45Options::default()).unwrap();
+    let y = solution.last();
+    assert_eq!(*solution.t.last().unwrap(), 10.0);
+    assert!((y.data[0] - 10f64.cos()).abs() < 1e-5 && (y.data[1] + 10f64.sin()).abs() < 1e-5);
+    assert_eq!(solution.t.len(), solution.y.len());
+    assert!(solution.t.len() < 200);
+
+    // backwards in time on a runtime sized state
+    let grow = |t: f64, y: &VectorN<f64>| VectorN::new(vec![y[0] * t, 1.0]);
+    let back = rk45(grow, 1.0, VectorN::new(vec![1.0, 1.0]), 0.0, &Rk45Options::default()).unwrap();
+    let y = back.last();
+    assert!((y[0] - (-0.5f64).exp()).abs() < 1e-6 && y[1].abs() < 1e-8);
+
+    let options = Rk45Options { max_steps: 3, ..Rk45Options::default() };
+    assert!(matches!(rk45(oscillator, 0.0, Vector::new([1.0, 0.0]), 100.0, &options), Err(OdeError::TooManySteps { .. })));
+}
+
+#[test]
+fn verlet_conserves_energy() {
+    let a = |_: f64, x: &Vector<f64, 1>| Vector::new([-x.data[0]]);
+    let energy = |x: &Vector<f64, 1>, v: &Vector<f64, 1>| 0.5 * (x.data[0] * x.data[0] + v.data[0] * v.data[0]);
+    let (mut x, mut v) = (Vector::new([1.0]), Vector::new([0.0]));
+    for i in 0..10_000 {
+        let (x1, v1) = velocity_verlet_step(a, i as f64 * 0.1, &x, &v, 0.1);
+        x = x1;
+        v = v1;
+        assert!((energy(&x, &v) - 0.5).abs() < 2e-3);
+    }
+    let (x, _) = velocity_verlet(a, 0.0, Vector::new([1.0]), Vector::new([0.0]), PI, 1000);
+    assert!((x.data[0] + 1.0).abs() < 1e-5);
+}
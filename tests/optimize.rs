@@ -0,0 +1,129 @@
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::numeric::*;
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+
+#[test]
+fn scalar_roots() {
+    let f = |x: f64| x * x * x - 2.0 * x - 5.0;
+    let exact = 2.094_551_481_542_327;
+    let criteria = Criteria { x_tol: 1e-12, ..Criteria::default() };
+
+    let r = bisection(f, 2.0, 3.0, &criteria).unwrap();
+    assert!((r.x - exact).abs() < 1e-11 && r.termination.converged());
+    let b = brent(f, 2.0, 3.0, &criteria).unwrap();
+    assert!((b.x - exact).abs() < 1e-11);
+    assert!(b.iterations < r.iterations / 3);
+    let n = newton(f, |x| 3.0 * x * x - 2.0, 2.0, &criteria).unwrap();
+    assert!((n.x - exact).abs() < 1e-12 && n.iterations < 8);
+    let s = secant(f, 2.0, 3.0, &criteria).unwrap();
+    assert!((s.x - exact).abs() < 1e-12);
+
+    assert_eq!(bisection(f, 3.0, 4.0, &criteria), Err(RootError::NotBracketed { fa: 16.0, fb: 51.0 }));
+    assert_eq!(newton(|x: f64| x * x - 1.0, |x| 2.0 * x, 0.0, &criteria), Err(RootError::ZeroSlope { x: 0.0 }));
+    assert_eq!(brent(f64::sin, 0.0, 1.0, &criteria).unwrap().x, 0.0);
+    // interpolation is useless on a step and near a triple root, brent falls back to bisection
+    let step = brent(|x: f64| if x < 1.0 / 3.0 { -1.0 } else { 1.0 }, 0.0, 1.0, &criteria).unwrap();
+    assert!((step.x - 1.0 / 3.0).abs() < 1e-11 && step.iterations < 3 * r.iterations);
+    let flat = brent(|x: f64| (x - 0.7).powi(3), -5.0, 4.0, &criteria).unwrap();
+    assert!((flat.x - 0.7).abs() < 1e-4 && flat.termination.converged());
+
+    let short = Criteria { max_iterations: 3, ..criteria };
+    assert_eq!(bisection(f, 2.0, 3.0, &short).unwrap().termination, Termination::MaxIterations);
+
+    // f32 with the default criteria
+    let r = brent(|x: f32| x.cos() - x, 0.0, 1.0, &Criteria::default()).unwrap();
+    assert!((r.x - 0.739_085_1).abs() < 1e-4);
+}
+
+fn rosenbrock(x: &VectorN<f64>) -> f64 {
+    (0..x.len() - 1).map(|i| 100.0 * (x[i + 1] - x[i] * x[i]).powi(2) + (1.0 - x[i]).powi(2)).sum()
+}
+
+fn rosenbrock_gradient(x: &VectorN<f64>) -> VectorN<f64> {
+    let n = x.len();
+    VectorN::from_fn(n, |i| {
+        let mut g = 0.0;
+        if i + 1 < n {
+            g += -400.0 * x[i] * (x[i + 1] - x[i] * x[i]) - 2.0 * (1.0 - x[i]);
+        }
+        if i > 0 {
+            g += 200.0 * (x[i] - x[i - 1] * x[i - 1]);
+        }
+        g
+    })
+}
+
+fn near_ones(x: &VectorN<f64>, tol: f64) -> bool {
+    x.iter().all(|v| (v - 1.0).abs() < tol)
+}
+
+#[test]
+fn quasi_newton() {
+    let f = WithGradient(rosenbrock, rosenbrock_gradient);
+    let x0 = VectorN::new(vec![-1.2, 1.0, -0.5, 0.8]);
+    let criteria = Criteria { g_tol: 1e-9, ..Criteria::default() };
+
+    let m = bfgs(&f, x0.clone(), &criteria);
+    assert!(near_ones(&m.x, 1e-6), "{:?}", m);
+    assert!(m.termination.converged() && m.value < 1e-12);
+
+    let m = lbfgs(&f, x0.clone(), 5, &criteria);
+    assert!(near_ones(&m.x, 1e-6), "{:?}", m);
+    assert!(m.gradients > 0 && m.evaluations >= m.iterations);
+
+    // finite difference gradient
+    let m = bfgs(&rosenbrock, x0, &Criteria::default());
+    assert!(near_ones(&m.x, 1e-4), "{:?}", m);
+}
+
+#[test]
+fn descent_and_simplex() {
+    // an elongated bowl on a fixed size vector
+    let bowl = |x: &Vector<f64, 2>| (x.data[0] - 3.0).powi(2) + 10.0 * (x.data[1] + 1.0).powi(2);
+    let criteria = Criteria { max_iterations: 10_000, ..Criteria::default() };
+    let m = gradient_descent(&bowl, Vector::new([0.0, 0.0]), &criteria);
+    assert!((m.x.data[0] - 3.0).abs() < 1e-5 && (m.x.data[1] + 1.0).abs() < 1e-5, "{:?}", m);
+
+    let m = nelder_mead(&bowl, Vector::new([0.0, 0.0]), 0.5, &Criteria { x_tol: 1e-10, ..Criteria::default() });
+    assert!((m.x.data[0] - 3.0).abs() < 1e-6 && (m.x.data[1] + 1.0).abs() < 1e-6, "{:?}", m);
+    assert_eq!(m.gradients, 0);
+
+    let m = nelder_mead(&rosenbrock, VectorN::new(vec![-1.2, 1.0]), 0.1, &Criteria { x_tol: 1e-10, ..Criteria::default() });
+    assert!(near_ones(&m.x, 1e-6), "{:?}", m);
+
+    let m = gradient_descent(&bowl, Vector::new([0.0, 0.0]), &Criteria { max_iterations: 2, ..Criteria::default() });
+    assert_eq!((m.iterations, m.termination), (2, Termination::MaxIterations));
+}
+
+#[test]
+fn least_squares() {
+    // fit y = a·exp(b·t) to exact samples
+    let ts: Vec<f64> = (0..10).map(|i| i as f64 * 0.3).collect();
+    let ys: Vec<f64> = ts.iter().map(|t| 2.0 * (-1.3 * t).exp()).collect();
+    let residual = |p: &Vector<f64, 2>| -> VectorN<f64> {
+        ts.iter().zip(ys.iter()).map(|(t, y)| p.data[0] * (p.data[1] * t).exp() - y).collect()
+    };
+    let m = levenberg_marquardt(&residual, Vector::new([1.0, 0.0]), &Criteria::default());
+    assert!((m.x.data[0] - 2.0).abs() < 1e-6 && (m.x.data[1] + 1.3).abs() < 1e-6, "{:?}", m);
+    assert!(m.value < 1e-12);
+
+    // circle through noisy points, with an analytic jacobian
+    let pts = [(3.0, 0.1), (0.0, 2.9), (-3.1, 0.0), (0.0, -3.0), (2.1, 2.1)];
+    let r = |p: &VectorN<f64>| -> VectorN<f64> {
+        pts.iter().map(|(x, y)| ((x - p[0]).powi(2) + (y - p[1]).powi(2)).sqrt() - p[2]).collect()
+    };
+    let j = |p: &VectorN<f64>| -> Matrix<f64> {
+        let rows: Vec<[f64; 3]> = pts.iter().map(|(x, y)| {
+            let d = ((x - p[0]).powi(2) + (y - p[1]).powi(2)).sqrt();
+            [(p[0] - x) / d, (p[1] - y) / d, -1.0]
+        }).collect();
+        Matrix::from_rows(&rows)
+    };
+    let analytic = levenberg_marquardt(&WithJacobian(r, j), VectorN::new(vec![0.5, 0.5, 1.0]), &Criteria::default());
+    let numeric = levenberg_marquardt(&r, VectorN::new(vec![0.5, 0.5, 1.0]), &Criteria::default());
+    assert!((analytic.x[2] - 3.0).abs() < 0.1 && analytic.x[0].abs() < 0.1);
+    for i in 0..3 {
+        assert!((analytic.x[i] - numeric.x[i]).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,72 @@
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::matrix::*;
+use rmu::parse::ParseError;
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+use rmu::vector::*;
+
+#[test]
+fn vector_forms() {
+    let v = Vector3::new(1.0, -2.5, 3.0);
+    for s in ["1, -2.5, 3", "1 -2.5 3", "[1 -2.5 3]", "(1, -2.5, 3)", "[1,-2.5,3]", " 1;\n-2.5;\n3 ", "[[1], [-2.5], [3]]"] {
+        assert_eq!(s.parse::<Vector3>(), Ok(v), "{:?}", s);
+    }
+    assert_eq!("1e-3 +2".parse::<Vector2>(), Ok(Vector2::new(1e-3, 2.0)));
+    assert_eq!("(0, 0, 0, 1)".parse::<Vector4>(), Ok(Vector4::new(0.0, 0.0, 0.0, 1.0)));
+    assert_eq!("[4 5 6]".parse::<Vector<i64, 3>>(), Ok(Vector::new([4, 5, 6])));
+    assert_eq!("1 2 3 4 5".parse::<VectorN<u8>>(), Ok(VectorN::new(vec![1, 2, 3, 4, 5])));
+    assert_eq!("[]".parse::<VectorN<f64>>(), Ok(VectorN::new(vec![])));
+}
+
+#[test]
+fn matrix_forms() {
+    let m = Matrix2x2::from([[1.0, 0.5], [-3.0, 4.0]]);
+    for s in ["1 0.5; -3 4", "[1 0.5; -3 4]", "[[1, 0.5], [-3, 4]]", "[1, 0.5]\n[-3, 4]", "1, 0.5\n-3, 4\n", "[[1, 0.5],\n [-3, 4]]"] {
+        assert_eq!(s.parse::<Matrix2x2>(), Ok(m), "{:?}", s);
+    }
+    let a: Matrix<i32> = "1 2 3; 4 5 6".parse().unwrap();
+    assert_eq!((a.n, a.m), (2, 3));
+    assert_eq!(a[(1, 2)], 6);
+}
+
+#[test]
+fn display_round_trip() {
+    let v = Vector3::new(0.125, -7.0, 1e6);
+    assert_eq!(v.to_string().parse::<Vector3>(), Ok(v));
+    assert_eq!(format!("{:+8.3}", v).parse::<Vector3>(), Ok(v));
+
+    let m = Matrix4x4::translate3(1.5, -2.0, 30.0);
+    assert_eq!(m.to_string().parse::<Matrix4x4>(), Ok(m));
+    assert_eq!(format!("{:#}", m).parse::<Matrix4x4>(), Ok(m));
+    let r = Matrix3x3::rotate3(0.3, 0.2, 0.1);
+    assert_eq!(r.to_string().parse::<Matrix3x3>(), Ok(r));
+
+    let a = Matrix::from_rows(&[[1.5f64, -2.0], [0.0, 1e-9], [4.0, 5.0]]);
+    assert_eq!(a.to_string().parse::<Matrix<f64>>().unwrap(), a);
+    let n = VectorN::new(vec![1, -2, 3]);
+    assert_eq!(n.to_string().parse(), Ok(n));
+}
+
+#[test]
+fn errors() {
+    assert_eq!("".parse::<Vector3>(), Err(ParseError::Empty));
+    assert_eq!(" \n ".parse::<VectorN<f32>>(), Err(ParseError::Empty));
+    assert_eq!("1, 2".parse::<Vector3>(), Err(ParseError::WrongLength { expected: 3, found: 2 }));
+    assert_eq!("1 2; 3 4".parse::<Vector4>(), Err(ParseError::NotAVector { rows: 2, cols: 2 }));
+    assert_eq!("1 2 3; 4 5 6".parse::<Matrix2x2>(), Err(ParseError::WrongShape { expected: (2, 2), found: (2, 3) }));
+    assert_eq!("1 2; 3".parse::<Matrix2x2>(), Err(ParseError::RaggedRows { row: 1, expected: 2, found: 1 }));
+    assert_eq!(
+        "1 2; 3 x4".parse::<Matrix2x2>(),
+        Err(ParseError::InvalidNumber { row: 1, col: 1, text: "x4".into() })
+    );
+    assert_eq!(
+        "1 2.5".parse::<Vector<i32, 2>>(),
+        Err(ParseError::InvalidNumber { row: 0, col: 1, text: "2.5".into() })
+    );
+    assert_eq!("[1 2".parse::<Vector2>(), Err(ParseError::Brackets { position: 0 }));
+    assert_eq!("(1 2]".parse::<Vector2>(), Err(ParseError::Brackets { position: 4 }));
+    assert_eq!("[[[1]]]".parse::<VectorN<f32>>(), Err(ParseError::Brackets { position: 2 }));
+
+    let e = "1 2; 3 x4".parse::<Matrix2x2>().unwrap_err();
+    assert_eq!(e.to_string(), "invalid number \"x4\" at (1, 1)");
+}
@@ -0,0 +1,178 @@
+use num::Complex;
+use rmu::polynomial::*;
+use rmu::vector::vectorn::VectorN;
+
+fn close(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol * b.abs().max(1.0)
+}
+
+fn assert_roots(found: &[f64], expected: &[f64], tol: f64) {
+    assert_eq!(found.len(), expected.len(), "{:?} vs {:?}", found, expected);
+    for (f, e) in found.iter().zip(expected) {
+        assert!(close(*f, *e, tol), "{:?} vs {:?}", found, expected);
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let p = Polynomial::new(vec![1, 2, 3]);
+    let q = Polynomial::new(vec![0, 1]);
+    assert_eq!(&p + &q, Polynomial::new(vec![1, 3, 3]));
+    assert_eq!(&p - &p, Polynomial::zero());
+    assert!((&p - &p).is_zero());
+    assert_eq!(&p * &q, Polynomial::new(vec![0, 1, 2, 3]));
+    assert_eq!(-&q * 2, Polynomial::new(vec![0, -2]));
+    assert_eq!(p.degree(), 2);
+    assert_eq!(p.leading_coefficient(), 3);
+    assert_eq!(Polynomial::new(vec![1, 0, 0]).degree(), 0);
+    assert_eq!(Polynomial::from_roots(&[1, -1]), Polynomial::new(vec![-1, 0, 1]));
+    assert_eq!(p.coeffs(), &VectorN::new(vec![1, 2, 3]));
+}
+
+#[test]
+fn division_with_remainder() {
+    // x³ - 2x² - 4 = (x - 3)(x² + x + 3) + 5
+    let a = Polynomial::new(vec![-4.0, 0.0, -2.0, 1.0]);
+    let b = Polynomial::new(vec![-3.0, 1.0]);
+    let (q, r) = a.div_rem(&b);
+    assert_eq!(q, Polynomial::new(vec![3.0, 1.0, 1.0]));
+    assert_eq!(r, Polynomial::constant(5.0));
+    assert_eq!(&(&q * &b) + &r, a);
+    assert_eq!(a.clone() / b.clone(), q);
+    assert_eq!(a.clone() % b.clone(), r);
+    assert_eq!(b.div_rem(&a), (Polynomial::zero(), b.clone()));
+    assert!(a.checked_div_rem(&Polynomial::zero()).is_none());
+
+    // integer coefficients divide exactly or not at all
+    let x2 = Polynomial::new(vec![0i32, 0, 1]);
+    assert!(x2.checked_div_rem(&Polynomial::new(vec![0, 2])).is_none());
+    assert!(Polynomial::new(vec![1i32, 0, 1]).checked_div_rem(&Polynomial::new(vec![0, 3])).is_none());
+    let (q, r) = Polynomial::new(vec![2i32, 0, 4]).div_rem(&Polynomial::new(vec![1, 2]));
+    assert_eq!((q.clone(), r.clone()), (Polynomial::new(vec![-1, 2]), Polynomial::constant(3)));
+    assert_eq!(&(&q * &Polynomial::new(vec![1, 2])) + &r, Polynomial::new(vec![2, 0, 4]));
+    assert_eq!(x2.div_rem(&Polynomial::new(vec![1, 1])), (Polynomial::new(vec![-1, 1]), Polynomial::constant(1)));
+}
+
+#[test]
+fn evaluation_and_calculus() {
+    let p = Polynomial::new(vec![1.0, -3.0, 0.0, 2.0]);
+    assert_eq!(p.eval(2.0), 11.0);
+    assert_eq!(p.eval_with_derivative(2.0), (11.0, 21.0));
+    assert_eq!(p.derivative(), Polynomial::new(vec![-3.0, 0.0, 6.0]));
+    assert_eq!(p.integral(), Polynomial::new(vec![0.0, 1.0, -1.5, 0.0, 0.5]));
+    assert_eq!(p.integral().derivative(), p);
+    assert_eq!(p.eval_complex(Complex::new(0.0, 1.0)), Complex::new(1.0, -5.0));
+
+    // (x + 1)² composed with x² is x⁴ + 2x² + 1
+    let outer = Polynomial::new(vec![1, 2, 1]);
+    let inner = Polynomial::new(vec![0, 0, 1]);
+    assert_eq!(outer.compose(&inner), Polynomial::new(vec![1, 0, 2, 0, 1]));
+    assert_eq!(outer.compose(&Polynomial::x()), outer);
+}
+
+#[test]
+fn closed_forms() {
+    assert_roots(&solve_linear(2.0, -3.0), &[1.5], 1e-15);
+    assert_roots(&solve_quadratic(1.0, -3.0, 2.0), &[1.0, 2.0], 1e-15);
+    assert_roots(&solve_quadratic(1.0, -2.0, 1.0), &[1.0, 1.0], 1e-12);
+    assert!(solve_quadratic(1.0, 0.0, 1.0).is_empty());
+    // no cancellation for tiny roots
+    assert_roots(&solve_quadratic(1.0, -1e8, 1.0), &[1e-8, 1e8], 1e-12);
+
+    assert_roots(&solve_cubic(1.0, -6.0, 11.0, -6.0), &[1.0, 2.0, 3.0], 1e-12);
+    assert_roots(&solve_cubic(2.0, 0.0, 0.0, -16.0), &[2.0], 1e-12);
+    assert_roots(&solve_cubic(1.0, -4.0, 5.0, -2.0), &[1.0, 1.0, 2.0], 1e-6);
+    assert_roots(&solve_cubic(1.0, -3.0, 3.0, -1.0), &[1.0, 1.0, 1.0], 1e-12);
+    assert_roots(&solve_cubic(0.0, 1.0, 0.0, -4.0), &[-2.0, 2.0], 1e-15);
+
+    assert_roots(&solve_quartic(1.0, -10.0, 35.0, -50.0, 24.0), &[1.0, 2.0, 3.0, 4.0], 1e-10);
+    assert_roots(&solve_quartic(1.0, 0.0, -5.0, 0.0, 4.0), &[-2.0, -1.0, 1.0, 2.0], 1e-12);
+    assert_roots(&solve_quartic(1.0, 0.0, 0.0, 0.0, 1.0), &[], 0.0);
+    // (x² + 1)(x - 0.5)(x + 3)
+    let p = Polynomial::from_roots(&[0.5, -3.0]) * Polynomial::new(vec![1.0, 0.0, 1.0]);
+    assert_roots(&p.real_roots().unwrap(), &[-3.0, 0.5], 1e-12);
+
+    let f: Vec<f32> = solve_cubic(1.0f32, -6.0, 11.0, -6.0);
+    assert!((f[2] - 3.0).abs() < 1e-5);
+}
+
+#[test]
+fn ray_torus_quartic() {
+    // ray along x through a torus with major radius 2 and minor radius 1 in
+    // the xy plane hits it at x = ±1 and x = ±3
+    let (big, small) = (2.0f64, 1.0f64);
+    // (x² + R² - r²)² - 4R²x² = 0 along the x axis
+    let k = big * big - small * small;
+    let roots = solve_quartic(1.0, 0.0, 2.0 * k - 4.0 * big * big, 0.0, k * k);
+    assert_roots(&roots, &[-3.0, -1.0, 1.0, 3.0], 1e-12);
+}
+
+#[test]
+fn companion_matrix_roots() {
+    let expected = [-4.0, -1.5, 0.25, 1.0, 2.0, 5.0, 7.5];
+    let p = Polynomial::from_roots(&expected);
+    assert_roots(&p.real_roots().unwrap(), &expected, 1e-9);
+    assert_eq!(p.companion_matrix().n, 7);
+
+    // x⁵ - 1 has one real root and two complex pairs on the unit circle
+    let q = Polynomial::new(vec![-1.0f64, 0.0, 0.0, 0.0, 0.0, 1.0]);
+    let roots = q.roots().unwrap();
+    assert_eq!(roots.len(), 5);
+    for z in &roots {
+        assert!((z.norm() - 1.0).abs() < 1e-12);
+        assert!(q.eval_complex(*z).norm() < 1e-12);
+    }
+    assert_roots(&q.real_roots().unwrap(), &[1.0], 1e-12);
+
+    // roots at zero are kept exactly
+    let r = Polynomial::new(vec![0.0, 0.0, -1.0, 0.0, 1.0]);
+    let mut zs: Vec<f64> = r.roots().unwrap().iter().map(|z| z.re).collect();
+    zs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_roots(&zs, &[-1.0, 0.0, 0.0, 1.0], 1e-14);
+    assert!(Polynomial::constant(3.0).roots().unwrap().is_empty());
+
+    // a small wilkinson polynomial with roots 1..=10
+    let w: Vec<f64> = (1..=10).map(|i| i as f64).collect();
+    assert_roots(&Polynomial::from_roots(&w).real_roots().unwrap(), &w, 1e-8);
+}
+
+#[test]
+fn companion_matrix_hard_cases() {
+    // badly scaled roots need balancing
+    let expected = [-1e4f64, -3.0, 1e-3, 0.5, 2e2, 7e5];
+    let p = Polynomial::from_roots(&expected);
+    let roots = p.real_roots().unwrap();
+    assert_eq!(roots.len(), expected.len());
+    for (x, e) in roots.iter().zip(&expected) {
+        assert!((x - e).abs() <= 1e-6 * e.abs(), "{} vs {}", x, e);
+    }
+
+    // x²⁰ - 1, the 20th roots of unity
+    let mut c = vec![0.0f64; 21];
+    c[0] = -1.0;
+    c[20] = 1.0;
+    let q = Polynomial::new(c);
+    let roots = q.roots().unwrap();
+    assert_eq!(roots.len(), 20);
+    for z in &roots {
+        assert!((z.norm() - 1.0).abs() < 1e-10);
+        assert!(q.eval_complex(*z).norm() < 1e-10);
+    }
+
+    // a repeated complex pair, (x² + x + 1)³
+    let r = Polynomial::new(vec![1.0, 1.0, 1.0]);
+    let r3 = &(&r * &r) * &r;
+    for z in r3.roots().unwrap() {
+        assert!(r.eval_complex(z).norm() < 1e-4);
+    }
+
+    let f = Polynomial::from_roots(&[1.0f32, -2.0, 3.0, 0.5, 4.0]);
+    assert_roots(&f.real_roots().unwrap().iter().map(|x| *x as f64).collect::<Vec<_>>(), &[-2.0, 0.5, 1.0, 3.0, 4.0], 1e-4);
+}
+
+#[test]
+fn companion_matrix_no_convergence() {
+    let p = Polynomial::new(vec![1.0, f64::NAN, 0.0, 2.0, 0.0, 1.0]);
+    assert_eq!(p.roots().unwrap_err(), RootsError::NoConvergence { found: 0 });
+    assert!(p.real_roots().is_err());
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "rand")]
+use std::collections::HashMap;
+use rmu::random::*;
+use rmu::random::rand::SeedableRng;
+use rmu::random::rand::rngs::StdRng;
+use rmu::random::rand::distributions::Uniform;
+use rmu::vector::{Vector2, Vector3};
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(7)
+}
+
+#[test]
+fn seeded_runs_repeat() {
+    let a: Vec<Vector3> = { let mut r = rng(); (0..16).map(|_| in_unit_sphere(&mut r)).collect() };
+    let b: Vec<Vector3> = { let mut r = rng(); (0..16).map(|_| in_unit_sphere(&mut r)).collect() };
+    assert_eq!(a, b);
+    let c: Vec<Vector3> = { let mut r = StdRng::seed_from_u64(8); (0..16).map(|_| in_unit_sphere(&mut r)).collect() };
+    assert_ne!(a, c);
+}
+
+#[test]
+fn sphere_and_disk() {
+    let mut r = rng();
+    let n = 20_000;
+    let mut mean = Vector3::default();
+    let mut inner = 0;
+    for _ in 0..n {
+        let u = unit_vector3(&mut r);
+        assert!((u.length() - 1.0).abs() < 1e-5);
+        mean = mean + u;
+        let p = in_unit_sphere(&mut r);
+        assert!(p.length() <= 1.0 + 1e-6);
+        // the ball of radius 1/2 holds 1/8 of the volume
+        if p.length() < 0.5 {
+            inner += 1;
+        }
+    }
+    assert!((mean / n as f32).length() < 0.03);
+    assert!((inner as f32 / n as f32 - 0.125).abs() < 0.01);
+
+    let mut inner = 0;
+    for _ in 0..n {
+        assert!((on_unit_circle(&mut r).length() - 1.0).abs() < 1e-5);
+        let p = in_unit_disk(&mut r);
+        assert!(p.length() <= 1.0 + 1e-6);
+        if p.length() < 0.5 {
+            inner += 1;
+        }
+    }
+    assert!((inner as f32 / n as f32 - 0.25).abs() < 0.015);
+}
+
+#[test]
+fn triangle_and_box() {
+    let mut r = rng();
+    let (a, b, c) = (Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+    let mut centroid = Vector2::default();
+    for _ in 0..10_000 {
+        let p = in_triangle2(&mut r, a, b, c);
+        assert!(p.x >= -1e-6 && p.y >= -1e-6 && p.x + p.y <= 1.0 + 1e-6);
+        centroid = centroid + p;
+    }
+    let centroid = centroid / 10_000.0;
+    assert!((centroid.x - 1.0 / 3.0).abs() < 0.01 && (centroid.y - 1.0 / 3.0).abs() < 0.01);
+
+    let p = in_triangle(&mut r, Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 1.0));
+    assert_eq!(p.z, 1.0);
+
+    let (min, max) = (Vector3::new(-1.0, 0.0, 2.0), Vector3::new(1.0, 3.0, 2.5));
+    for _ in 0..1000 {
+        let p = in_aabb(&mut r, min, max);
+        assert!(p.x >= min.x && p.x <= max.x && p.y >= min.y && p.y <= max.y && p.z >= min.z && p.z <= max.z);
+        let s = on_aabb(&mut r, min, max);
+        let on_face = (0..3).any(|i| s[i] == min[i] || s[i] == max[i]);
+        assert!(on_face);
+    }
+    let q = in_aabb2(&mut r, Vector2::new(0.0, 0.0), Vector2::new(2.0, 2.0));
+    assert!(q.x <= 2.0 && q.y <= 2.0);
+}
+
+#[test]
+fn rotations() {
+    let mut r = rng();
+    let mut mean_z = Vector3::default();
+    for _ in 0..2000 {
+        let q = rotation_quaternion(&mut r);
+        assert!((q.length() - 1.0).abs() < 1e-5);
+        let m = rotation_matrix3(&mut r);
+        assert!((m.determinate() - 1.0).abs() < 1e-4);
+        let i = m * m.transpose();
+        for a in 0..3 {
+            for b in 0..3 {
+                let e = if a == b { 1.0 } else { 0.0 };
+                assert!((i.data[a][b] - e).abs() < 1e-5);
+            }
+        }
+        mean_z = mean_z + m * Vector3::new(0.0, 0.0, 1.0);
+    }
+    // uniform rotations send a fixed axis uniformly over the sphere
+    assert!((mean_z / 2000.0).length() < 0.06);
+}
+
+#[test]
+fn random_matrices() {
+    let mut r = rng();
+    let m = matrix(&mut r, 3, 5, &Uniform::new(-2.0f64, 2.0));
+    assert_eq!((m.n, m.m), (3, 5));
+    assert!(m.data.iter().all(|x| (-2.0..2.0).contains(x)));
+    let v = vectorn(&mut r, 4, &Uniform::new(0u32, 10));
+    assert_eq!(v.len(), 4);
+    let f = matrix4x4(&mut r, &Uniform::new(0.0, 1.0));
+    assert!(f.data.iter().flatten().all(|x| (0.0..1.0).contains(x)));
+    let a = matrix3x3(&mut rng(), &Uniform::new(0.0, 1.0));
+    let b = matrix3x3(&mut rng(), &Uniform::new(0.0, 1.0));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn graphs() {
+    let mut r = rng();
+    let g = erdos_renyi(&mut r, 40, 0.25, |i| i);
+    assert_eq!(g.nodes.len(), 40);
+    let expected = 0.25 * 40.0 * 39.0 / 2.0;
+    assert!((g.edges.len() as f64 - expected).abs() < 0.2 * expected);
+    assert!(erdos_renyi(&mut r, 10, 0.0, |_| ()).edges.is_empty());
+    assert_eq!(erdos_renyi(&mut r, 10, 1.0, |_| ()).edges.len(), 45);
+
+    let (n, m) = (50, 3);
+    let g = barabasi_albert(&mut r, n, m, |_| ());
+    assert_eq!(g.edges.len(), m * (m + 1) / 2 + (n - m - 1) * m);
+    for (a, b) in g.edges.values() {
+        assert_ne!(a, b);
+    }
+
+    let d = random_dag(&mut r, 30, 0.3, |i| i * 2);
+    assert_eq!(d.nodes["4"], 8);
+    // kahn's algorithm removes every node exactly when there is no cycle
+    let mut indegree: HashMap<&String, usize> = d.nodes.keys().map(|k| (k, 0)).collect();
+    for next in d.edges.values() {
+        for b in next {
+            *indegree.get_mut(b).unwrap() += 1;
+        }
+    }
+    let mut ready: Vec<&String> = indegree.iter().filter(|(_, &c)| c == 0).map(|(k, _)| *k).collect();
+    let mut removed = 0;
+    while let Some(a) = ready.pop() {
+        removed += 1;
+        for b in d.edges.get(a).into_iter().flatten() {
+            let c = indegree.get_mut(b).unwrap();
+            *c -= 1;
+            if *c == 0 {
+                ready.push(b);
+            }
+        }
+    }
+    assert_eq!(removed, 30);
+}
+
+#[test]
+#[should_panic(expected = "erdos_renyi needs p in [0, 1]")]
+fn erdos_renyi_rejects_bad_p() {
+    erdos_renyi(&mut rng(), 1, f64::NAN, |_| ());
+}
+
+#[test]
+#[should_panic(expected = "random_dag needs p in [0, 1]")]
+fn random_dag_rejects_bad_p() {
+    random_dag(&mut rng(), 5, 1.5, |_| ());
+}
@@ -0,0 +1,97 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use rmu::matrix::rotation::*;
+use rmu::matrix::*;
+use rmu::vector::*;
+
+fn close(a: &Matrix3x3, b: &Matrix3x3, tol: f32) -> bool {
+    (0..3).all(|i| (0..3).all(|j| (a[i][j] - b[i][j]).abs() < tol))
+}
+
+fn is_rotation(m: &Matrix3x3) -> bool {
+    close(&(*m * m.transpose()), &Matrix3x3::default(), 1e-5) && (m.determinate() - 1.0).abs() < 1e-5
+}
+
+const ANGLES: [(f32, f32, f32); 5] = [
+    (0.3, -0.7, 1.1),
+    (-2.5, 0.4, 2.9),
+    (1.0, 1.2, -0.2),
+    (3.0, -1.4, 0.05),
+    (-0.6, 0.9, -3.0),
+];
+
+#[test]
+fn every_order_round_trips() {
+    for order in EulerRot::ALL {
+        for &(a, b, c) in &ANGLES {
+            // fold the middle angle into the range extraction returns
+            let b = if order.is_proper_euler() { b.abs() } else { b };
+            let m = order.to_matrix3(a, b, c);
+            assert!(is_rotation(&m), "{:?}", order);
+
+            let (a2, b2, c2) = m.to_euler(order);
+            assert!((a2 - a).abs() < 1e-4 && (b2 - b).abs() < 1e-4 && (c2 - c).abs() < 1e-4,
+                "{:?}: {:?} != {:?}", order, (a2, b2, c2), (a, b, c));
+
+            let q = order.to_quat(a, b, c);
+            assert!(close(&quat_to_matrix3(q), &m, 1e-5), "{:?}", order);
+            let (qa, qb, qc) = order.from_quat(q);
+            assert!(close(&order.to_matrix3(qa, qb, qc), &m, 1e-4), "{:?}", order);
+
+            let (axis, angle) = order.to_axis_angle(a, b, c);
+            assert!(close(&Matrix3x3::from_axis_angle(axis, angle), &m, 1e-4), "{:?}", order);
+            let (xa, xb, xc) = order.from_axis_angle(axis, angle);
+            assert!(close(&order.to_matrix3(xa, xb, xc), &m, 1e-4), "{:?}", order);
+        }
+    }
+}
+
+#[test]
+fn gimbal_lock() {
+    for order in EulerRot::ALL {
+        let locked = if order.is_proper_euler() { [0.0, PI] } else { [FRAC_PI_2, -FRAC_PI_2] };
+        for b in locked {
+            let m = order.to_matrix3(0.4, b, -1.3);
+            let (a2, b2, c2) = m.to_euler(order);
+            assert!(a2.is_finite() && b2.is_finite());
+            assert_eq!(c2, 0.0, "{:?}", order);
+            assert!(close(&order.to_matrix3(a2, b2, c2), &m, 1e-4), "{:?} b = {}", order, b);
+        }
+    }
+}
+
+#[test]
+fn axis_conventions() {
+    // intrinsic x then y: rotating z by XYZ(a, 0, 0) is a rotation about x
+    let m = Matrix3x3::from_euler(EulerRot::XYZ, FRAC_PI_2, 0.0, 0.0);
+    let v = m * Vector3::new(0.0, 1.0, 0.0);
+    assert!((v - Vector3::new(0.0, 0.0, 1.0)).length() < 1e-6);
+
+    // ZYX yaw, pitch, roll applies roll first
+    let yaw = EulerRot::ZYX.to_matrix3(FRAC_PI_2, 0.0, 0.0);
+    let roll = EulerRot::ZYX.to_matrix3(0.0, 0.0, FRAC_PI_2);
+    assert!(close(&EulerRot::ZYX.to_matrix3(FRAC_PI_2, 0.0, FRAC_PI_2), &(yaw * roll), 1e-6));
+}
+
+#[test]
+fn axis_angle() {
+    let m = Matrix3x3::from_axis_angle(Vector3::new(0.0, 0.0, 2.0), FRAC_PI_2);
+    assert!((m * Vector3::new(1.0, 0.0, 0.0) - Vector3::new(0.0, 1.0, 0.0)).length() < 1e-6);
+
+    for angle in [0.2, 1.5, 3.0, PI] {
+        let axis = Vector3::new(1.0, -2.0, 0.5).normalized();
+        let (axis2, angle2) = Matrix3x3::from_axis_angle(axis, angle).to_axis_angle();
+        assert!((angle2 - angle).abs() < 1e-4);
+        // at π both directions are the same rotation
+        assert!((Vector3::dot(axis, axis2).abs() - 1.0).abs() < 1e-4);
+    }
+    assert_eq!(Matrix3x3::default().to_axis_angle().1, 0.0);
+}
+
+#[test]
+fn rotate3_is_zxz() {
+    let (x, y, z) = (0.4, -1.1, 2.2);
+    let m = Matrix3x3::rotate3(x, y, z);
+    assert!(is_rotation(&m));
+    assert!(close(&m, &EulerRot::ZXZ.to_matrix3(x, y, z), 1e-6));
+}
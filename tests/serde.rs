@@ -0,0 +1,77 @@
+#![cfg(feature = "serde")]
+
+use rmu::combinatorics::graph::*;
+use rmu::matrix::*;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::vector::*;
+use rmu::vector::vector::Vector;
+use rmu::vector::vectorn::VectorN;
+
+#[test]
+fn vector_wire_format() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let s = serde_json::to_string(&v).unwrap();
+    assert_eq!(s, "[1.0,2.0,3.0]");
+    assert_eq!(serde_json::from_str::<Vector3>(&s).unwrap(), v);
+
+    assert_eq!(serde_json::to_string(&Vector2::new(1.0, 2.0)).unwrap(), "[1.0,2.0]");
+    assert_eq!(serde_json::from_str::<Vector4>("[1,2,3,4]").unwrap(), Vector4::new(1.0, 2.0, 3.0, 4.0));
+
+    assert!(serde_json::from_str::<Vector3>("[1.0,2.0]").is_err());
+    assert!(serde_json::from_str::<Vector3>("[1.0,2.0,3.0,4.0]").is_err());
+}
+
+#[test]
+fn generic_vector() {
+    let v = Vector::<i32, 5>::new([1, 2, 3, 4, 5]);
+    let s = serde_json::to_string(&v).unwrap();
+    assert_eq!(s, "[1,2,3,4,5]");
+    assert_eq!(serde_json::from_str::<Vector<i32, 5>>(&s).unwrap(), v);
+    assert!(serde_json::from_str::<Vector<i32, 5>>("[1,2,3,4]").is_err());
+    assert!(serde_json::from_str::<Vector<i32, 5>>("[1,2,3,4,5,6]").is_err());
+
+    let n = VectorN::<f64> { data: vec![1.0, 2.5] };
+    let s = serde_json::to_string(&n).unwrap();
+    assert_eq!(s, "[1.0,2.5]");
+    assert_eq!(serde_json::from_str::<VectorN<f64>>(&s).unwrap().data, n.data);
+}
+
+#[test]
+fn matrix_wire_format() {
+    let m = Matrix4x4::translate3(1.0, 2.0, 3.0);
+    let s = serde_json::to_string(&m).unwrap();
+    assert_eq!(s, "[[1.0,0.0,0.0,1.0],[0.0,1.0,0.0,2.0],[0.0,0.0,1.0,3.0],[0.0,0.0,0.0,1.0]]");
+    assert_eq!(serde_json::from_str::<Matrix4x4>(&s).unwrap(), m);
+
+    let m = Matrix2x2::rotate2(0.5);
+    assert_eq!(serde_json::from_str::<Matrix2x2>(&serde_json::to_string(&m).unwrap()).unwrap(), m);
+    assert!(serde_json::from_str::<Matrix3x3>("[[1,0,0],[0,1,0]]").is_err());
+    assert!(serde_json::from_str::<Matrix3x3>("[[1,0,0],[0,1,0],[0,0]]").is_err());
+
+    let m = Matrix { n: 2, m: 3, data: vec![1, 2, 3, 4, 5, 6].into_boxed_slice() };
+    let s = serde_json::to_string(&m).unwrap();
+    assert_eq!(s, r#"{"n":2,"m":3,"data":[1,2,3,4,5,6]}"#);
+    let back: Matrix<i32> = serde_json::from_str(&s).unwrap();
+    assert_eq!((back.n, back.m, &back.data[..]), (2, 3, &[1, 2, 3, 4, 5, 6][..]));
+    assert!(serde_json::from_str::<Matrix<i32>>(r#"{"n":2,"m":3,"data":[1,2,3]}"#).is_err());
+}
+
+#[test]
+fn graph_round_trip() {
+    let mut g = Graph::<u32>::new();
+    g.add_node("a".into(), 1);
+    g.add_node("b".into(), 2);
+    g.connect("i".into(), "a".into(), "b".into()).unwrap();
+    let back: Graph<u32> = serde_json::from_str(&serde_json::to_string(&g).unwrap()).unwrap();
+    assert_eq!(back.nodes, g.nodes);
+    assert_eq!(back.edges, g.edges);
+    assert!(serde_json::from_str::<Graph<u32>>(r#"{"nodes":{"a":1},"edges":{"i":["a","b"]}}"#).is_err());
+
+    let mut dg = DGraph::<()>::new();
+    dg.add_node("a".into(), ());
+    dg.add_node("b".into(), ());
+    dg.connect("a".into(), "b".into()).unwrap();
+    let back: DGraph<()> = serde_json::from_str(&serde_json::to_string(&dg).unwrap()).unwrap();
+    assert_eq!(back.edges, dg.edges);
+    assert!(serde_json::from_str::<DGraph<()>>(r#"{"nodes":{"a":null},"edges":{"a":["c"]}}"#).is_err());
+}
@@ -0,0 +1,159 @@
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::matrix::solver::*;
+use rmu::matrix::sparse::{CooMatrix, CsrMatrix};
+use rmu::vector::vectorn::VectorN;
+
+/// 1d poisson matrix, tridiagonal [-1 2 -1], symmetric positive definite
+fn laplacian(n: usize) -> CsrMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0);
+        if i > 0 {
+            coo.push(i, i - 1, -1.0);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0);
+        }
+    }
+    coo.to_csr()
+}
+
+/// non-symmetric convection-diffusion style matrix
+fn convection(n: usize) -> CsrMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 4.0);
+        if i > 0 {
+            coo.push(i, i - 1, -1.5);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -0.5);
+        }
+        if i + 5 < n {
+            coo.push(i, i + 5, 0.25);
+        }
+    }
+    coo.to_csr()
+}
+
+fn residual(a: &CsrMatrix<f64>, x: &VectorN<f64>, b: &VectorN<f64>) -> f64 {
+    let ax = a.mul_vec(&x.data);
+    let r: f64 = ax.iter().zip(&b.data).map(|(p, q)| (p - q) * (p - q)).sum();
+    let bn: f64 = b.data.iter().map(|q| q * q).sum();
+    (r / bn).sqrt()
+}
+
+fn rhs(n: usize) -> VectorN<f64> {
+    VectorN { data: (0..n).map(|i| (i as f64 * 0.37).sin() + 1.0).collect() }
+}
+
+#[test]
+fn cg_converges() {
+    let n = 100;
+    let a = laplacian(n);
+    let b = rhs(n);
+    let opts = SolverOptions { tol: 1e-10, max_iter: 500, ..Default::default() };
+
+    let plain = conjugate_gradient(&a, &b, None, &Identity, &opts).unwrap();
+    assert!(plain.converged);
+    assert!(residual(&a, &plain.x, &b) < 1e-9);
+    assert_eq!(plain.residual_history.len(), plain.iterations + 1);
+    // cg finishes in at most n steps in exact arithmetic
+    assert!(plain.iterations <= n + 10);
+
+    let jacobi = conjugate_gradient(&a, &b, None, &Jacobi::from_csr(&a).unwrap(), &opts).unwrap();
+    assert!(jacobi.converged);
+    assert!(residual(&a, &jacobi.x, &b) < 1e-9);
+
+    // ic(0) of a tridiagonal matrix is the exact cholesky factor
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    let exact = conjugate_gradient(&a, &b, None, &ic, &opts).unwrap();
+    assert!(exact.converged);
+    assert!(exact.iterations <= 2);
+}
+
+#[test]
+fn cg_rejects_indefinite() {
+    let a = Matrix::from_rows(&[[1.0, 0.0], [0.0, -1.0]]);
+    let b = VectorN { data: vec![1.0, 1.0] };
+    assert_eq!(conjugate_gradient(&a, &b, None, &Identity, &SolverOptions::default()).unwrap_err(), SolverError::NotPositiveDefinite);
+    assert!(IncompleteCholesky::new(&CsrMatrix::from_dense(&a)).is_err());
+}
+
+#[test]
+fn bicgstab_and_gmres_converge() {
+    let n = 80;
+    let a = convection(n);
+    let b = rhs(n);
+    let opts = SolverOptions { tol: 1e-10, max_iter: 400, restart: 20 };
+
+    let bi = bicgstab(&a, &b, None, &Identity, &opts).unwrap();
+    assert!(bi.converged);
+    assert!(residual(&a, &bi.x, &b) < 1e-9);
+
+    let gm = gmres(&a, &b, None, &Identity, &opts).unwrap();
+    assert!(gm.converged);
+    assert!(residual(&a, &gm.x, &b) < 1e-9);
+    assert!(gm.residual_history.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+
+    let jacobi = Jacobi::from_csr(&a).unwrap();
+    let gm = gmres(&a, &b, None, &jacobi, &SolverOptions { restart: 5, ..opts }).unwrap();
+    assert!(gm.converged);
+    assert!(residual(&a, &gm.x, &b) < 1e-9);
+}
+
+#[test]
+fn matrix_free() {
+    let n = 50;
+    let op = FnOperator {
+        n,
+        f: |x: &[f64], y: &mut [f64]| {
+            for i in 0..x.len() {
+                y[i] = 3.0 * x[i] - if i > 0 { x[i - 1] } else { 0.0 } - if i + 1 < x.len() { x[i + 1] } else { 0.0 };
+            }
+        },
+    };
+    let b = rhs(n);
+    let report = conjugate_gradient(&op, &b, None, &Identity, &SolverOptions::default()).unwrap();
+    assert!(report.converged);
+    assert!(report.residual() <= f64::EPSILON.sqrt());
+
+    let dense = laplacian(n).to_dense();
+    let x0 = VectorN { data: vec![1.0; n] };
+    let report = gmres(&dense, &b, Some(&x0), &Identity, &SolverOptions::default()).unwrap();
+    assert!(report.converged);
+
+    let short = VectorN { data: vec![1.0; n - 1] };
+    assert_eq!(gmres(&dense, &short, None, &Identity, &SolverOptions::default()).unwrap_err(), SolverError::DimensionMismatch);
+}
+
+#[test]
+fn preconditioner_dimension() {
+    let a = laplacian(10);
+    let b = rhs(10);
+    let jacobi = Jacobi::from_csr(&laplacian(8)).unwrap();
+    let opts = SolverOptions::default();
+    assert_eq!(Preconditioner::<f64>::dim(&jacobi), Some(8));
+    assert_eq!(conjugate_gradient(&a, &b, None, &jacobi, &opts).unwrap_err(), SolverError::DimensionMismatch);
+    assert_eq!(bicgstab(&a, &b, None, &jacobi, &opts).unwrap_err(), SolverError::DimensionMismatch);
+
+    let ic = IncompleteCholesky::new(&laplacian(12)).unwrap();
+    assert_eq!(gmres(&a, &b, None, &ic, &opts).unwrap_err(), SolverError::DimensionMismatch);
+}
+
+#[test]
+fn gmres_judges_the_true_residual() {
+    // on an ill conditioned hilbert matrix the givens estimate runs far ahead of ‖b - A x‖
+    let n = 12;
+    let data = (0..n * n).map(|k| 1.0 / ((k / n + k % n + 1) as f64)).collect();
+    let a = Matrix::from_vec(n, n, data);
+    let b = VectorN { data: vec![1.0; n] };
+    for tol in [1e-6, 1e-9, 1e-12] {
+        let opts = SolverOptions { tol, max_iter: 60, restart: 12 };
+        let report = gmres(&a, &b, None, &Identity, &opts).unwrap();
+        let ax = a.mul_vec(&report.x.data);
+        let true_res = ax.iter().zip(&b.data).map(|(p, q)| (p - q) * (p - q)).sum::<f64>().sqrt() / (n as f64).sqrt();
+        assert!((report.residual() - true_res).abs() <= 1e-3 * true_res, "tol {}", tol);
+        assert_eq!(report.converged, true_res <= tol, "tol {}", tol);
+    }
+}
@@ -0,0 +1,79 @@
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::matrix::sparse::*;
+
+/// a 5 x 4 matrix with an empty row and an empty column
+fn sample() -> CooMatrix<f64> {
+    let mut coo = CooMatrix::new(5, 4);
+    coo.push(0, 0, 4.0);
+    coo.push(3, 1, -1.0);
+    coo.push(0, 3, 2.0);
+    coo.push(2, 0, 1.5);
+    coo.push(3, 3, 7.0);
+    coo.push(0, 0, 1.0);
+    coo.push(4, 1, 3.0);
+    coo
+}
+
+#[test]
+fn conversions() {
+    let coo = sample();
+    let dense = coo.to_dense();
+    assert_eq!(dense[(0, 0)], 5.0);
+    assert_eq!(dense.row(1), &[0.0; 4]);
+
+    let csr = coo.to_csr();
+    assert_eq!(csr.nnz(), 6);
+    assert_eq!(csr.row_ptr, vec![0, 2, 2, 3, 5, 6]);
+    assert_eq!(csr.row(0), (&[0, 3][..], &[5.0, 2.0][..]));
+    assert_eq!(csr.to_dense(), dense);
+    assert_eq!(csr.get(3, 3), 7.0);
+    assert_eq!(csr.get(1, 1), 0.0);
+
+    let csc = coo.to_csc();
+    assert_eq!(csc.col_ptr, vec![0, 2, 4, 4, 6]);
+    assert_eq!(csc.to_dense(), dense);
+    assert_eq!(csc.to_csr(), csr);
+    assert_eq!(csr.to_csc(), csc);
+    assert_eq!(CsrMatrix::from_dense(&dense), csr);
+    assert_eq!(CscMatrix::from_dense(&dense), csc);
+    assert_eq!(csr.to_coo().to_csr(), csr);
+
+    let entries: Vec<_> = csr.iter().collect();
+    assert_eq!(entries[..2], [(0, 0, 5.0), (0, 3, 2.0)]);
+    let mut csc_entries: Vec<_> = csc.iter().collect();
+    csc_entries.sort_by_key(|(i, j, _)| (*i, *j));
+    assert_eq!(csc_entries, entries);
+}
+
+#[test]
+fn products_match_dense() {
+    let a = sample();
+    let dense = a.to_dense();
+    let x = [1.0, -2.0, 0.5, 3.0];
+    assert_eq!(a.to_csr().mul_vec(&x), dense.mul_vec(&x));
+    assert_eq!(a.to_csc().mul_vec(&x), dense.mul_vec(&x));
+
+    let mut b = CooMatrix::new(4, 3);
+    b.push(0, 2, 1.0);
+    b.push(1, 0, 2.0);
+    b.push(3, 1, -1.0);
+    b.push(3, 2, 0.5);
+    let expect = &dense * &b.to_dense();
+    assert_eq!(a.to_csr().mul_sparse(&b.to_csr()).to_dense(), expect);
+    assert_eq!((&a.to_csc() * &b.to_csc()).to_dense(), expect);
+
+    let i = CsrMatrix::<f64>::identity(4);
+    assert_eq!(&a.to_csr() * &i, a.to_csr());
+}
+
+#[test]
+fn transpose() {
+    let a = sample();
+    let t = a.to_dense().transpose();
+    assert_eq!(a.to_csr().transpose().to_dense(), t);
+    assert_eq!(a.to_csc().transpose().to_dense(), t);
+    assert_eq!(a.to_csr().transpose().transpose(), a.to_csr());
+
+    let empty: Matrix<f64> = CsrMatrix::zeros(2, 3).transpose().to_dense();
+    assert_eq!((empty.n, empty.m), (3, 2));
+}
@@ -0,0 +1,116 @@
+use rmu::common::factorial;
+use rmu::common::special::*;
+
+fn close(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol * b.abs().max(1.0)
+}
+
+#[test]
+fn factorial_terminates() {
+    assert_eq!(factorial(0u64), 1);
+    assert_eq!(factorial(1u64), 1);
+    assert_eq!(factorial(5u64), 120);
+    assert_eq!(factorial(20u64), 2_432_902_008_176_640_000);
+    assert_eq!(factorial(6.0f64), 720.0);
+}
+
+#[test]
+#[should_panic(expected = "negative")]
+fn factorial_negative() {
+    factorial(-3i32);
+}
+
+#[test]
+#[should_panic(expected = "negative")]
+fn factorial_nan() {
+    factorial(f64::NAN);
+}
+
+#[test]
+fn gamma_table() {
+    let table = [
+        (0.1, 9.513_507_698_668_732),
+        (0.5, 1.772_453_850_905_516),
+        (1.0, 1.0),
+        (1.5, 0.886_226_925_452_758),
+        (5.0, 24.0),
+        (10.5, 1_133_278.388_948_785_2),
+        (-0.5, -3.544_907_701_811_032),
+        (-1.5, 2.363_271_801_207_355),
+    ];
+    for (x, g) in table {
+        assert!(close(gamma(x), g, 1e-13), "gamma({}) = {}", x, gamma(x));
+        assert!(close(ln_gamma(x), g.abs().ln(), 1e-13));
+    }
+    assert!(close(ln_gamma(100.0), 359.134_205_369_575_4, 1e-14));
+    assert!(gamma(-2.0f64).is_nan());
+    assert!((gamma(4.5f32) - 11.631_728).abs() < 1e-5);
+}
+
+#[test]
+fn beta_table() {
+    assert!(close(beta(2.0, 3.0), 1.0 / 12.0, 1e-14));
+    assert!(close(beta(0.5, 0.5), std::f64::consts::PI, 1e-14));
+    assert!(close(beta(1.5, 2.5), 0.196_349_540_849_362_08, 1e-13));
+}
+
+#[test]
+fn erf_table() {
+    let table = [
+        (0.1, 0.112_462_916_018_284_9),
+        (0.5, 0.520_499_877_813_046_5),
+        (1.0, 0.842_700_792_949_714_9),
+        (2.0, 0.995_322_265_018_952_7),
+        (3.0, 0.999_977_909_503_001_4),
+    ];
+    for (x, e) in table {
+        assert!(close(erf(x), e, 1e-14), "erf({}) = {}", x, erf(x));
+        assert!(close(erf(-x), -e, 1e-14));
+    }
+    assert_eq!(erf(0.0f64), 0.0);
+    assert!(close(erfc(3.0), 2.209_049_699_858_544e-5, 1e-12));
+    assert!(close(erfc(5.0), 1.537_459_794_428_035e-12, 1e-12));
+    assert!(close(erfc(-1.0), 1.842_700_792_949_715, 1e-14));
+    assert!((erf(1.0f32) - 0.842_700_8).abs() < 1e-6);
+}
+
+#[test]
+fn incomplete_gamma_table() {
+    // P(1, x) = 1 - e^-x and P(3, 2) = 1 - 5e^-2
+    for x in [0.1f64, 1.0, 4.0, 20.0] {
+        assert!(close(gamma_p(1.0, x), 1.0 - (-x).exp(), 1e-14));
+    }
+    assert!(close(gamma_p(3.0, 2.0), 1.0 - 5.0 * (-2.0f64).exp(), 1e-14));
+    assert!(close(gamma_q(3.0, 2.0), 5.0 * (-2.0f64).exp(), 1e-14));
+    assert!(close(gamma_p(10.0, 5.0) + gamma_q(10.0, 5.0), 1.0, 1e-15));
+    assert_eq!(gamma_p(2.0, 0.0f64), 0.0);
+    assert!(gamma_p(-1.0, 1.0f64).is_nan());
+}
+
+#[test]
+fn bessel_table() {
+    let j0 = [
+        (0.0, 1.0),
+        (1.0, 0.765_197_686_557_966_6),
+        (5.0, -0.177_596_771_314_338_3),
+        (10.0, -0.245_935_764_451_348_3),
+        (20.0, 0.167_024_664_340_583_1),
+    ];
+    for (x, j) in j0 {
+        assert!(close(bessel_j0(x), j, 1e-10), "J0({}) = {}", x, bessel_j0(x));
+    }
+    let j1 = [
+        (0.0, 0.0),
+        (1.0, 0.440_050_585_744_933_5),
+        (5.0, -0.327_579_137_591_465_2),
+        (10.0, 0.043_472_746_168_861_44),
+        (20.0, 0.066_833_124_175_850_05),
+    ];
+    for (x, j) in j1 {
+        assert!(close(bessel_j1(x), j, 1e-10), "J1({}) = {}", x, bessel_j1(x));
+        assert!(close(bessel_j1(-x), -j, 1e-10));
+    }
+    // first zero of J0
+    assert!(bessel_j0(2.404_825_557_695_773f64).abs() < 1e-12);
+    assert!((bessel_j0(1.0f32) - 0.765_197_7).abs() < 1e-6);
+}
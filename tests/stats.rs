@@ -0,0 +1,171 @@
+use rmu::matrix::eigen::SymmetricEigen;
+use rmu::matrix::matrixnxm::Matrix;
+use rmu::stats::*;
+use rmu::vector::Vector3;
+use rmu::vector::vectorn::{LengthMismatch, VectorN};
+
+const DATA: [f64; 8] = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn descriptive() {
+    assert_eq!(mean(&DATA), Some(5.0));
+    assert_eq!(population_variance(&DATA), Some(4.0));
+    assert!(close(sample_variance(&DATA).unwrap(), 32.0 / 7.0));
+    assert_eq!(population_std_dev(&DATA), Some(2.0));
+    assert_eq!(min_max(&[3.0, f64::NAN, -1.0, 8.0]), Some((-1.0, 8.0)));
+    assert_eq!(mean::<f32>(&[]), None);
+    assert_eq!(sample_variance(&[1.0f32]), None);
+
+    let v = VectorN::new(vec![1.0f32, 2.0, 3.0]);
+    assert_eq!(mean(v.as_slice()), Some(2.0));
+}
+
+#[test]
+fn median_and_quantiles() {
+    assert_eq!(median(&DATA), Some(4.5));
+    assert_eq!(median(&[3.0, 1.0, 2.0]), Some(2.0));
+    assert_eq!(quantile(&DATA, 0.0), Some(2.0));
+    assert_eq!(quantile(&DATA, 1.0), Some(9.0));
+    assert_eq!(quantile(&DATA, 0.25), Some(4.0));
+    assert!(close(quantile(&DATA, 0.9).unwrap(), 7.6));
+    assert_eq!(quantile(&DATA, 1.5), None);
+    assert_eq!(quantiles(&DATA, &[0.0, 0.5, 1.0]), Some(vec![2.0, 4.5, 9.0]));
+}
+
+#[test]
+fn welford_streaming_and_merge() {
+    let mut all = Welford::new();
+    all.extend(DATA.iter().copied());
+    assert_eq!(all.count(), 8);
+    assert!(close(all.mean().unwrap(), 5.0));
+    assert!(close(all.population_variance().unwrap(), 4.0));
+
+    let mut a = Welford::new();
+    let mut b = Welford::new();
+    a.extend(DATA[..3].iter().copied());
+    b.extend(DATA[3..].iter().copied());
+    a.merge(&b);
+    assert_eq!(a.count(), 8);
+    assert!(close(a.mean().unwrap(), 5.0));
+    assert!(close(a.sample_variance().unwrap(), 32.0 / 7.0));
+}
+
+#[test]
+fn histogram_binning() {
+    let data: Vec<f64> = (0..=10).map(|i| i as f64).collect();
+
+    let h = Histogram::new(&data, Binning::Count(5)).unwrap();
+    assert_eq!(h.edges(), &[0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+    assert_eq!(h.counts(), &[2, 2, 2, 2, 3]);
+    assert_eq!(h.total(), 11);
+
+    let h = Histogram::new(&data, Binning::Width(2.5)).unwrap();
+    assert_eq!(h.counts(), &[3, 2, 3, 2, 1]);
+    // a width this small would need 10¹⁵ edges
+    assert!(Histogram::new(&data, Binning::Width(1e-14)).is_none());
+    assert!(Histogram::new(&[0.0, 1.0], Binning::Width(2.0 / histogram::MAX_WIDTH_BINS as f64)).is_some());
+    assert!(Histogram::new(&[0.0, 1.0], Binning::Width(0.5 / histogram::MAX_WIDTH_BINS as f64)).is_none());
+
+    let mut h = Histogram::new(&data, Binning::Edges(vec![1.0, 5.0, 6.0])).unwrap();
+    assert_eq!(h.counts(), &[4, 2]);
+    assert_eq!(h.outside(), 5);
+    assert!(!h.add(f64::NAN));
+    let area: f64 = h.density().iter().zip(h.bins()).map(|(d, (lo, hi, _))| d * (hi - lo)).sum();
+    assert!(close(area, 1.0));
+
+    assert_eq!(Histogram::new(&data, Binning::Sturges).unwrap().len(), 5);
+    assert_eq!(Histogram::new(&data, Binning::SquareRoot).unwrap().len(), 4);
+    assert!(Histogram::new(&data, Binning::Edges(vec![2.0, 1.0])).is_none());
+    assert!(Histogram::<f64>::new(&[], Binning::Count(3)).is_none());
+}
+
+#[test]
+fn covariance_of_vector3() {
+    let samples = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 0.0), Vector3::new(2.0, 4.0, 0.0)];
+    let cov = covariance_matrix3(&samples).unwrap();
+    assert_eq!(cov.data, [[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 0.0, 0.0]]);
+    let cor = correlation_matrix3(&samples).unwrap();
+    assert!((cor.data[0][1] - 1.0).abs() < 1e-6);
+    assert!(cor.data[2][2].is_nan());
+    assert_eq!(covariance_matrix3(&samples[..1]), Err(StatsError::NotEnoughSamples { needed: 2, found: 1 }));
+}
+
+#[test]
+fn covariance_of_vectorn() {
+    let samples = vec![
+        VectorN::new(vec![1.0, 2.0]),
+        VectorN::new(vec![2.0, 1.0]),
+        VectorN::new(vec![3.0, 0.0]),
+    ];
+    assert_eq!(mean_vector(&samples).unwrap(), VectorN::new(vec![2.0, 1.0]));
+    let cov = covariance_matrix(&samples).unwrap();
+    assert_eq!(cov, Matrix::from_rows(&[[1.0, -1.0], [-1.0, 1.0]]));
+    let cor = correlation_matrix(&samples).unwrap();
+    assert!(close(cor[(0, 1)], -1.0));
+
+    let bad = vec![VectorN::new(vec![1.0, 2.0]), VectorN::new(vec![1.0])];
+    assert_eq!(covariance_matrix(&bad), Err(StatsError::LengthMismatch(LengthMismatch { left: 2, right: 1 })));
+}
+
+#[test]
+fn symmetric_eigen() {
+    let a = Matrix::from_rows(&[
+        [4.0, 1.0, 2.0, 0.5],
+        [1.0, 3.0, 0.0, 1.0],
+        [2.0, 0.0, 5.0, 1.5],
+        [0.5, 1.0, 1.5, 2.0],
+    ]);
+    let e = SymmetricEigen::new(&a);
+    assert!(e.values.windows(2).all(|w| w[0] >= w[1]));
+    for k in 0..4 {
+        let v = e.vectors.col(k);
+        let av = a.mul_vec(&v);
+        for i in 0..4 {
+            assert!(close(av[i], e.values[k] * v[i]));
+        }
+    }
+    let trace: f64 = e.values.iter().sum();
+    assert!(close(trace, 14.0));
+}
+
+#[test]
+fn pca_of_vector3() {
+    let mut samples = Vec::new();
+    for t in -4..=4 {
+        let t = t as f32;
+        samples.push(Vector3::new(t + 0.1, t - 0.1, 0.05 * t.sin()));
+        samples.push(Vector3::new(t - 0.1, t + 0.1, -0.05 * t.cos()));
+    }
+    let pca = Pca::from_vector3(&samples).unwrap();
+    let axis = pca.axis(0);
+    let along = (axis.x + axis.y).abs() / 2f32.sqrt();
+    assert!((along - 1.0).abs() < 1e-4);
+    assert!(pca.explained_variance_ratio()[0] > 0.99);
+
+    let p = Vector3::new(1.0, 2.0, 3.0);
+    let back = pca.reconstruct(&pca.project(&p));
+    assert!((back - p).length() < 1e-4);
+}
+
+#[test]
+fn pca_of_vectorn() {
+    let samples: Vec<VectorN<f64>> = (0..20)
+        .map(|i| {
+            let t = i as f64 / 4.0;
+            VectorN::new(vec![t, 2.0 * t + (i % 3) as f64 * 0.01, -t, (i % 2) as f64 * 0.02])
+        })
+        .collect();
+    let pca = Pca::from_vectorn(&samples).unwrap();
+    assert_eq!(pca.dim(), 4);
+    let axis = pca.axis(0);
+    assert!(close(axis.norm_l2(), 1.0));
+    assert!((axis[1] / axis[0] - 2.0).abs() < 1e-2);
+
+    let x = &samples[7];
+    let back = pca.reconstruct(&pca.project(x));
+    assert!((back - x.clone()).norm_inf() < 1e-9);
+}